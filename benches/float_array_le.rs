@@ -0,0 +1,48 @@
+//! Compares [`FloatArrayLe`] to reading the same floats one by one through [`Tuple`] of [`IEEE754`].
+//!
+//! Run with `cargo bench --bench float_array_le`.
+
+use raw_seeders::{raw, FloatArrayLe, LittleEndian, Tuple, IEEE754};
+use serde_seeded::DeSeeder;
+use std::time::{Duration, Instant};
+
+const N: usize = 1024;
+const ITERATIONS: u32 = 2000;
+
+/// Runs `read` repeatedly and prints the mean time per iteration.
+fn bench(name: &str, mut read: impl FnMut() -> [f32; N]) {
+	// Warm up, and keep the results observable so they aren't optimised away.
+	let mut checksum = 0_f32;
+	for _ in 0..ITERATIONS / 10 {
+		checksum += read()[N - 1];
+	}
+
+	let start = Instant::now();
+	for _ in 0..ITERATIONS {
+		checksum += read()[N - 1];
+	}
+	let elapsed: Duration = start.elapsed();
+	println!(
+		"{:<24} {:>10.2?}/iter (checksum {})",
+		name,
+		elapsed / ITERATIONS,
+		checksum
+	);
+}
+
+fn main() {
+	let mut bytes = Vec::with_capacity(1 + N * 4);
+	// Offset by one byte so that the floats are unaligned.
+	bytes.push(0);
+	for i in 0..N {
+		bytes.extend_from_slice(&(i as f32 * 0.5).to_le_bytes());
+	}
+	let bytes = &bytes[1..];
+
+	bench("Tuple(IEEE754(LE))", || {
+		raw::from_slice(Tuple::of(IEEE754(LittleEndian)).seed(), bytes).unwrap()
+	});
+	bench("FloatArrayLe", || {
+		raw::from_slice(FloatArrayLe::<N>.seed(), bytes).unwrap()
+	});
+}
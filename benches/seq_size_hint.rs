@@ -0,0 +1,53 @@
+//! Compares reading a [`Seq`] whose deserializer provides a [`size_hint`](`serde::de::SeqAccess::size_hint`)
+//! to reading the same items without one, which can't preallocate.
+//!
+//! Run with `cargo bench --bench seq_size_hint`.
+
+use raw_seeders::{Seq, SerdeLike};
+use serde::de::{value::SeqDeserializer, DeserializeSeed};
+use serde_seeded::DeSeeder;
+use std::{
+	iter,
+	time::{Duration, Instant},
+};
+
+const N: usize = 100_000;
+const ITERATIONS: u32 = 200;
+
+type Error = serde::de::value::Error;
+
+/// Runs `read` repeatedly and prints the mean time per iteration.
+fn bench(name: &str, mut read: impl FnMut() -> Vec<u32>) {
+	// Warm up, and keep the results observable so they aren't optimised away.
+	let mut checksum = 0_u32;
+	for _ in 0..ITERATIONS / 10 {
+		checksum = checksum.wrapping_add(read()[N - 1]);
+	}
+
+	let start = Instant::now();
+	for _ in 0..ITERATIONS {
+		checksum = checksum.wrapping_add(read()[N - 1]);
+	}
+	let elapsed: Duration = start.elapsed();
+	println!(
+		"{:<24} {:>10.2?}/iter (checksum {})",
+		name,
+		elapsed / ITERATIONS,
+		checksum
+	);
+}
+
+fn main() {
+	let items: Vec<u32> = (0..N as u32).collect();
+
+	bench("Seq, hinted", || {
+		let deserializer = SeqDeserializer::<_, Error>::new(items.iter().copied());
+		Seq(SerdeLike).seed().deserialize(deserializer).unwrap()
+	});
+	bench("Seq, unhinted", || {
+		let mut items = items.iter().copied();
+		// `from_fn` hides the length, so the deserializer can't hint it.
+		let deserializer = SeqDeserializer::<_, Error>::new(iter::from_fn(|| items.next()));
+		Seq(SerdeLike).seed().deserialize(deserializer).unwrap()
+	});
+}
@@ -0,0 +1,323 @@
+//! Seeders for encoded text, available with the `"encoding"` feature.
+
+use super::*;
+use alloc::borrow::Cow;
+use encoding::{all, types::EncodingRef};
+
+/// String as encoded storage, with the text encoding selected by the marker type `E`.  
+/// (Usage: [`Encoding::new(bytes_seeder)`], Parameters: [`StaticEncoding`], Vec<u8> [`Seeder`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Encoding<E, BytesSeeder>(pub BytesSeeder, pub PhantomData<E>);
+impl<E, BytesSeeder> Encoding<E, BytesSeeder> {
+	pub fn new(bytes_seeder: BytesSeeder) -> Self {
+		Self(bytes_seeder, PhantomData)
+	}
+}
+impl<'de, E: StaticEncoding, T: DeEncodingable<'de, E>, BytesSeeder: DeSeeder<'de, Vec<u8>>>
+	DeSeeder<'de, T> for Encoding<E, BytesSeeder>
+{
+	type Seed = EncodingSeed<E, T, BytesSeeder>;
+	fn seed(self) -> Self::Seed {
+		EncodingSeed(self.0, PhantomData)
+	}
+}
+impl<E: StaticEncoding, T: SerEncodingable<E>, BytesSeeder: SerSeeder<Vec<u8>>> SerSeeder<T>
+	for Encoding<E, BytesSeeder>
+{
+	fn seeded<'s>(&'s self, value: &'s T) -> Seeded<'s> {
+		Box::new(EncodingSeeded(value, &self.0, PhantomData::<E>))
+	}
+}
+
+macro_rules! encoding_seeders {
+	($($(#[$attr:meta])* $name:ident($encoding:ty)),*$(,)?) => {$(
+		$(#[$attr])*
+		#[derive(Debug, Copy, Clone, Default)]
+		pub struct $name<BytesSeeder>(pub BytesSeeder);
+		impl<'de, T: DeEncodingable<'de, $encoding>, BytesSeeder: DeSeeder<'de, Vec<u8>>> DeSeeder<'de, T>
+			for $name<BytesSeeder>
+		{
+			type Seed = EncodingSeed<$encoding, T, BytesSeeder>;
+			fn seed(self) -> Self::Seed {
+				EncodingSeed(self.0, PhantomData)
+			}
+		}
+		impl<T: SerEncodingable<$encoding>, BytesSeeder: SerSeeder<Vec<u8>>> SerSeeder<T>
+			for $name<BytesSeeder>
+		{
+			fn seeded<'s>(&'s self, value: &'s T) -> Seeded<'s> {
+				Box::new(EncodingSeeded(value, &self.0, PhantomData::<$encoding>))
+			}
+		}
+	)*};
+}
+encoding_seeders! {
+	/// String as Windows-1252 storage.
+	/// (Parameters: Vec<u8> [`Seeder`])
+	Windows1252(Windows1252Encoding),
+	/// String as 7-bit ASCII storage. Bytes from 0x80 up are an error.
+	/// (Parameters: Vec<u8> [`Seeder`])
+	Ascii(AsciiEncoding),
+	/// String as strict UTF-8 storage. Invalid sequences are an error.
+	/// (Parameters: Vec<u8> [`Seeder`])
+	Utf8(Utf8Encoding),
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct EncodingSeed<E, T, BytesSeeder>(BytesSeeder, PhantomData<(E, T)>);
+impl<'de, E: StaticEncoding, T: DeEncodingable<'de, E>, BytesSeeder: DeSeeder<'de, Vec<u8>>>
+	de::DeserializeSeed<'de> for EncodingSeed<E, T, BytesSeeder>
+{
+	type Value = T;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let value = self.0.seed().deserialize(deserializer)?.pipe(T::from)?;
+		debug!("Decoded {}: {:?}", E::encoding().name(), value);
+		Ok(value)
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct EncodingSeeded<'a, E, T, BytesSeeder>(&'a T, &'a BytesSeeder, PhantomData<E>);
+impl<'a, E: StaticEncoding, T: SerEncodingable<E>, BytesSeeder: SerSeeder<Vec<u8>>> ser::Serialize
+	for EncodingSeeded<'a, E, T, BytesSeeder>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		self.0
+			.to()?
+			.pipe(|repr| self.1.seeded(&repr).serialize(serializer))
+	}
+}
+
+/// Selects a text encoding from the `encoding` crate at the type level.  
+/// See [`Encoding`].
+pub trait StaticEncoding {
+	fn encoding() -> EncodingRef;
+}
+
+macro_rules! static_encodings {
+	($($(#[$attr:meta])* $name:ident => $encoding:expr),*$(,)?) => {$(
+		$(#[$attr])*
+		#[derive(Debug, Copy, Clone, Default, Ord, PartialOrd, Eq, PartialEq, Hash)]
+		pub struct $name;
+		impl StaticEncoding for $name {
+			fn encoding() -> EncodingRef {
+				$encoding
+			}
+		}
+	)*};
+}
+static_encodings! {
+	/// Windows-1252 (Western European). See [`Windows1252`].
+	Windows1252Encoding => all::WINDOWS_1252,
+	/// 7-bit ASCII, which rejects any byte from 0x80 up. See [`Ascii`].
+	AsciiEncoding => all::ASCII,
+	/// ISO-8859-1 (Latin-1).
+	Latin1Encoding => all::ISO_8859_1,
+	/// UTF-8, which rejects invalid sequences. See [`Utf8`].
+	Utf8Encoding => all::UTF_8,
+	/// Shift-JIS, as the Windows-31J superset.
+	ShiftJisEncoding => all::WINDOWS_31J,
+	/// EUC-JP.
+	EucJpEncoding => all::EUC_JP,
+	/// GBK (Simplified Chinese).
+	GbkEncoding => all::GBK,
+	/// Big5 (Traditional Chinese).
+	Big5Encoding => all::BIG5_2003,
+	/// EUC-KR / Windows-949 (Korean).
+	EucKrEncoding => all::WINDOWS_949,
+}
+
+/// See [`Encoding`].
+pub trait DeEncodingable<'de, E: StaticEncoding>: Sized + Debug {
+	fn from<Error: de::Error>(repr: Vec<u8>) -> Result<Self, Error>;
+}
+/// See [`Encoding`].
+pub trait SerEncodingable<E: StaticEncoding>: Sized {
+	fn to<Error: ser::Error>(&self) -> Result<Vec<u8>, Error>;
+}
+
+impl<'de, E: StaticEncoding> DeEncodingable<'de, E> for String {
+	fn from<Error: de::Error>(repr: Vec<u8>) -> Result<Self, Error> {
+		let mut decoder = E::encoding().raw_decoder();
+		let mut string = String::new();
+		let (offset, error) = decoder.raw_feed(&repr, &mut string);
+		match error.or_else(|| decoder.raw_finish(&mut string)) {
+			None => Ok(string),
+			Some(error) => Err(de::Error::custom(format_args!(
+				"Invalid {} at byte offset {}: {}",
+				E::encoding().name(),
+				offset,
+				error.cause
+			))),
+		}
+	}
+}
+impl<'de, E: StaticEncoding> DeEncodingable<'de, E> for Cow<'de, str> {
+	fn from<Error: de::Error>(repr: Vec<u8>) -> Result<Self, Error> {
+		<String as DeEncodingable<E>>::from(repr).map(Cow::Owned)
+	}
+}
+impl<'de, E: StaticEncoding> DeEncodingable<'de, E> for Box<str> {
+	fn from<Error: de::Error>(repr: Vec<u8>) -> Result<Self, Error> {
+		<String as DeEncodingable<E>>::from(repr).map(String::into_boxed_str)
+	}
+}
+
+impl<E: StaticEncoding> SerEncodingable<E> for String {
+	fn to<Error: ser::Error>(&self) -> Result<Vec<u8>, Error> {
+		encode_str::<E, Error>(self)
+	}
+}
+impl<E: StaticEncoding> SerEncodingable<E> for &str {
+	fn to<Error: ser::Error>(&self) -> Result<Vec<u8>, Error> {
+		encode_str::<E, Error>(self)
+	}
+}
+impl<'a, E: StaticEncoding> SerEncodingable<E> for Cow<'a, str> {
+	fn to<Error: ser::Error>(&self) -> Result<Vec<u8>, Error> {
+		encode_str::<E, Error>(self)
+	}
+}
+impl<E: StaticEncoding> SerEncodingable<E> for Box<str> {
+	fn to<Error: ser::Error>(&self) -> Result<Vec<u8>, Error> {
+		encode_str::<E, Error>(self)
+	}
+}
+
+fn encode_str<E: StaticEncoding, Error: ser::Error>(string: &str) -> Result<Vec<u8>, Error> {
+	let mut encoder = E::encoding().raw_encoder();
+	let mut bytes = Vec::new();
+	let (offset, error) = encoder.raw_feed(string, &mut bytes);
+	match error.or_else(|| encoder.raw_finish(&mut bytes)) {
+		None => Ok(bytes),
+		Some(error) => Err(ser::Error::custom(format_args!(
+			"Can't encode {:?} at byte offset {} as {}: {}",
+			string[offset..].chars().next().unwrap_or_default(),
+			offset,
+			E::encoding().name(),
+			error.cause
+		))),
+	}
+}
+
+/// `COUNT` strings in the encoding selected by the marker type `E` (Windows-1252 by default),
+/// each stored in a slot of `WIDTH` bytes padded with trailing NULs.  
+/// (Usage: [`FixedStringArray::<COUNT, WIDTH>::new()`], [`FixedStringArray::<COUNT, WIDTH, E>::new()`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct FixedStringArray<const COUNT: usize, const WIDTH: usize, E = Windows1252Encoding>(
+	pub PhantomData<E>,
+);
+impl<const COUNT: usize, const WIDTH: usize, E> FixedStringArray<COUNT, WIDTH, E> {
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+impl<'de, const COUNT: usize, const WIDTH: usize, E: StaticEncoding, T: DeEncodingable<'de, E>>
+	DeSeeder<'de, Vec<T>> for FixedStringArray<COUNT, WIDTH, E>
+{
+	type Seed = FixedStringArraySeed<COUNT, WIDTH, E, T>;
+	fn seed(self) -> Self::Seed {
+		FixedStringArraySeed(PhantomData)
+	}
+}
+impl<const COUNT: usize, const WIDTH: usize, E: StaticEncoding, T: SerEncodingable<E>>
+	SerSeeder<Vec<T>> for FixedStringArray<COUNT, WIDTH, E>
+{
+	fn seeded<'s>(&'s self, value: &'s Vec<T>) -> Seeded<'s> {
+		Box::new(FixedStringArraySeeded::<COUNT, WIDTH, E, T>(
+			value,
+			PhantomData,
+		))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct FixedStringArraySeed<const COUNT: usize, const WIDTH: usize, E, T>(PhantomData<(E, T)>);
+impl<'de, const COUNT: usize, const WIDTH: usize, E: StaticEncoding, T: DeEncodingable<'de, E>>
+	de::DeserializeSeed<'de> for FixedStringArraySeed<COUNT, WIDTH, E, T>
+{
+	type Value = Vec<T>;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<const COUNT: usize, const WIDTH: usize, E, T>(PhantomData<(E, T)>);
+		impl<
+				'de,
+				const COUNT: usize,
+				const WIDTH: usize,
+				E: StaticEncoding,
+				T: DeEncodingable<'de, E>,
+			> de::Visitor<'de> for Visitor<COUNT, WIDTH, E, T>
+		{
+			type Value = Vec<T>;
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "FixedStringArray::<{}, {}>", COUNT, WIDTH)
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let mut strings = Vec::with_capacity(COUNT);
+				while strings.len() < COUNT {
+					let mut slot: Vec<u8> = seq
+						.next_element_seed(TupleNSeed(WIDTH, SerdeLike, PhantomData))?
+						.ok_or_else(|| de::Error::invalid_length(strings.len(), &self))?;
+					slot.truncate(slot.iter().rposition(|&b| b != 0).map_or(0, |i| i + 1));
+					strings.push(T::from(slot)?);
+				}
+				Ok(strings)
+			}
+		}
+
+		deserializer.deserialize_tuple(COUNT, Visitor::<COUNT, WIDTH, E, T>(PhantomData))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct FixedStringArraySeeded<'a, const COUNT: usize, const WIDTH: usize, E, T>(
+	&'a Vec<T>,
+	PhantomData<E>,
+);
+impl<'a, const COUNT: usize, const WIDTH: usize, E: StaticEncoding, T: SerEncodingable<E>>
+	ser::Serialize for FixedStringArraySeeded<'a, COUNT, WIDTH, E, T>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		if self.0.len() != COUNT {
+			return Err(ser::Error::custom(format_args!(
+				"Tried to serialise FixedStringArray::<{}, {}> from a .len = {}",
+				COUNT,
+				WIDTH,
+				self.0.len()
+			)));
+		}
+		let mut serialize_tuple = serializer.serialize_tuple(COUNT)?;
+		for string in self.0 {
+			let mut slot = string.to()?;
+			if slot.len() > WIDTH {
+				return Err(ser::Error::custom(format_args!(
+					"Encoded string of {} bytes doesn't fit FixedStringArray slot of {}",
+					slot.len(),
+					WIDTH
+				)));
+			}
+			slot.resize(WIDTH, 0);
+			serialize_tuple.serialize_element(&TupleNSeeded(&slot, WIDTH, &SerdeLike))?
+		}
+		serialize_tuple.end()
+	}
+}
@@ -1,15 +1,67 @@
-use arrayvec::{Array, ArrayVec};
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{
+	boxed::Box,
+	collections::{BTreeMap, BTreeSet},
+	format,
+	string::{String, ToString as _},
+	vec,
+	vec::Vec,
+};
 use cast::{i32, u32, usize};
-use encoding::{all::WINDOWS_1252, DecoderTrap, Encoding as _};
-use log::{debug, trace};
+use core::{
+	cell::RefCell,
+	convert::{TryFrom, TryInto},
+	fmt::{Debug, Display, UpperHex},
+	hash::Hash,
+	iter,
+	marker::PhantomData,
+	mem::{self, ManuallyDrop, MaybeUninit},
+	ops::{Deref, RangeBounds},
+	ptr,
+	str::FromStr,
+};
+use log::{debug, trace, warn};
 use serde::{
 	de::{self, DeserializeSeed as _},
 	ser::{self, SerializeSeq as _, SerializeTuple as _},
 };
-use serde_seeded::{seed, seeded, DeSeeder, Seeded, SerSeeder};
-use std::{fmt::Debug, iter, marker::PhantomData, ops::Deref};
+use serde_seeded::{DeSeeder, Seeded, SerSeeder};
 use wyz::Pipe as _;
 
+#[cfg(feature = "encoding")]
+mod encodings;
+#[cfg(feature = "encoding")]
+pub use encodings::*;
+pub mod raw;
+#[cfg(feature = "std")]
+mod with_std;
+#[cfg(feature = "std")]
+pub use with_std::*;
+#[cfg(test)]
+mod tests;
+
+/// Deserializes a `T` from `deserializer` as laid out by `seeder`.  
+/// Shorthand for `seeder.seed().deserialize(deserializer)`.
+pub fn seed_deserialize<'de, T, Seeder: DeSeeder<'de, T>, D: serde::Deserializer<'de>>(
+	seeder: Seeder,
+	deserializer: D,
+) -> Result<T, D::Error> {
+	seeder.seed().deserialize(deserializer)
+}
+
+/// Serializes `value` into `serializer` as laid out by `seeder`.  
+/// Shorthand for `seeder.seeded(value).serialize(serializer)`.
+pub fn seed_serialize<T, Seeder: SerSeeder<T>, S: serde::Serializer>(
+	seeder: &Seeder,
+	value: &T,
+	serializer: S,
+) -> Result<S::Ok, S::Error> {
+	ser::Serialize::serialize(&seeder.seeded(value), serializer)
+}
+
 /// Stores a binary slice instead of a `()`.  
 /// (Parameters: A `&[u8]` specifying the data to store or check against.)
 #[derive(Debug, Clone, Copy, PartialEq, Ord, PartialOrd, Eq)]
@@ -46,8 +98,8 @@ impl<'a, 'de> de::DeserializeSeed<'de> for Literal<'a> {
 			type Value = ();
 			fn expecting(
 				&self,
-				f: &mut std::fmt::Formatter<'_>,
-			) -> std::result::Result<(), std::fmt::Error> {
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
 				write!(f, "{} literal bytes", self.0.len())
 			}
 
@@ -71,6 +123,67 @@ impl<'a, 'de> de::DeserializeSeed<'de> for Literal<'a> {
 	}
 }
 
+/// Like [`Literal`], but reports the bytes actually found in hexadecimal on mismatch, which helps with identifying files.  
+/// (Parameters: A `&[u8]` specifying the magic bytes to store or check against.)
+#[derive(Debug, Clone, Copy, PartialEq, Ord, PartialOrd, Eq)]
+pub struct Magic<'a>(pub &'a [u8]);
+impl<'a, 'de> DeSeeder<'de, ()> for Magic<'a> {
+	type Seed = Self;
+	fn seed(self) -> Self::Seed {
+		self
+	}
+}
+impl<'a> SerSeeder<()> for Magic<'a> {
+	fn seeded<'s>(&'s self, _: &()) -> Seeded<'s> {
+		Box::new(Literal(self.0))
+	}
+}
+impl<'a, 'de> de::DeserializeSeed<'de> for Magic<'a> {
+	type Value = ();
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let len = self.0.len();
+
+		struct Visitor<'a>(&'a [u8]);
+		impl<'a, 'de> de::Visitor<'de> for Visitor<'a> {
+			type Value = ();
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "magic {:02X?}", self.0)
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let mut actual: Vec<u8> = Vec::with_capacity(self.0.len());
+				while actual.len() < self.0.len() {
+					match seq.next_element() {
+						Ok(Some(byte)) => actual.push(byte),
+						Ok(None) => break,
+						Err(error) => {
+							return Err(de::Error::custom(format_args!(
+								"Expected magic {:02X?}, found {:02X?} and then: {}",
+								self.0, actual, error
+							)))
+						}
+					}
+				}
+				if actual != self.0 {
+					return Err(de::Error::custom(format_args!(
+						"Expected magic {:02X?}, found {:02X?}",
+						self.0, actual
+					)));
+				}
+				Ok(())
+			}
+		}
+
+		deserializer.deserialize_tuple(len, Visitor(self.0))
+	}
+}
+
 /// Little-endian (least significant byte first) storage for integers.
 #[derive(Debug, Copy, Clone, Default)]
 pub struct LittleEndian;
@@ -110,27 +223,195 @@ impl<'a, T: ByteOrdered> ser::Serialize for LittleEndianSeeded<'a, T> {
 	}
 }
 
+/// Big-endian (most significant byte first) storage for integers.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct BigEndian;
+impl<'de, T: ByteOrdered> DeSeeder<'de, T> for BigEndian {
+	type Seed = BigEndianSeed<T>;
+	fn seed(self) -> Self::Seed {
+		BigEndianSeed(PhantomData)
+	}
+}
+impl<T: ByteOrdered> SerSeeder<T> for BigEndian {
+	fn seeded<'s>(&self, value: &'s T) -> Seeded<'s> {
+		Box::new(BigEndianSeeded(value))
+	}
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct BigEndianSeed<T>(PhantomData<T>);
+impl<'de, T: ByteOrdered> de::DeserializeSeed<'de> for BigEndianSeed<T> {
+	type Value = T;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		T::deserialize_be(deserializer)
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct BigEndianSeeded<'a, T>(&'a T);
+impl<'a, T: ByteOrdered> ser::Serialize for BigEndianSeeded<'a, T> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		self.0.serialize_be(serializer)
+	}
+}
+
 /// See [`BigEndian`] and [`LittleEndian`].
 pub trait ByteOrdered: Sized {
 	fn deserialize_le<'de, D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
 	fn serialize_le<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+	fn deserialize_be<'de, D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+	fn serialize_be<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+}
+
+macro_rules! impl_byte_ordered {
+	($($ty:ty),*$(,)?) => {$(
+		impl ByteOrdered for $ty {
+			fn deserialize_le<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+				Ok(Self::from_le_bytes(PhantomData.deserialize(deserializer)?))
+			}
+			fn serialize_le<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+				serializer.serialize_bytes(&self.to_le_bytes())
+			}
+			fn deserialize_be<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+				Ok(Self::from_be_bytes(PhantomData.deserialize(deserializer)?))
+			}
+			fn serialize_be<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+				serializer.serialize_bytes(&self.to_be_bytes())
+			}
+		}
+	)*};
+}
+impl_byte_ordered!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128);
+
+/// Byte order as declared by a TIFF-style byte order mark, chosen at runtime.  
+/// Read it with [`Bom`], then use it as integer [`Seeder`] for the fields it governs.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum BomEndian {
+	/// `II`, like [`LittleEndian`].
+	Little,
+	/// `MM`, like [`BigEndian`].
+	Big,
+}
+impl<'de, T: ByteOrdered> DeSeeder<'de, T> for BomEndian {
+	type Seed = BomEndianSeed<T>;
+	fn seed(self) -> Self::Seed {
+		BomEndianSeed(self, PhantomData)
+	}
+}
+impl<T: ByteOrdered> SerSeeder<T> for BomEndian {
+	fn seeded<'s>(&self, value: &'s T) -> Seeded<'s> {
+		Box::new(BomEndianSeeded(value, *self))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone)]
+pub struct BomEndianSeed<T>(BomEndian, PhantomData<T>);
+impl<'de, T: ByteOrdered> de::DeserializeSeed<'de> for BomEndianSeed<T> {
+	type Value = T;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		match self.0 {
+			BomEndian::Little => T::deserialize_le(deserializer),
+			BomEndian::Big => T::deserialize_be(deserializer),
+		}
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct BomEndianSeeded<'a, T>(&'a T, BomEndian);
+impl<'a, T: ByteOrdered> ser::Serialize for BomEndianSeeded<'a, T> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		match self.1 {
+			BomEndian::Little => self.0.serialize_le(serializer),
+			BomEndian::Big => self.0.serialize_be(serializer),
+		}
+	}
 }
 
-impl ByteOrdered for i32 {
-	fn deserialize_le<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-		Ok(Self::from_le_bytes(PhantomData.deserialize(deserializer)?))
+/// Stores a [`BomEndian`] as two-byte mark, `II` or `MM`.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Bom;
+impl<'de> DeSeeder<'de, BomEndian> for Bom {
+	type Seed = Self;
+	fn seed(self) -> Self::Seed {
+		self
+	}
+}
+impl SerSeeder<BomEndian> for Bom {
+	fn seeded<'s>(&self, value: &'s BomEndian) -> Seeded<'s> {
+		Box::new(BomSeeded(value))
 	}
-	fn serialize_le<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-		serializer.serialize_bytes(&self.to_le_bytes())
+}
+impl<'de> de::DeserializeSeed<'de> for Bom {
+	type Value = BomEndian;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let mark: [u8; 2] = PhantomData.deserialize(deserializer)?;
+		match &mark {
+			b"II" => Ok(BomEndian::Little),
+			b"MM" => Ok(BomEndian::Big),
+			_ => Err(de::Error::invalid_value(
+				de::Unexpected::Bytes(&mark),
+				&"byte order mark II or MM",
+			)),
+		}
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct BomSeeded<'a>(&'a BomEndian);
+impl<'a> ser::Serialize for BomSeeded<'a> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		serializer.serialize_bytes(match self.0 {
+			BomEndian::Little => b"II",
+			BomEndian::Big => b"MM",
+		})
 	}
 }
 
-impl ByteOrdered for u32 {
-	fn deserialize_le<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-		Ok(Self::from_le_bytes(PhantomData.deserialize(deserializer)?))
+/// Integer byte order chosen at runtime, little-endian if `true`.  
+/// This is a plain-flag counterpart to [`BomEndian`], for when the byte order was declared some other way.  
+/// (Parameters: `little: bool`)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DynEndian(pub bool);
+impl From<DynEndian> for BomEndian {
+	fn from(dyn_endian: DynEndian) -> Self {
+		if dyn_endian.0 {
+			BomEndian::Little
+		} else {
+			BomEndian::Big
+		}
+	}
+}
+impl<'de, T: ByteOrdered> DeSeeder<'de, T> for DynEndian {
+	type Seed = BomEndianSeed<T>;
+	fn seed(self) -> Self::Seed {
+		BomEndianSeed(self.into(), PhantomData)
 	}
-	fn serialize_le<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-		serializer.serialize_bytes(&self.to_le_bytes())
+}
+impl<T: ByteOrdered> SerSeeder<T> for DynEndian {
+	fn seeded<'s>(&self, value: &'s T) -> Seeded<'s> {
+		Box::new(BomEndianSeeded(value, (*self).into()))
 	}
 }
 
@@ -210,6 +491,28 @@ impl IEEE754able for f64 {
 	}
 }
 
+#[cfg(feature = "half")]
+impl IEEE754able for half::f16 {
+	type Repr = u16;
+	fn from(repr: Self::Repr) -> Self {
+		half::f16::from_bits(repr)
+	}
+	fn to(&self) -> Self::Repr {
+		self.to_bits()
+	}
+}
+
+#[cfg(feature = "half")]
+impl IEEE754able for half::bf16 {
+	type Repr = u16;
+	fn from(repr: Self::Repr) -> Self {
+		half::bf16::from_bits(repr)
+	}
+	fn to(&self) -> Self::Repr {
+		self.to_bits()
+	}
+}
+
 /// Fixed length containers as tuple.  
 /// (Usage: [`Tuple::of(item_seeder)`])
 #[derive(Debug, Copy, Clone, Default)]
@@ -254,24 +557,37 @@ impl<'de, T: DeTupleable, ItemSeeder: Clone + DeSeeder<'de, T::Item>> de::Deseri
 			type Value = T;
 			fn expecting(
 				&self,
-				f: &mut std::fmt::Formatter<'_>,
-			) -> std::result::Result<(), std::fmt::Error> {
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
 				write!(f, "Tuple with lenth {}", T::len())
 			}
 
 			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
 				let mut error = Ok(());
+				let mut index = 0;
 				let array = T::from(
 					iter::from_fn(|| match seq.next_element_seed(self.0.clone().seed()) {
-						Ok(next) => next,
+						Ok(next) => {
+							index += 1;
+							next
+						}
 						Err(e) => {
 							error = Err(e);
 							None
 						}
 					})
 					.take(T::len()),
-				)?;
-				Ok(array)
+				);
+				// A failed element ends the iterator early, so check this before the length.
+				error.map_err(|error| {
+					de::Error::custom(format_args!(
+						"{} (in Tuple element {} of {})",
+						error,
+						index,
+						T::len()
+					))
+				})?;
+				array
 			}
 		}
 
@@ -315,24 +631,44 @@ pub trait SerTupleable<Item> {
 	}
 }
 
-impl<T: Array> DeTupleable for T {
-	type Item = T::Item;
+impl<Item, const N: usize> DeTupleable for [Item; N] {
+	type Item = Item;
 	fn len() -> usize {
-		T::CAPACITY
+		N
 	}
 	fn from<I: IntoIterator<Item = Self::Item>, E: de::Error>(items: I) -> Result<Self, E> {
-		let mut items = items.into_iter();
-		let mut vec = ArrayVec::new();
-		while !vec.is_full() {
-			vec.push(items.next().ok_or_else(|| {
-				de::Error::invalid_length(
-					vec.len(),
-					&format!("Tuple of {}", <Self as DeTupleable>::len()).as_ref(),
-				)
-			})?)
+		/// Drops the filled prefix if the array can't be completed.
+		struct Partial<Item, const N: usize> {
+			array: [MaybeUninit<Item>; N],
+			filled: usize,
+		}
+		impl<Item, const N: usize> Drop for Partial<Item, N> {
+			fn drop(&mut self) {
+				for item in &mut self.array[..self.filled] {
+					// Safety: The first `filled` items were written.
+					unsafe { item.assume_init_drop() }
+				}
+			}
+		}
+
+		let mut partial = Partial::<Item, N> {
+			// Safety: An array of `MaybeUninit` doesn't require initialization.
+			array: unsafe { MaybeUninit::<[MaybeUninit<Item>; N]>::uninit().assume_init() },
+			filled: 0,
+		};
+		for item in items.into_iter().take(N) {
+			partial.array[partial.filled].write(item);
+			partial.filled += 1;
+		}
+		if partial.filled < N {
+			return Err(de::Error::invalid_length(
+				partial.filled,
+				&format!("Tuple of {}", N).as_ref(),
+			));
 		}
-		let array = vec.into_inner().map_err(|_| unreachable!())?;
-		Ok(array)
+		let partial = ManuallyDrop::new(partial);
+		// Safety: All `N` items were written, and `partial` won't drop them anymore.
+		Ok(unsafe { ptr::read(partial.array.as_ptr().cast::<[Item; N]>()) })
 	}
 }
 impl<T: AsRef<[Item]>, Item> SerTupleable<Item> for T {
@@ -351,7 +687,9 @@ impl<T: AsRef<[Item]>, Item> SerTupleable<Item> for T {
 	}
 }
 
-/// Vec as tuple.
+/// Vec as tuple.  
+/// Exactly `length` items are read and written. Serializing a value with a different `.len()` is an error rather than truncating or padding it
+/// (see [`TupleN::truncating`] and [`PadToLen`] for those).  
 /// (Usage: [`TupleN(length, item_seeder)`])
 #[derive(Debug, Copy, Clone, Default)]
 pub struct TupleN<ItemSeeder>(pub usize, pub ItemSeeder);
@@ -368,6 +706,32 @@ impl<T: SerTupleNable, ItemSeeder: SerSeeder<T::Item>> SerSeeder<T> for TupleN<I
 		Box::new(TupleNSeeded(value, self.0, &self.1))
 	}
 }
+impl<ItemSeeder> TupleN<ItemSeeder> {
+	/// Like [`TupleN`], but serializing a longer value writes only its first `length` items.  
+	/// Shorter values are still an error.
+	pub fn truncating(length: usize, item_seeder: ItemSeeder) -> TruncatingTupleN<ItemSeeder> {
+		TruncatingTupleN(length, item_seeder)
+	}
+}
+
+/// See [`TupleN::truncating`].
+#[derive(Debug, Copy, Clone, Default)]
+pub struct TruncatingTupleN<ItemSeeder>(pub usize, pub ItemSeeder);
+impl<'de, T: DeTupleNable, ItemSeeder: Clone + DeSeeder<'de, T::Item>> DeSeeder<'de, T>
+	for TruncatingTupleN<ItemSeeder>
+{
+	type Seed = TupleNSeed<T, ItemSeeder>;
+	fn seed(self) -> Self::Seed {
+		TupleNSeed(self.0, self.1, PhantomData)
+	}
+}
+impl<T: SerTupleNable, ItemSeeder: SerSeeder<T::Item>> SerSeeder<T>
+	for TruncatingTupleN<ItemSeeder>
+{
+	fn seeded<'s>(&'s self, value: &'s T) -> Seeded<'s> {
+		Box::new(TruncatingTupleNSeeded(value, self.0, &self.1))
+	}
+}
 
 #[doc(hidden)]
 #[derive(Debug, Copy, Clone, Default)]
@@ -387,8 +751,8 @@ impl<'de, T: DeTupleNable, ItemSeeder: Clone + DeSeeder<'de, T::Item>> de::Deser
 			type Value = T;
 			fn expecting(
 				&self,
-				f: &mut std::fmt::Formatter<'_>,
-			) -> std::result::Result<(), std::fmt::Error> {
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
 				write!(f, "TupleN({}, _)", self.0)
 			}
 
@@ -396,7 +760,7 @@ impl<'de, T: DeTupleNable, ItemSeeder: Clone + DeSeeder<'de, T::Item>> de::Deser
 				trace!(
 					"Deserializing TupleN({}, {})...",
 					self.0,
-					std::any::type_name::<A>()
+					core::any::type_name::<A>()
 				);
 				let mut error = Ok(());
 				let vec = T::from(
@@ -413,7 +777,7 @@ impl<'de, T: DeTupleNable, ItemSeeder: Clone + DeSeeder<'de, T::Item>> de::Deser
 				if self.0 != vec.len() {
 					return Err(de::Error::invalid_length(vec.len(), &self));
 				}
-				trace!("Done TupleN({}, {}).", self.0, std::any::type_name::<A>());
+				trace!("Done TupleN({}, {}).", self.0, core::any::type_name::<A>());
 				Ok(vec)
 			}
 		}
@@ -424,8 +788,8 @@ impl<'de, T: DeTupleNable, ItemSeeder: Clone + DeSeeder<'de, T::Item>> de::Deser
 
 #[doc(hidden)]
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
-pub struct TupleNSeeded<'a, T, ItemSeeder>(&'a T, usize, &'a ItemSeeder);
-impl<'a, T: SerTupleNable, ItemSeeder: SerSeeder<T::Item>> ser::Serialize
+pub struct TupleNSeeded<'a, T: ?Sized, ItemSeeder>(&'a T, usize, &'a ItemSeeder);
+impl<'a, T: ?Sized + SerTupleNable, ItemSeeder: SerSeeder<T::Item>> ser::Serialize
 	for TupleNSeeded<'a, T, ItemSeeder>
 {
 	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -434,7 +798,7 @@ impl<'a, T: SerTupleNable, ItemSeeder: SerSeeder<T::Item>> ser::Serialize
 	{
 		if self.1 != self.0.len() {
 			return Err(ser::Error::custom(format_args!(
-				"Tried to serialise SeqN({}, _) from a .len = {}",
+				"Tried to serialise TupleN({}, _) from a .len = {} (the length must match exactly)",
 				self.1,
 				self.0.len()
 			)));
@@ -445,6 +809,58 @@ impl<'a, T: SerTupleNable, ItemSeeder: SerSeeder<T::Item>> ser::Serialize
 	}
 }
 
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct TruncatingTupleNSeeded<'a, T: ?Sized, ItemSeeder>(&'a T, usize, &'a ItemSeeder);
+impl<'a, T: ?Sized + SerTupleNable, ItemSeeder: SerSeeder<T::Item>> ser::Serialize
+	for TruncatingTupleNSeeded<'a, T, ItemSeeder>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		if self.0.len() < self.1 {
+			return Err(ser::Error::custom(format_args!(
+				"Tried to serialise TupleN::truncating({}, _) from a .len = {} (the length must be at least that)",
+				self.1,
+				self.0.len()
+			)));
+		}
+		if self.0.len() > self.1 {
+			debug!(
+				"Truncating {} items to TupleN::truncating({}, _).",
+				self.0.len(),
+				self.1
+			);
+		}
+		let mut serialize_tuple = Truncated(serializer.serialize_tuple(self.1)?, self.1);
+		self.0.to(&mut serialize_tuple, self.2)?;
+		serialize_tuple.0.end()
+	}
+}
+
+/// Forwards only the first `.1` elements to the wrapped [`ser::SerializeTuple`].
+struct Truncated<SerializeTuple>(SerializeTuple, usize);
+impl<SerializeTuple: ser::SerializeTuple> ser::SerializeTuple for Truncated<SerializeTuple> {
+	type Ok = SerializeTuple::Ok;
+	type Error = SerializeTuple::Error;
+	fn serialize_element<T: ?Sized + ser::Serialize>(
+		&mut self,
+		value: &T,
+	) -> Result<(), Self::Error> {
+		match self.1.checked_sub(1) {
+			Some(remaining) => {
+				self.1 = remaining;
+				self.0.serialize_element(value)
+			}
+			None => Ok(()),
+		}
+	}
+	fn end(self) -> Result<Self::Ok, Self::Error> {
+		self.0.end()
+	}
+}
+
 /// See [`TupleN`].
 pub trait DeTupleNable: Sized {
 	type Item;
@@ -513,6 +929,28 @@ impl<T> SerTupleNable for Vec<T> {
 		Ok(())
 	}
 }
+impl<T> DeTupleNable for Box<[T]> {
+	type Item = T;
+	fn len(&self) -> usize {
+		self.deref().len()
+	}
+	fn from<I: IntoIterator<Item = Self::Item>, E: de::Error>(items: I) -> Result<Self, E> {
+		Ok(items.into_iter().collect())
+	}
+}
+impl<T> SerTupleNable for Box<[T]> {
+	type Item = T;
+	fn len(&self) -> usize {
+		self.deref().len()
+	}
+	fn to<SerializeTuple: ser::SerializeTuple, ItemSeeder: SerSeeder<Self::Item>>(
+		&self,
+		serialize_tuple: &mut SerializeTuple,
+		item_seeder: &ItemSeeder,
+	) -> Result<(), SerializeTuple::Error> {
+		SerTupleNable::to(self.deref(), serialize_tuple, item_seeder)
+	}
+}
 impl<Item> SerTupleNable for [Item] {
 	type Item = Item;
 	fn len(&self) -> usize {
@@ -566,22 +1004,25 @@ impl<'de, T: DeSeqable, ItemSeeder: Clone + DeSeeder<'de, T::Item>> de::Deserial
 			type Value = T;
 			fn expecting(
 				&self,
-				f: &mut std::fmt::Formatter<'_>,
-			) -> std::result::Result<(), std::fmt::Error> {
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
 				write!(f, "Seq")
 			}
 
 			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
 				let mut error = Ok(());
-				let array = T::from(iter::from_fn(|| {
-					match seq.next_element_seed(self.0.clone().seed()) {
+				let size_hint = seq.size_hint();
+				let array = T::from_hinted(
+					iter::from_fn(|| match seq.next_element_seed(self.0.clone().seed()) {
 						Ok(next) => next,
 						Err(e) => {
 							error = Err(e);
 							None
 						}
-					}
-				}))?;
+					}),
+					size_hint,
+				)?;
+				error?;
 				Ok(array)
 			}
 		}
@@ -610,6 +1051,16 @@ impl<'a, T: SerSeqable, ItemSeeder: SerSeeder<T::Item>> ser::Serialize
 pub trait DeSeqable: Sized {
 	type Item;
 	fn from<I: IntoIterator<Item = Self::Item>, E: de::Error>(items: I) -> Result<Self, E>;
+
+	/// Like [`DeSeqable::from`], but with the deserializer's [`size_hint`](`de::SeqAccess::size_hint`) to preallocate with.  
+	/// The hint isn't trusted, so implementations should cap how much they reserve up front.
+	fn from_hinted<I: IntoIterator<Item = Self::Item>, E: de::Error>(
+		items: I,
+		size_hint: Option<usize>,
+	) -> Result<Self, E> {
+		let _ = size_hint;
+		Self::from(items)
+	}
 }
 /// See [`Seq`].
 pub trait SerSeqable {
@@ -631,6 +1082,22 @@ impl<T> DeSeqable for Vec<T> {
 	fn from<I: IntoIterator<Item = Self::Item>, E: de::Error>(items: I) -> Result<Self, E> {
 		Ok(items.into_iter().collect())
 	}
+	fn from_hinted<I: IntoIterator<Item = Self::Item>, E: de::Error>(
+		items: I,
+		size_hint: Option<usize>,
+	) -> Result<Self, E> {
+		let mut vec = Vec::with_capacity(cautious_capacity::<T>(size_hint));
+		vec.extend(items);
+		Ok(vec)
+	}
+}
+
+/// Caps preallocation from untrusted length hints at about 1 MiB.
+fn cautious_capacity<T>(size_hint: Option<usize>) -> usize {
+	const MAX_PREALLOCATION: usize = 1024 * 1024;
+	size_hint
+		.unwrap_or(0)
+		.min(MAX_PREALLOCATION / mem::size_of::<T>().max(1))
 }
 impl<T> SerSeqable for Vec<T> {
 	type Item = T;
@@ -665,104 +1132,175 @@ impl<Item> SerSeqable for [Item] {
 	}
 }
 
-/// [`Vec<_>`] as length-prefixed tuple.  
-/// (Usage: [`Tuple::of(length_seeder: --Seeder<usize>, item_seeder)`])
+/// [`Vec<_>`] (or another [`DeTupleNable`]/[`SerTupleNable`] container) as length-prefixed tuple.  
+/// (Usage: [`LengthPrefixed(length_seeder: --Seeder<usize>, item_seeder)`])
 #[derive(Debug, Copy, Clone)]
 pub struct LengthPrefixed<LengthSeeder, ItemSeeder>(pub LengthSeeder, pub ItemSeeder);
 
-impl<'de, LengthSeeder: DeSeeder<'de, usize>, ItemSeeder: DeSeeder<'de, Item> + Clone, Item>
-	DeSeeder<'de, Vec<Item>> for LengthPrefixed<LengthSeeder, ItemSeeder>
+impl<'de, LengthSeeder: DeSeeder<'de, usize>, ItemSeeder: DeSeeder<'de, T::Item> + Clone, T>
+	DeSeeder<'de, T> for LengthPrefixed<LengthSeeder, ItemSeeder>
+where
+	T: DeTupleNable,
 {
-	type Seed = LengthPrefixedSeed<LengthSeeder, ItemSeeder, Item>;
+	type Seed = LengthPrefixedSeed<LengthSeeder, ItemSeeder, T>;
 	fn seed(self) -> Self::Seed {
 		LengthPrefixedSeed(self.0, self.1, PhantomData)
 	}
 }
 
-pub struct LengthPrefixedSeed<LengthSeeder, ItemSeeder, Item>(
+pub struct LengthPrefixedSeed<LengthSeeder, ItemSeeder, T>(
 	pub LengthSeeder,
 	pub ItemSeeder,
-	pub PhantomData<Item>,
+	pub PhantomData<T>,
 );
 
-impl<'de, LengthSeeder: DeSeeder<'de, usize>, ItemSeeder: DeSeeder<'de, Item> + Clone, Item>
-	de::DeserializeSeed<'de> for LengthPrefixedSeed<LengthSeeder, ItemSeeder, Item>
+impl<'de, LengthSeeder: DeSeeder<'de, usize>, ItemSeeder: DeSeeder<'de, T::Item> + Clone, T>
+	de::DeserializeSeed<'de> for LengthPrefixedSeed<LengthSeeder, ItemSeeder, T>
+where
+	T: DeTupleNable,
 {
-	type Value = Vec<Item>;
+	type Value = T;
 	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
 	where
 		D: serde::Deserializer<'de>,
 	{
-		#[derive(Debug, seed)]
-		#[seed_generics_de('de, LengthSeeder: DeSeeder<'de, usize>, ItemSeeder: DeSeeder<'de, Item> + Clone)]
-		#[seed_args(length_seeder: LengthSeeder, item_seeder: ItemSeeder)]
-		struct LengthPrefixedLayout<Item> {
-			#[seeded(length_seeder)]
-			length: usize,
+		struct Visitor<LengthSeeder, ItemSeeder, T>(LengthSeeder, ItemSeeder, PhantomData<T>);
+		impl<
+				'de,
+				LengthSeeder: DeSeeder<'de, usize>,
+				ItemSeeder: DeSeeder<'de, T::Item> + Clone,
+				T: DeTupleNable,
+			> de::Visitor<'de> for Visitor<LengthSeeder, ItemSeeder, T>
+		{
+			type Value = T;
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "LengthPrefixed")
+			}
 
-			#[seeded(TupleN(length, item_seeder))]
-			data: Vec<Item>,
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let Visitor(length_seeder, item_seeder, _) = self;
+				let length = seq
+					.next_element_seed(length_seeder.seed())?
+					.ok_or_else(|| de::Error::invalid_length(0, &"LengthPrefixed"))?;
+				seq.next_element_seed(TupleNSeed(length, item_seeder, PhantomData))?
+					.ok_or_else(|| de::Error::invalid_length(1, &"LengthPrefixed"))
+			}
 		}
 
-		LengthPrefixedLayout::seed(self.0, self.1)
-			.deserialize(deserializer)?
-			.data
-			.pipe(Ok)
+		deserializer.deserialize_tuple(2, Visitor(self.0, self.1, PhantomData))
 	}
 }
 
-impl<LengthSeeder: SerSeeder<usize>, ItemSeeder: SerSeeder<Item>, Item> SerSeeder<Vec<Item>>
+impl<LengthSeeder: SerSeeder<usize>, ItemSeeder: SerSeeder<T::Item>, T: SerTupleNable> SerSeeder<T>
 	for LengthPrefixed<LengthSeeder, ItemSeeder>
 {
-	fn seeded<'s>(&'s self, value: &'s Vec<Item>) -> Seeded<'s> {
+	fn seeded<'s>(&'s self, value: &'s T) -> Seeded<'s> {
 		Box::new(LengthPrefixedSeeded(&self.0, &self.1, value))
 	}
 }
 
-struct LengthPrefixedSeeded<'a, LengthSeeder, ItemSeeder, Item>(
+struct LengthPrefixedSeeded<'a, LengthSeeder, ItemSeeder, T>(
 	&'a LengthSeeder,
 	&'a ItemSeeder,
-	&'a Vec<Item>,
+	&'a T,
 );
 
-impl<'a, LengthSeeder: SerSeeder<usize>, ItemSeeder: SerSeeder<Item>, Item> ser::Serialize
-	for LengthPrefixedSeeded<'a, LengthSeeder, ItemSeeder, Item>
+impl<'a, LengthSeeder: SerSeeder<usize>, ItemSeeder: SerSeeder<T::Item>, T: SerTupleNable>
+	ser::Serialize for LengthPrefixedSeeded<'a, LengthSeeder, ItemSeeder, T>
 {
 	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
 	where
 		S: serde::Serializer,
 	{
-		#[derive(Debug, seeded)]
-		#[seed_generics('ser, LengthSeeder: 'ser + SerSeeder<usize>, ItemSeeder: 'ser + SerSeeder<Item>)]
-		#[seed_args(length_seeder: &'ser LengthSeeder, item_seeder: &'ser ItemSeeder)]
-		struct LengthPrefixedLayout<'a, Item> {
-			#[seeded(length_seeder)]
-			length: usize,
-
-			#[seeded(TupleN(*length, item_seeder))]
-			data: &'a Vec<Item>,
-		}
-
-		LengthPrefixedLayout {
-			length: self.2.len(),
-			data: self.2,
-		}
-		.seeded(self.0, self.1)
-		.serialize(serializer)
+		let mut serialize_tuple = serializer.serialize_tuple(2)?;
+		serialize_tuple.serialize_element(&self.0.seeded(&self.2.len()))?;
+		serialize_tuple.serialize_element(&TupleNSeeded(self.2, self.2.len(), self.1))?;
+		serialize_tuple.end()
 	}
 }
 
-#[derive(Debug, Copy, Clone)]
-pub struct SerdeLike;
-impl<T: ser::Serialize> SerSeeder<T> for SerdeLike {
-	fn seeded<'s>(&self, value: &'s T) -> Seeded<'s> {
-		Box::new(value)
+impl<LengthSeeder, ItemSeeder> LengthPrefixed<LengthSeeder, ItemSeeder> {
+	/// Like [`LengthPrefixed`], but fails before reading any items if the stored length exceeds `max_len`.
+	pub fn bounded(
+		length_seeder: LengthSeeder,
+		item_seeder: ItemSeeder,
+		max_len: usize,
+	) -> LengthPrefixed<MaxLength<LengthSeeder>, ItemSeeder> {
+		LengthPrefixed(MaxLength(max_len, length_seeder), item_seeder)
 	}
 }
-impl<'de, T: de::Deserialize<'de>> DeSeeder<'de, T> for SerdeLike {
-	type Seed = PhantomData<T>;
+
+/// Length that may not exceed a maximum, to guard against allocating for bogus lengths in untrusted input.  
+/// Lengths over the maximum are rejected in both directions.  
+/// (Parameters: maximum length, length [`Seeder`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct MaxLength<LengthSeeder>(pub usize, pub LengthSeeder);
+impl<'de, LengthSeeder: DeSeeder<'de, usize>> DeSeeder<'de, usize> for MaxLength<LengthSeeder> {
+	type Seed = MaxLengthSeed<LengthSeeder>;
 	fn seed(self) -> Self::Seed {
-		PhantomData
+		MaxLengthSeed(self.0, self.1)
+	}
+}
+impl<LengthSeeder: SerSeeder<usize>> SerSeeder<usize> for MaxLength<LengthSeeder> {
+	fn seeded<'s>(&'s self, value: &'s usize) -> Seeded<'s> {
+		Box::new(MaxLengthSeeded(value, self.0, &self.1))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct MaxLengthSeed<LengthSeeder>(usize, LengthSeeder);
+impl<'de, LengthSeeder: DeSeeder<'de, usize>> de::DeserializeSeed<'de>
+	for MaxLengthSeed<LengthSeeder>
+{
+	type Value = usize;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let length = self.1.seed().deserialize(deserializer)?;
+		if length > self.0 {
+			return Err(de::Error::invalid_length(
+				length,
+				&format!("at most {}", self.0).as_str(),
+			));
+		}
+		Ok(length)
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct MaxLengthSeeded<'a, LengthSeeder>(&'a usize, usize, &'a LengthSeeder);
+impl<'a, LengthSeeder: SerSeeder<usize>> ser::Serialize for MaxLengthSeeded<'a, LengthSeeder> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		if *self.0 > self.1 {
+			return Err(ser::Error::custom(format_args!(
+				"Tried to serialise length {} over maximum {}",
+				self.0, self.1
+			)));
+		}
+		self.2.seeded(self.0).serialize(serializer)
+	}
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SerdeLike;
+impl<T: ser::Serialize> SerSeeder<T> for SerdeLike {
+	fn seeded<'s>(&self, value: &'s T) -> Seeded<'s> {
+		Box::new(value)
+	}
+}
+impl<'de, T: de::Deserialize<'de>> DeSeeder<'de, T> for SerdeLike {
+	type Seed = PhantomData<T>;
+	fn seed(self) -> Self::Seed {
+		PhantomData
 	}
 }
 
@@ -824,7 +1362,159 @@ impl TryAsU32able for usize {
 		usize(repr).pipe(Ok)
 	}
 	fn to<E: ser::Error>(&self) -> Result<u32, E> {
-		u32(*self).map_err(ser::Error::custom)
+		u32(*self).map_err(|_| {
+			ser::Error::custom(format_args!(
+				"{} (usize) is out of range for u32 ({}..={})",
+				self,
+				u32::MIN,
+				u32::MAX,
+			))
+		})
+	}
+}
+
+/// Fallible u16-storage.  
+/// (Parameters: u16 [`Seeder`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct TryAsU16<U16Seeder>(pub U16Seeder);
+impl<'d, T: TryAsU16able, U16Seeder: DeSeeder<'d, u16>> DeSeeder<'d, T> for TryAsU16<U16Seeder> {
+	type Seed = TryAsU16Seed<T, U16Seeder>;
+	fn seed(self) -> Self::Seed {
+		TryAsU16Seed(self.0, PhantomData)
+	}
+}
+impl<T: TryAsU16able, U16Seeder: SerSeeder<u16>> SerSeeder<T> for TryAsU16<U16Seeder> {
+	fn seeded<'s>(&'s self, value: &'s T) -> Seeded<'s> {
+		Box::new(TryAsU16Seeded(value, &self.0))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct TryAsU16Seed<T, U16Seeder>(U16Seeder, PhantomData<T>);
+impl<'de, T: TryAsU16able, U16Seeder: DeSeeder<'de, u16>> de::DeserializeSeed<'de>
+	for TryAsU16Seed<T, U16Seeder>
+{
+	type Value = T;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		self.0.seed().deserialize(deserializer)?.pipe(T::from)
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct TryAsU16Seeded<'a, T, U16Seeder>(&'a T, &'a U16Seeder);
+impl<'a, T: TryAsU16able, U16Seeder: SerSeeder<u16>> ser::Serialize
+	for TryAsU16Seeded<'a, T, U16Seeder>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		self.0
+			.to()?
+			.pipe(|repr| self.1.seeded(&repr).serialize(serializer))
+	}
+}
+
+/// See [`TryAsU16`].
+pub trait TryAsU16able: Sized {
+	fn from<E: de::Error>(repr: u16) -> Result<Self, E>;
+	fn to<E: ser::Error>(&self) -> Result<u16, E>;
+}
+
+impl TryAsU16able for usize {
+	fn from<E: de::Error>(repr: u16) -> Result<Self, E> {
+		Ok(repr.into())
+	}
+	fn to<E: ser::Error>(&self) -> Result<u16, E> {
+		u16::try_from(*self).map_err(|_| {
+			ser::Error::custom(format_args!(
+				"{} (usize) is out of range for u16 ({}..={})",
+				self,
+				u16::MIN,
+				u16::MAX,
+			))
+		})
+	}
+}
+
+/// Fallible u64-storage.  
+/// (Parameters: u64 [`Seeder`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct TryAsU64<U64Seeder>(pub U64Seeder);
+impl<'d, T: TryAsU64able, U64Seeder: DeSeeder<'d, u64>> DeSeeder<'d, T> for TryAsU64<U64Seeder> {
+	type Seed = TryAsU64Seed<T, U64Seeder>;
+	fn seed(self) -> Self::Seed {
+		TryAsU64Seed(self.0, PhantomData)
+	}
+}
+impl<T: TryAsU64able, U64Seeder: SerSeeder<u64>> SerSeeder<T> for TryAsU64<U64Seeder> {
+	fn seeded<'s>(&'s self, value: &'s T) -> Seeded<'s> {
+		Box::new(TryAsU64Seeded(value, &self.0))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct TryAsU64Seed<T, U64Seeder>(U64Seeder, PhantomData<T>);
+impl<'de, T: TryAsU64able, U64Seeder: DeSeeder<'de, u64>> de::DeserializeSeed<'de>
+	for TryAsU64Seed<T, U64Seeder>
+{
+	type Value = T;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		self.0.seed().deserialize(deserializer)?.pipe(T::from)
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct TryAsU64Seeded<'a, T, U64Seeder>(&'a T, &'a U64Seeder);
+impl<'a, T: TryAsU64able, U64Seeder: SerSeeder<u64>> ser::Serialize
+	for TryAsU64Seeded<'a, T, U64Seeder>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		self.0
+			.to()?
+			.pipe(|repr| self.1.seeded(&repr).serialize(serializer))
+	}
+}
+
+/// See [`TryAsU64`].
+pub trait TryAsU64able: Sized {
+	fn from<E: de::Error>(repr: u64) -> Result<Self, E>;
+	fn to<E: ser::Error>(&self) -> Result<u64, E>;
+}
+
+impl TryAsU64able for usize {
+	fn from<E: de::Error>(repr: u64) -> Result<Self, E> {
+		usize::try_from(repr).map_err(|_| {
+			de::Error::custom(format_args!(
+				"{} (u64) is out of range for usize ({}..={})",
+				repr,
+				usize::MIN,
+				usize::MAX,
+			))
+		})
+	}
+	fn to<E: ser::Error>(&self) -> Result<u64, E> {
+		u64::try_from(*self).map_err(|_| {
+			ser::Error::custom(format_args!(
+				"{} (usize) is out of range for u64 ({}..={})",
+				self,
+				u64::MIN,
+				u64::MAX,
+			))
+		})
 	}
 }
 
@@ -883,55 +1573,66 @@ pub trait TryAsI32able: Sized {
 
 impl TryAsI32able for usize {
 	fn from<E: de::Error>(repr: i32) -> Result<Self, E> {
-		usize(repr).map_err(de::Error::custom)
+		usize(repr).map_err(|_| {
+			de::Error::custom(format_args!(
+				"{} (i32) is out of range for usize ({}..={})",
+				repr,
+				usize::MIN,
+				usize::MAX,
+			))
+		})
 	}
 	fn to<E: ser::Error>(&self) -> Result<i32, E> {
-		i32(*self).map_err(ser::Error::custom)
+		i32(*self).map_err(|_| {
+			ser::Error::custom(format_args!(
+				"{} (usize) is out of range for i32 ({}..={})",
+				self,
+				i32::MIN,
+				i32::MAX,
+			))
+		})
 	}
 }
 
-/// String as Windows-1252 storage.  
-/// (Parameters: Vec<u8> [`Seeder`])
+/// Packed DOS date/time storage, with the date in the high and the time in the low 16 bits.  
+/// Years count from 1980 and seconds are stored with 2-second granularity.  
+/// (Parameters: u32 [`Seeder`])
 #[derive(Debug, Copy, Clone, Default)]
-pub struct Windows1252<BytesSeeder>(pub BytesSeeder);
-impl<'de, T: DeWindows1252able<'de>, BytesSeeder: DeSeeder<'de, Vec<u8>>> DeSeeder<'de, T>
-	for Windows1252<BytesSeeder>
+pub struct DosDateTime<U32Seeder>(pub U32Seeder);
+impl<'d, T: DosDateTimeable, U32Seeder: DeSeeder<'d, u32>> DeSeeder<'d, T>
+	for DosDateTime<U32Seeder>
 {
-	type Seed = Windows1252Seed<T, BytesSeeder>;
+	type Seed = DosDateTimeSeed<T, U32Seeder>;
 	fn seed(self) -> Self::Seed {
-		Windows1252Seed(self.0, PhantomData)
+		DosDateTimeSeed(self.0, PhantomData)
 	}
 }
-impl<T: SerWindows1252able, BytesSeeder: SerSeeder<Vec<u8>>> SerSeeder<T>
-	for Windows1252<BytesSeeder>
-{
+impl<T: DosDateTimeable, U32Seeder: SerSeeder<u32>> SerSeeder<T> for DosDateTime<U32Seeder> {
 	fn seeded<'s>(&'s self, value: &'s T) -> Seeded<'s> {
-		Box::new(Windows1252Seeded(value, &self.0))
+		Box::new(DosDateTimeSeeded(value, &self.0))
 	}
 }
 
 #[doc(hidden)]
 #[derive(Debug, Copy, Clone, Default)]
-pub struct Windows1252Seed<T, BytesSeeder>(BytesSeeder, PhantomData<T>);
-impl<'de, T: DeWindows1252able<'de>, BytesSeeder: DeSeeder<'de, Vec<u8>>> de::DeserializeSeed<'de>
-	for Windows1252Seed<T, BytesSeeder>
+pub struct DosDateTimeSeed<T, U32Seeder>(U32Seeder, PhantomData<T>);
+impl<'de, T: DosDateTimeable, U32Seeder: DeSeeder<'de, u32>> de::DeserializeSeed<'de>
+	for DosDateTimeSeed<T, U32Seeder>
 {
 	type Value = T;
 	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
 	where
 		D: serde::Deserializer<'de>,
 	{
-		let value = self.0.seed().deserialize(deserializer)?.pipe(T::from)?;
-		debug!("Decoded Windows-1252: {:?}", value);
-		Ok(value)
+		self.0.seed().deserialize(deserializer)?.pipe(T::from)
 	}
 }
 
 #[doc(hidden)]
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
-pub struct Windows1252Seeded<'a, T, BytesSeeder>(&'a T, &'a BytesSeeder);
-impl<'a, T: SerWindows1252able, BytesSeeder: SerSeeder<Vec<u8>>> ser::Serialize
-	for Windows1252Seeded<'a, T, BytesSeeder>
+pub struct DosDateTimeSeeded<'a, T, U32Seeder>(&'a T, &'a U32Seeder);
+impl<'a, T: DosDateTimeable, U32Seeder: SerSeeder<u32>> ser::Serialize
+	for DosDateTimeSeeded<'a, T, U32Seeder>
 {
 	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
 	where
@@ -943,26 +1644,6810 @@ impl<'a, T: SerWindows1252able, BytesSeeder: SerSeeder<Vec<u8>>> ser::Serialize
 	}
 }
 
-/// See [`Windows1252`].
-pub trait DeWindows1252able<'de>: Sized + Debug {
-	fn from<E: de::Error>(repr: Vec<u8>) -> Result<Self, E>;
+/// See [`DosDateTime`].
+pub trait DosDateTimeable: Sized {
+	fn from<E: de::Error>(repr: u32) -> Result<Self, E>;
+	fn to<E: ser::Error>(&self) -> Result<u32, E>;
+}
+
+/// `(year, month, day, hour, minute, second)`
+impl DosDateTimeable for (u16, u8, u8, u8, u8, u8) {
+	fn from<E: de::Error>(repr: u32) -> Result<Self, E> {
+		let (date, time) = ((repr >> 16) as u16, repr as u16);
+		let year = 1980 + (date >> 9);
+		let month = (date >> 5 & 0b1111) as u8;
+		let day = (date & 0b1_1111) as u8;
+		let hour = (time >> 11) as u8;
+		let minute = (time >> 5 & 0b11_1111) as u8;
+		let second = (time & 0b1_1111) as u8 * 2;
+		if !(1..=12).contains(&month) || day == 0 || hour > 23 || minute > 59 || second > 59 {
+			return Err(de::Error::invalid_value(
+				de::Unexpected::Unsigned(repr as u64),
+				&"DOS date/time",
+			));
+		}
+		Ok((year, month, day, hour, minute, second))
+	}
+	fn to<E: ser::Error>(&self) -> Result<u32, E> {
+		let &(year, month, day, hour, minute, second) = self;
+		if !(1980..=2107).contains(&year)
+			|| !(1..=12).contains(&month)
+			|| !(1..=31).contains(&day)
+			|| hour > 23
+			|| minute > 59
+			|| second > 59
+		{
+			return Err(ser::Error::custom(format_args!(
+				"{:?} is not representable as DOS date/time",
+				self
+			)));
+		}
+		if second % 2 != 0 {
+			return Err(ser::Error::custom(format_args!(
+				"DOS time has 2-second granularity, but second was {}",
+				second
+			)));
+		}
+		let date = (year - 1980) << 9 | (month as u16) << 5 | day as u16;
+		let time = (hour as u16) << 11 | (minute as u16) << 5 | (second / 2) as u16;
+		Ok((date as u32) << 16 | time as u32)
+	}
+}
+
+/// [`Vec<u8>`] of 6-bit units packed into bytes, prefixed by the unit count.  
+/// Units are packed most significant bit first, so the first unit occupies the top six bits of the first byte.
+/// A partial trailing byte is padded with zero bits.  
+/// (Parameters: length [`Seeder`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct SixBit<LengthSeeder>(pub LengthSeeder);
+impl<'de, LengthSeeder: DeSeeder<'de, usize>> DeSeeder<'de, Vec<u8>> for SixBit<LengthSeeder> {
+	type Seed = SixBitSeed<LengthSeeder>;
+	fn seed(self) -> Self::Seed {
+		SixBitSeed(self.0)
+	}
 }
-/// See [`Windows1252`].
-pub trait SerWindows1252able: Sized {
-	fn to<E: ser::Error>(&self) -> Result<Vec<u8>, E>;
+impl<LengthSeeder: SerSeeder<usize>> SerSeeder<Vec<u8>> for SixBit<LengthSeeder> {
+	fn seeded<'s>(&'s self, value: &'s Vec<u8>) -> Seeded<'s> {
+		Box::new(SixBitSeeded(value, &self.0))
+	}
 }
 
-impl<'de> DeWindows1252able<'de> for String {
-	fn from<E: de::Error>(repr: Vec<u8>) -> Result<Self, E> {
-		WINDOWS_1252
-			.decode(repr.as_ref(), DecoderTrap::Strict)
-			.map_err(de::Error::custom)
+/// [`None`] on overflow.
+fn six_bit_packed_len(units: usize) -> Option<usize> {
+	units
+		.checked_mul(6)
+		.map(|bits| bits / 8 + (bits % 8 != 0) as usize)
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct SixBitSeed<LengthSeeder>(LengthSeeder);
+impl<'de, LengthSeeder: DeSeeder<'de, usize>> de::DeserializeSeed<'de>
+	for SixBitSeed<LengthSeeder>
+{
+	type Value = Vec<u8>;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<LengthSeeder>(LengthSeeder);
+		impl<'de, LengthSeeder: DeSeeder<'de, usize>> de::Visitor<'de> for Visitor<LengthSeeder> {
+			type Value = Vec<u8>;
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "SixBit")
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let length = seq
+					.next_element_seed(self.0.seed())?
+					.ok_or_else(|| de::Error::invalid_length(0, &"unit count and packed units"))?;
+				let packed_len = six_bit_packed_len(length).ok_or_else(|| {
+					de::Error::custom(format_args!("SixBit unit count {} overflows", length))
+				})?;
+				let packed: Vec<u8> = seq
+					.next_element_seed(TupleN(packed_len, SerdeLike).seed())?
+					.ok_or_else(|| de::Error::invalid_length(1, &"unit count and packed units"))?;
+
+				let mut units = Vec::with_capacity(length);
+				let (mut buffer, mut bits) = (0_u16, 0);
+				for byte in packed {
+					buffer = buffer << 8 | byte as u16;
+					bits += 8;
+					while bits >= 6 && units.len() < length {
+						bits -= 6;
+						units.push((buffer >> bits) as u8 & 0b11_1111);
+					}
+					buffer &= (1 << bits) - 1;
+				}
+				Ok(units)
+			}
+		}
+
+		deserializer.deserialize_tuple(2, Visitor(self.0))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct SixBitSeeded<'a, LengthSeeder>(&'a Vec<u8>, &'a LengthSeeder);
+impl<'a, LengthSeeder: SerSeeder<usize>> ser::Serialize for SixBitSeeded<'a, LengthSeeder> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let packed_len = six_bit_packed_len(self.0.len()).ok_or_else(|| {
+			ser::Error::custom(format_args!("SixBit unit count {} overflows", self.0.len()))
+		})?;
+		let mut packed = Vec::with_capacity(packed_len);
+		let (mut buffer, mut bits) = (0_u16, 0);
+		for &unit in self.0 {
+			if unit > 0b11_1111 {
+				return Err(ser::Error::custom(format_args!(
+					"Tried to serialise {} as SixBit unit",
+					unit
+				)));
+			}
+			buffer = buffer << 6 | unit as u16;
+			bits += 6;
+			if bits >= 8 {
+				bits -= 8;
+				packed.push((buffer >> bits) as u8);
+				buffer &= (1 << bits) - 1;
+			}
+		}
+		if bits > 0 {
+			packed.push((buffer << (8 - bits)) as u8);
+		}
+
+		let mut serialize_tuple = serializer.serialize_tuple(2)?;
+		serialize_tuple.serialize_element(&self.1.seeded(&self.0.len()))?;
+		serialize_tuple.serialize_element(&TupleN(packed.len(), SerdeLike).seeded(&packed))?;
+		serialize_tuple.end()
+	}
+}
+
+/// Trailing optional value, present only if its sequence has any elements left.  
+/// Against a buffered deserializer that ends sequences with its input, this reads the inner value iff any bytes remain,
+/// which makes it suitable only as last field.  
+/// (Parameters: inner [`Seeder`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct IfRemaining<Inner>(pub Inner);
+impl<'de, T, Inner: DeSeeder<'de, T>> DeSeeder<'de, Option<T>> for IfRemaining<Inner> {
+	type Seed = IfRemainingSeed<T, Inner>;
+	fn seed(self) -> Self::Seed {
+		IfRemainingSeed(self.0, PhantomData)
+	}
+}
+impl<T, Inner: SerSeeder<T>> SerSeeder<Option<T>> for IfRemaining<Inner> {
+	fn seeded<'s>(&'s self, value: &'s Option<T>) -> Seeded<'s> {
+		Box::new(IfRemainingSeeded(value, &self.0))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct IfRemainingSeed<T, Inner>(Inner, PhantomData<T>);
+impl<'de, T, Inner: DeSeeder<'de, T>> de::DeserializeSeed<'de> for IfRemainingSeed<T, Inner> {
+	type Value = Option<T>;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<T, Inner>(Inner, PhantomData<T>);
+		impl<'de, T, Inner: DeSeeder<'de, T>> de::Visitor<'de> for Visitor<T, Inner> {
+			type Value = Option<T>;
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "IfRemaining")
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let value = seq.next_element_seed(self.0.seed())?;
+				trace!("IfRemaining present: {}", value.is_some());
+				Ok(value)
+			}
+		}
+
+		deserializer.deserialize_seq(Visitor(self.0, PhantomData))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct IfRemainingSeeded<'a, T, Inner>(&'a Option<T>, &'a Inner);
+impl<'a, T, Inner: SerSeeder<T>> ser::Serialize for IfRemainingSeeded<'a, T, Inner> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let mut serialize_seq = serializer.serialize_seq(self.0.iter().len().into())?;
+		if let Some(value) = self.0 {
+			serialize_seq.serialize_element(&self.1.seeded(value))?
+		}
+		serialize_seq.end()
+	}
+}
+
+/// [`String`] as UTF-16 code units, prefixed by the number of code units (not characters or bytes).  
+/// (Parameters: length [`Seeder`], u16 [`Seeder`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Utf16LengthPrefixed<LengthSeeder, U16Seeder>(pub LengthSeeder, pub U16Seeder);
+impl<'de, LengthSeeder: DeSeeder<'de, usize>, U16Seeder: Clone + DeSeeder<'de, u16>>
+	DeSeeder<'de, String> for Utf16LengthPrefixed<LengthSeeder, U16Seeder>
+{
+	type Seed = Utf16LengthPrefixedSeed<LengthSeeder, U16Seeder>;
+	fn seed(self) -> Self::Seed {
+		Utf16LengthPrefixedSeed(self.0, self.1)
+	}
+}
+impl<LengthSeeder: SerSeeder<usize>, U16Seeder: SerSeeder<u16>> SerSeeder<String>
+	for Utf16LengthPrefixed<LengthSeeder, U16Seeder>
+{
+	fn seeded<'s>(&'s self, value: &'s String) -> Seeded<'s> {
+		Box::new(Utf16LengthPrefixedSeeded(value, &self.0, &self.1))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Utf16LengthPrefixedSeed<LengthSeeder, U16Seeder>(LengthSeeder, U16Seeder);
+impl<'de, LengthSeeder: DeSeeder<'de, usize>, U16Seeder: Clone + DeSeeder<'de, u16>>
+	de::DeserializeSeed<'de> for Utf16LengthPrefixedSeed<LengthSeeder, U16Seeder>
+{
+	type Value = String;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<LengthSeeder, U16Seeder>(LengthSeeder, U16Seeder);
+		impl<'de, LengthSeeder: DeSeeder<'de, usize>, U16Seeder: Clone + DeSeeder<'de, u16>>
+			de::Visitor<'de> for Visitor<LengthSeeder, U16Seeder>
+		{
+			type Value = String;
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "code unit count and UTF-16 code units")
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let length = seq.next_element_seed(self.0.seed())?.ok_or_else(|| {
+					de::Error::invalid_length(0, &"code unit count and UTF-16 code units")
+				})?;
+				let units: Vec<u16> = seq
+					.next_element_seed(TupleNSeed(length, self.1, PhantomData))?
+					.ok_or_else(|| {
+						de::Error::invalid_length(1, &"code unit count and UTF-16 code units")
+					})?;
+				let value = String::from_utf16(&units).map_err(de::Error::custom)?;
+				debug!("Decoded UTF-16: {:?}", value);
+				Ok(value)
+			}
+		}
+
+		deserializer.deserialize_tuple(2, Visitor(self.0, self.1))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct Utf16LengthPrefixedSeeded<'a, LengthSeeder, U16Seeder>(
+	&'a String,
+	&'a LengthSeeder,
+	&'a U16Seeder,
+);
+impl<'a, LengthSeeder: SerSeeder<usize>, U16Seeder: SerSeeder<u16>> ser::Serialize
+	for Utf16LengthPrefixedSeeded<'a, LengthSeeder, U16Seeder>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let units: Vec<u16> = self.0.encode_utf16().collect();
+		let mut serialize_tuple = serializer.serialize_tuple(2)?;
+		serialize_tuple.serialize_element(&self.1.seeded(&units.len()))?;
+		serialize_tuple.serialize_element(&TupleNSeeded(&units, units.len(), self.2))?;
+		serialize_tuple.end()
 	}
 }
-impl SerWindows1252able for String {
-	fn to<E: ser::Error>(&self) -> Result<Vec<u8>, E> {
-		WINDOWS_1252
-			.encode(self, encoding::EncoderTrap::Strict)
-			.map_err(ser::Error::custom)
+
+/// Seq whose first element is a distinct header, as `(head, tail)`.  
+/// (Parameters: head [`Seeder`], tail item [`Seeder`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct HeadThenRest<HeadSeeder, TailSeeder>(pub HeadSeeder, pub TailSeeder);
+impl<'de, Head, Tail, HeadSeeder: DeSeeder<'de, Head>, TailSeeder: Clone + DeSeeder<'de, Tail>>
+	DeSeeder<'de, (Head, Vec<Tail>)> for HeadThenRest<HeadSeeder, TailSeeder>
+{
+	type Seed = HeadThenRestSeed<HeadSeeder, TailSeeder, Head, Tail>;
+	fn seed(self) -> Self::Seed {
+		HeadThenRestSeed(self.0, self.1, PhantomData)
+	}
+}
+impl<Head, Tail, HeadSeeder: SerSeeder<Head>, TailSeeder: SerSeeder<Tail>>
+	SerSeeder<(Head, Vec<Tail>)> for HeadThenRest<HeadSeeder, TailSeeder>
+{
+	fn seeded<'s>(&'s self, value: &'s (Head, Vec<Tail>)) -> Seeded<'s> {
+		Box::new(HeadThenRestSeeded(value, &self.0, &self.1))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct HeadThenRestSeed<HeadSeeder, TailSeeder, Head, Tail>(
+	HeadSeeder,
+	TailSeeder,
+	PhantomData<(Head, Tail)>,
+);
+impl<'de, Head, Tail, HeadSeeder: DeSeeder<'de, Head>, TailSeeder: Clone + DeSeeder<'de, Tail>>
+	de::DeserializeSeed<'de> for HeadThenRestSeed<HeadSeeder, TailSeeder, Head, Tail>
+{
+	type Value = (Head, Vec<Tail>);
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<HeadSeeder, TailSeeder, Head, Tail>(
+			HeadSeeder,
+			TailSeeder,
+			PhantomData<(Head, Tail)>,
+		);
+		impl<
+				'de,
+				Head,
+				Tail,
+				HeadSeeder: DeSeeder<'de, Head>,
+				TailSeeder: Clone + DeSeeder<'de, Tail>,
+			> de::Visitor<'de> for Visitor<HeadSeeder, TailSeeder, Head, Tail>
+		{
+			type Value = (Head, Vec<Tail>);
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "Seq with head")
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let head = seq
+					.next_element_seed(self.0.seed())?
+					.ok_or_else(|| de::Error::invalid_length(0, &"Seq with head"))?;
+				let mut tail = Vec::new();
+				while let Some(item) = seq.next_element_seed(self.1.clone().seed())? {
+					tail.push(item)
+				}
+				Ok((head, tail))
+			}
+		}
+
+		deserializer.deserialize_seq(Visitor(self.0, self.1, PhantomData))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct HeadThenRestSeeded<'a, HeadSeeder, TailSeeder, Head, Tail>(
+	&'a (Head, Vec<Tail>),
+	&'a HeadSeeder,
+	&'a TailSeeder,
+);
+impl<'a, Head, Tail, HeadSeeder: SerSeeder<Head>, TailSeeder: SerSeeder<Tail>> ser::Serialize
+	for HeadThenRestSeeded<'a, HeadSeeder, TailSeeder, Head, Tail>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let (head, tail) = self.0;
+		let mut serialize_seq = serializer.serialize_seq(Some(1 + tail.len()))?;
+		serialize_seq.serialize_element(&self.1.seeded(head))?;
+		for item in tail {
+			serialize_seq.serialize_element(&self.2.seeded(item))?
+		}
+		serialize_seq.end()
+	}
+}
+
+/// Unsigned integer split into a tag in its top `TAG_BITS` bits and a value in the remaining low bits, as `(tag, value)`.  
+/// (Parameters: unsigned integer [`Seeder`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct PackedTagValue<const TAG_BITS: u32, Inner>(pub Inner);
+impl<'de, const TAG_BITS: u32, T: Bitfieldable, Inner: DeSeeder<'de, T>> DeSeeder<'de, (T, T)>
+	for PackedTagValue<TAG_BITS, Inner>
+{
+	type Seed = PackedTagValueSeed<TAG_BITS, T, Inner>;
+	fn seed(self) -> Self::Seed {
+		PackedTagValueSeed(self.0, PhantomData)
+	}
+}
+impl<const TAG_BITS: u32, T: Bitfieldable, Inner: SerSeeder<T>> SerSeeder<(T, T)>
+	for PackedTagValue<TAG_BITS, Inner>
+{
+	fn seeded<'s>(&'s self, value: &'s (T, T)) -> Seeded<'s> {
+		Box::new(PackedTagValueSeeded::<TAG_BITS, _, _>(value, &self.0))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct PackedTagValueSeed<const TAG_BITS: u32, T, Inner>(Inner, PhantomData<T>);
+impl<'de, const TAG_BITS: u32, T: Bitfieldable, Inner: DeSeeder<'de, T>> de::DeserializeSeed<'de>
+	for PackedTagValueSeed<TAG_BITS, T, Inner>
+{
+	type Value = (T, T);
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let packed = self.0.seed().deserialize(deserializer)?.to_u64();
+		let value_bits = PackedValueBits::<TAG_BITS, T>::BITS;
+		Ok((
+			T::from_u64(packed.checked_shr(value_bits).unwrap_or(0)),
+			T::from_u64(packed & low_bits_mask(value_bits)),
+		))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct PackedTagValueSeeded<'a, const TAG_BITS: u32, T, Inner>(&'a (T, T), &'a Inner);
+impl<'a, const TAG_BITS: u32, T: Bitfieldable, Inner: SerSeeder<T>> ser::Serialize
+	for PackedTagValueSeeded<'a, TAG_BITS, T, Inner>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let (tag, value) = (self.0 .0.to_u64(), self.0 .1.to_u64());
+		let value_bits = PackedValueBits::<TAG_BITS, T>::BITS;
+		if tag & !low_bits_mask(TAG_BITS) != 0 || value & !low_bits_mask(value_bits) != 0 {
+			return Err(ser::Error::custom(format_args!(
+				"Tried to serialise PackedTagValue ({}, {}) with {} tag and {} value bits",
+				tag, value, TAG_BITS, value_bits
+			)));
+		}
+		T::from_u64(tag.checked_shl(value_bits).unwrap_or(0) | value)
+			.pipe(|packed| self.1.seeded(&packed).serialize(serializer))
+	}
+}
+
+/// The value width a [`PackedTagValue`] leaves in `T`. Using it fails to compile if the tag doesn't fit.
+struct PackedValueBits<const TAG_BITS: u32, T>(PhantomData<T>);
+impl<const TAG_BITS: u32, T: Bitfieldable> PackedValueBits<TAG_BITS, T> {
+	const BITS: u32 = {
+		assert!(
+			TAG_BITS <= T::BITS,
+			"PackedTagValue's TAG_BITS exceeds the width of its integer"
+		);
+		T::BITS - TAG_BITS
+	};
+}
+
+fn low_bits_mask(bits: u32) -> u64 {
+	1_u64.checked_shl(bits).map_or(u64::MAX, |bit| bit - 1)
+}
+
+/// See [`PackedTagValue`], [`SignedRepr`] and [`Bits`].
+pub trait Bitfieldable: Copy {
+	const BITS: u32;
+	fn from_u64(bits: u64) -> Self;
+	fn to_u64(self) -> u64;
+}
+
+macro_rules! impl_bitfieldable {
+	($($ty:ty),*$(,)?) => {$(
+		impl Bitfieldable for $ty {
+			const BITS: u32 = <$ty>::BITS;
+			fn from_u64(bits: u64) -> Self {
+				bits as $ty
+			}
+			fn to_u64(self) -> u64 {
+				self as u64
+			}
+		}
+	)*};
+}
+impl_bitfieldable!(u8, u16, u32, u64);
+
+/// Unsigned integer container split into `N` bit fields, the first of which occupies the highest bits,
+/// as array or tuple of unsigned integers.  
+/// The widths must add up to the container's size, and field values that don't fit their width are an error when writing.  
+/// (Usage: [`Bits::new([width, ...], container_seeder)`], Parameters: field widths, unsigned integer [`Seeder`], container type)
+#[derive(Debug, Copy, Clone)]
+pub struct Bits<const N: usize, Inner, Container>(
+	pub [u32; N],
+	pub Inner,
+	pub PhantomData<Container>,
+);
+impl<const N: usize, Inner, Container> Bits<N, Inner, Container> {
+	pub fn new(widths: [u32; N], container_seeder: Inner) -> Self {
+		Self(widths, container_seeder, PhantomData)
+	}
+}
+impl<
+		'de,
+		const N: usize,
+		T: Bitsable<N>,
+		Inner: DeSeeder<'de, Container>,
+		Container: Bitfieldable,
+	> DeSeeder<'de, T> for Bits<N, Inner, Container>
+{
+	type Seed = BitsSeed<N, T, Inner, Container>;
+	fn seed(self) -> Self::Seed {
+		BitsSeed(self.0, self.1, PhantomData)
+	}
+}
+impl<const N: usize, T: Bitsable<N>, Inner: SerSeeder<Container>, Container: Bitfieldable>
+	SerSeeder<T> for Bits<N, Inner, Container>
+{
+	fn seeded<'s>(&'s self, value: &'s T) -> Seeded<'s> {
+		Box::new(BitsSeeded(value, self.0, &self.1, PhantomData))
+	}
+}
+
+fn bits_width_error<Container: Bitfieldable>(widths: &[u32]) -> Option<String> {
+	let total: u32 = widths.iter().sum();
+	if total == Container::BITS {
+		None
+	} else {
+		Some(format!(
+			"Bits field widths {:?} add up to {} instead of the container's {} bits",
+			widths,
+			total,
+			Container::BITS
+		))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone)]
+pub struct BitsSeed<const N: usize, T, Inner, Container>(
+	[u32; N],
+	Inner,
+	PhantomData<(T, Container)>,
+);
+impl<
+		'de,
+		const N: usize,
+		T: Bitsable<N>,
+		Inner: DeSeeder<'de, Container>,
+		Container: Bitfieldable,
+	> de::DeserializeSeed<'de> for BitsSeed<N, T, Inner, Container>
+{
+	type Value = T;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let BitsSeed(widths, inner, _) = self;
+		if let Some(error) = bits_width_error::<Container>(&widths) {
+			return Err(de::Error::custom(error));
+		}
+		let packed = inner.seed().deserialize(deserializer)?.to_u64();
+		let mut shift = Container::BITS;
+		let fields = widths.map(|width| {
+			shift -= width;
+			packed.checked_shr(shift).unwrap_or(0) & low_bits_mask(width)
+		});
+		T::from_fields(fields).ok_or_else(|| {
+			de::Error::custom(format_args!(
+				"Bits fields {:?} don't fit the target integer types",
+				fields
+			))
+		})
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone)]
+pub struct BitsSeeded<'a, const N: usize, T, Inner, Container>(
+	&'a T,
+	[u32; N],
+	&'a Inner,
+	PhantomData<Container>,
+);
+impl<'a, const N: usize, T: Bitsable<N>, Inner: SerSeeder<Container>, Container: Bitfieldable>
+	ser::Serialize for BitsSeeded<'a, N, T, Inner, Container>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let BitsSeeded(value, widths, inner, _) = *self;
+		if let Some(error) = bits_width_error::<Container>(&widths) {
+			return Err(ser::Error::custom(error));
+		}
+		let mut packed = 0_u64;
+		for (i, (field, width)) in value.to_fields().iter().zip(widths).enumerate() {
+			if field & !low_bits_mask(width) != 0 {
+				return Err(ser::Error::custom(format_args!(
+					"Bits field {} value {} doesn't fit into {} bits",
+					i, field, width
+				)));
+			}
+			packed = packed.checked_shl(width).unwrap_or(0) | field;
+		}
+		Container::from_u64(packed).pipe(|packed| inner.seeded(&packed).serialize(serializer))
+	}
+}
+
+/// See [`Bits`].
+pub trait Bitsable<const N: usize>: Sized {
+	fn from_fields(fields: [u64; N]) -> Option<Self>;
+	fn to_fields(&self) -> [u64; N];
+}
+
+impl<const N: usize, U: Bitfieldable> Bitsable<N> for [U; N] {
+	fn from_fields(fields: [u64; N]) -> Option<Self> {
+		if fields
+			.iter()
+			.any(|field| field & !low_bits_mask(U::BITS) != 0)
+		{
+			return None;
+		}
+		Some(fields.map(U::from_u64))
+	}
+	fn to_fields(&self) -> [u64; N] {
+		self.map(U::to_u64)
+	}
+}
+
+macro_rules! impl_bitsable_tuple {
+	($($n:literal: $($T:ident $i:tt),+;)*) => {$(
+		impl<$($T: Bitfieldable),+> Bitsable<$n> for ($($T,)+) {
+			fn from_fields(fields: [u64; $n]) -> Option<Self> {
+				if $(fields[$i] & !low_bits_mask($T::BITS) != 0)||+ {
+					return None;
+				}
+				Some(($($T::from_u64(fields[$i]),)+))
+			}
+			fn to_fields(&self) -> [u64; $n] {
+				[$(self.$i.to_u64()),+]
+			}
+		}
+	)*};
+}
+impl_bitsable_tuple! {
+	2: A 0, B 1;
+	3: A 0, B 1, C 2;
+	4: A 0, B 1, C 2, D 3;
+}
+
+/// Seq of `(offset, item)`, where `offset` is the item's byte offset from the start of the input.  
+/// Only the items are written.
+/// Requires a [`raw::Deserializer`] to read.  
+/// (Parameters: item [`Seeder`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct OffsetTrackedSeq<ItemSeeder>(pub ItemSeeder);
+impl<'de, T, ItemSeeder: Clone + DeSeeder<'de, T>> DeSeeder<'de, Vec<(usize, T)>>
+	for OffsetTrackedSeq<ItemSeeder>
+{
+	type Seed = OffsetTrackedSeqSeed<T, ItemSeeder>;
+	fn seed(self) -> Self::Seed {
+		OffsetTrackedSeqSeed(self.0, PhantomData)
+	}
+}
+impl<T, ItemSeeder: SerSeeder<T>> SerSeeder<Vec<(usize, T)>> for OffsetTrackedSeq<ItemSeeder> {
+	fn seeded<'s>(&'s self, value: &'s Vec<(usize, T)>) -> Seeded<'s> {
+		Box::new(OffsetTrackedSeqSeeded(value, &self.0))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct OffsetTrackedSeqSeed<T, ItemSeeder>(ItemSeeder, PhantomData<T>);
+impl<'de, T, ItemSeeder: Clone + DeSeeder<'de, T>> de::DeserializeSeed<'de>
+	for OffsetTrackedSeqSeed<T, ItemSeeder>
+{
+	type Value = Vec<(usize, T)>;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<T, ItemSeeder>(ItemSeeder, PhantomData<T>);
+		impl<'de, T, ItemSeeder: Clone + DeSeeder<'de, T>> de::Visitor<'de> for Visitor<T, ItemSeeder> {
+			type Value = Vec<(usize, T)>;
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "OffsetTrackedSeq")
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let mut items = Vec::new();
+				while let Some(offset) = seq.next_element_seed(raw::OffsetSeed)? {
+					let item = seq
+						.next_element_seed(self.0.clone().seed())?
+						.ok_or_else(|| de::Error::invalid_length(items.len(), &self))?;
+					trace!(
+						"OffsetTrackedSeq item {} at offset {:#X}",
+						items.len(),
+						offset
+					);
+					items.push((offset, item));
+				}
+				Ok(items)
+			}
+		}
+
+		deserializer.deserialize_seq(Visitor(self.0, PhantomData))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct OffsetTrackedSeqSeeded<'a, T, ItemSeeder>(&'a Vec<(usize, T)>, &'a ItemSeeder);
+impl<'a, T, ItemSeeder: SerSeeder<T>> ser::Serialize for OffsetTrackedSeqSeeded<'a, T, ItemSeeder> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let mut serialize_seq = serializer.serialize_seq(self.0.len().into())?;
+		for (_, item) in self.0 {
+			serialize_seq.serialize_element(&self.1.seeded(item))?
+		}
+		serialize_seq.end()
+	}
+}
+
+/// Dense [`Vec<_>`] stored sparsely as seq of `(zero_run_length, value)` pairs, where zero is [`Default::default`].  
+/// Each pair expands to `zero_run_length` zeros followed by `value`, so trailing zeros are stored with a zero value.  
+/// Dense lengths over the maximum are rejected in both directions, since zero runs take up next to no input.  
+/// (Parameters: maximum dense length, run length [`Seeder`], value [`Seeder`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct SparseZeroRle<CountSeeder, ValueSeeder>(pub usize, pub CountSeeder, pub ValueSeeder);
+impl<
+		'de,
+		T: Clone + Default,
+		CountSeeder: Clone + DeSeeder<'de, usize>,
+		ValueSeeder: Clone + DeSeeder<'de, T>,
+	> DeSeeder<'de, Vec<T>> for SparseZeroRle<CountSeeder, ValueSeeder>
+{
+	type Seed = SparseZeroRleSeed<T, CountSeeder, ValueSeeder>;
+	fn seed(self) -> Self::Seed {
+		SparseZeroRleSeed(self.0, self.1, self.2, PhantomData)
+	}
+}
+impl<T: Default + PartialEq, CountSeeder: SerSeeder<usize>, ValueSeeder: SerSeeder<T>>
+	SerSeeder<Vec<T>> for SparseZeroRle<CountSeeder, ValueSeeder>
+{
+	fn seeded<'s>(&'s self, value: &'s Vec<T>) -> Seeded<'s> {
+		Box::new(SparseZeroRleSeeded(value, self.0, &self.1, &self.2))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct SparseZeroRleSeed<T, CountSeeder, ValueSeeder>(
+	usize,
+	CountSeeder,
+	ValueSeeder,
+	PhantomData<T>,
+);
+impl<
+		'de,
+		T: Clone + Default,
+		CountSeeder: Clone + DeSeeder<'de, usize>,
+		ValueSeeder: Clone + DeSeeder<'de, T>,
+	> de::DeserializeSeed<'de> for SparseZeroRleSeed<T, CountSeeder, ValueSeeder>
+{
+	type Value = Vec<T>;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<T, CountSeeder, ValueSeeder>(
+			usize,
+			CountSeeder,
+			ValueSeeder,
+			PhantomData<T>,
+		);
+		impl<
+				'de,
+				T: Clone + Default,
+				CountSeeder: Clone + DeSeeder<'de, usize>,
+				ValueSeeder: Clone + DeSeeder<'de, T>,
+			> de::Visitor<'de> for Visitor<T, CountSeeder, ValueSeeder>
+		{
+			type Value = Vec<T>;
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "SparseZeroRle")
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let mut dense = Vec::new();
+				let mut pairs = 0;
+				while let Some(zeros) = seq.next_element_seed(self.1.clone().seed())? {
+					let value = seq
+						.next_element_seed(self.2.clone().seed())?
+						.ok_or_else(|| de::Error::invalid_length(pairs * 2 + 1, &self))?;
+					let zeros_end = dense
+						.len()
+						.checked_add(zeros)
+						.filter(|&zeros_end| zeros_end < self.0)
+						.ok_or_else(|| {
+							de::Error::custom(format_args!(
+								"SparseZeroRle run of {} zeros after {} items exceeds maximum length {}",
+								zeros,
+								dense.len(),
+								self.0
+							))
+						})?;
+					dense.resize(zeros_end, T::default());
+					dense.push(value);
+					pairs += 1;
+				}
+				trace!(
+					"Expanded {} SparseZeroRle pairs to {} items.",
+					pairs,
+					dense.len()
+				);
+				Ok(dense)
+			}
+		}
+
+		deserializer.deserialize_seq(Visitor(self.0, self.1, self.2, PhantomData))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct SparseZeroRleSeeded<'a, T, CountSeeder, ValueSeeder>(
+	&'a Vec<T>,
+	usize,
+	&'a CountSeeder,
+	&'a ValueSeeder,
+);
+impl<'a, T: Default + PartialEq, CountSeeder: SerSeeder<usize>, ValueSeeder: SerSeeder<T>>
+	ser::Serialize for SparseZeroRleSeeded<'a, T, CountSeeder, ValueSeeder>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		if self.0.len() > self.1 {
+			return Err(ser::Error::custom(format_args!(
+				"Tried to serialise {} items as SparseZeroRle with maximum length {}",
+				self.0.len(),
+				self.1
+			)));
+		}
+		let zero = T::default();
+		let mut pairs = Vec::new();
+		let mut zeros = 0;
+		for value in self.0 {
+			if *value == zero {
+				zeros += 1;
+			} else {
+				pairs.push((zeros, value));
+				zeros = 0;
+			}
+		}
+		if zeros > 0 {
+			pairs.push((zeros - 1, &zero));
+		}
+
+		let mut serialize_seq = serializer.serialize_seq(Some(pairs.len() * 2))?;
+		for (zeros, value) in pairs {
+			serialize_seq.serialize_element(&self.2.seeded(&zeros))?;
+			serialize_seq.serialize_element(&self.3.seeded(value))?;
+		}
+		serialize_seq.end()
+	}
+}
+
+/// Stores a version as one of several equally long magic byte strings.  
+/// (Parameters: A `&[(&[u8], version)]` table of magics and the versions they stand for.)
+#[derive(Debug, Clone, Copy, PartialEq, Ord, PartialOrd, Eq)]
+pub struct VersionedMagic<'a, V>(pub &'a [(&'a [u8], V)]);
+impl<'a, 'de, V: Clone + Debug> DeSeeder<'de, V> for VersionedMagic<'a, V> {
+	type Seed = Self;
+	fn seed(self) -> Self::Seed {
+		self
+	}
+}
+impl<'a, V: PartialEq + Debug> SerSeeder<V> for VersionedMagic<'a, V> {
+	fn seeded<'s>(&'s self, value: &'s V) -> Seeded<'s> {
+		Box::new(VersionedMagicSeeded(value, self.0))
+	}
+}
+impl<'a, 'de, V: Clone + Debug> de::DeserializeSeed<'de> for VersionedMagic<'a, V> {
+	type Value = V;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let len = self.0.first().map_or(0, |(magic, _)| magic.len());
+		if self.0.iter().any(|(magic, _)| magic.len() != len) {
+			return Err(de::Error::custom(format_args!(
+				"VersionedMagic magics must all have the same length, but got {:?}",
+				self.0
+			)));
+		}
+
+		let received: Vec<u8> =
+			TupleNSeed(len, SerdeLike, PhantomData).deserialize(deserializer)?;
+		let (_, version) = self
+			.0
+			.iter()
+			.find(|(magic, _)| *magic == received.as_slice())
+			.ok_or_else(|| {
+				de::Error::invalid_value(
+					de::Unexpected::Bytes(&received),
+					&format!("one of {:?}", self.0).as_str(),
+				)
+			})?;
+		debug!("Matched magic for version {:?}", version);
+		Ok(version.clone())
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct VersionedMagicSeeded<'a, V>(&'a V, &'a [(&'a [u8], V)]);
+impl<'a, V: PartialEq + Debug> ser::Serialize for VersionedMagicSeeded<'a, V> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let (magic, _) = self
+			.1
+			.iter()
+			.find(|(_, version)| version == self.0)
+			.ok_or_else(|| ser::Error::custom(format_args!("No magic for version {:?}", self.0)))?;
+		Literal(magic).serialize(serializer)
+	}
+}
+
+/// `(capacity, items)` stored as capacity, item count, the items and then zero bytes in place of the unused slots.  
+/// (Parameters: length [`Seeder`] for both capacity and count, item [`Seeder`], byte size of each unused slot)
+#[derive(Debug, Copy, Clone, Default)]
+pub struct CapacityPrefixed<LengthSeeder, ItemSeeder>(pub LengthSeeder, pub ItemSeeder, pub usize);
+impl<'de, T, LengthSeeder: Clone + DeSeeder<'de, usize>, ItemSeeder: Clone + DeSeeder<'de, T>>
+	DeSeeder<'de, (usize, Vec<T>)> for CapacityPrefixed<LengthSeeder, ItemSeeder>
+{
+	type Seed = CapacityPrefixedSeed<T, LengthSeeder, ItemSeeder>;
+	fn seed(self) -> Self::Seed {
+		CapacityPrefixedSeed(self.0, self.1, self.2, PhantomData)
+	}
+}
+impl<T, LengthSeeder: SerSeeder<usize>, ItemSeeder: SerSeeder<T>> SerSeeder<(usize, Vec<T>)>
+	for CapacityPrefixed<LengthSeeder, ItemSeeder>
+{
+	fn seeded<'s>(&'s self, value: &'s (usize, Vec<T>)) -> Seeded<'s> {
+		Box::new(CapacityPrefixedSeeded(value, &self.0, &self.1, self.2))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct CapacityPrefixedSeed<T, LengthSeeder, ItemSeeder>(
+	LengthSeeder,
+	ItemSeeder,
+	usize,
+	PhantomData<T>,
+);
+impl<'de, T, LengthSeeder: Clone + DeSeeder<'de, usize>, ItemSeeder: Clone + DeSeeder<'de, T>>
+	de::DeserializeSeed<'de> for CapacityPrefixedSeed<T, LengthSeeder, ItemSeeder>
+{
+	type Value = (usize, Vec<T>);
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<T, LengthSeeder, ItemSeeder>(
+			LengthSeeder,
+			ItemSeeder,
+			usize,
+			PhantomData<T>,
+		);
+		impl<
+				'de,
+				T,
+				LengthSeeder: Clone + DeSeeder<'de, usize>,
+				ItemSeeder: Clone + DeSeeder<'de, T>,
+			> de::Visitor<'de> for Visitor<T, LengthSeeder, ItemSeeder>
+		{
+			type Value = (usize, Vec<T>);
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "capacity, count, items and unused slots")
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let capacity = seq
+					.next_element_seed(self.0.clone().seed())?
+					.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+				let count = seq
+					.next_element_seed(self.0.clone().seed())?
+					.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+				if count > capacity {
+					return Err(de::Error::custom(format_args!(
+						"Item count {} exceeds capacity {}",
+						count, capacity
+					)));
+				}
+				let items: Vec<T> = seq
+					.next_element_seed(TupleNSeed(count, self.1.clone(), PhantomData))?
+					.ok_or_else(|| de::Error::invalid_length(2, &self))?;
+				let padding_len = (capacity - count).checked_mul(self.2).ok_or_else(|| {
+					de::Error::custom(format_args!(
+						"{} unused slots of {} bytes each overflow",
+						capacity - count,
+						self.2
+					))
+				})?;
+				let _: Vec<u8> = seq
+					.next_element_seed(TupleNSeed(padding_len, SerdeLike, PhantomData))?
+					.ok_or_else(|| de::Error::invalid_length(3, &self))?;
+				Ok((capacity, items))
+			}
+		}
+
+		deserializer.deserialize_tuple(4, Visitor(self.0, self.1, self.2, PhantomData))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct CapacityPrefixedSeeded<'a, T, LengthSeeder, ItemSeeder>(
+	&'a (usize, Vec<T>),
+	&'a LengthSeeder,
+	&'a ItemSeeder,
+	usize,
+);
+impl<'a, T, LengthSeeder: SerSeeder<usize>, ItemSeeder: SerSeeder<T>> ser::Serialize
+	for CapacityPrefixedSeeded<'a, T, LengthSeeder, ItemSeeder>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let (capacity, items) = self.0;
+		if items.len() > *capacity {
+			return Err(ser::Error::custom(format_args!(
+				"Tried to serialise {} items with capacity {}",
+				items.len(),
+				capacity
+			)));
+		}
+		let padding_len = (capacity - items.len())
+			.checked_mul(self.3)
+			.ok_or_else(|| {
+				ser::Error::custom(format_args!(
+					"{} unused slots of {} bytes each overflow",
+					capacity - items.len(),
+					self.3
+				))
+			})?;
+		let padding = vec![0_u8; padding_len];
+		let mut serialize_tuple = serializer.serialize_tuple(4)?;
+		serialize_tuple.serialize_element(&self.1.seeded(capacity))?;
+		serialize_tuple.serialize_element(&self.1.seeded(&items.len()))?;
+		serialize_tuple.serialize_element(&TupleNSeeded(items, items.len(), self.2))?;
+		serialize_tuple.serialize_element(&TupleNSeeded(&padding, padding.len(), &SerdeLike))?;
+		serialize_tuple.end()
+	}
+}
+
+/// Integer as ASCII decimal text with optional leading sign.  
+/// (Parameters: Vec<u8> [`Seeder`], whether to write `+` before non-negative values)
+#[derive(Debug, Copy, Clone, Default)]
+pub struct AsciiSignedInt<BytesSeeder>(pub BytesSeeder, pub bool);
+impl<'de, T: FromStr, BytesSeeder: DeSeeder<'de, Vec<u8>>> DeSeeder<'de, T>
+	for AsciiSignedInt<BytesSeeder>
+where
+	T::Err: Display,
+{
+	type Seed = AsciiSignedIntSeed<T, BytesSeeder>;
+	fn seed(self) -> Self::Seed {
+		AsciiSignedIntSeed(self.0, PhantomData)
+	}
+}
+impl<T: Display, BytesSeeder: SerSeeder<Vec<u8>>> SerSeeder<T> for AsciiSignedInt<BytesSeeder> {
+	fn seeded<'s>(&'s self, value: &'s T) -> Seeded<'s> {
+		Box::new(AsciiSignedIntSeeded(value, &self.0, self.1))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct AsciiSignedIntSeed<T, BytesSeeder>(BytesSeeder, PhantomData<T>);
+impl<'de, T: FromStr, BytesSeeder: DeSeeder<'de, Vec<u8>>> de::DeserializeSeed<'de>
+	for AsciiSignedIntSeed<T, BytesSeeder>
+where
+	T::Err: Display,
+{
+	type Value = T;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let text = self.0.seed().deserialize(deserializer)?;
+		let digits = match text.first() {
+			Some(b'+') | Some(b'-') => &text[1..],
+			_ => &text[..],
+		};
+		if digits.is_empty() || !digits.iter().all(u8::is_ascii_digit) {
+			return Err(de::Error::invalid_value(
+				de::Unexpected::Bytes(&text),
+				&"ASCII decimal integer with optional sign",
+			));
+		}
+		core::str::from_utf8(&text)
+			.expect("validated ASCII")
+			.parse()
+			.map_err(de::Error::custom)
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct AsciiSignedIntSeeded<'a, T, BytesSeeder>(&'a T, &'a BytesSeeder, bool);
+impl<'a, T: Display, BytesSeeder: SerSeeder<Vec<u8>>> ser::Serialize
+	for AsciiSignedIntSeeded<'a, T, BytesSeeder>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let mut text = self.0.to_string();
+		if self.2 && !text.starts_with('-') {
+			text.insert(0, '+')
+		}
+		self.1.seeded(&text.into_bytes()).serialize(serializer)
+	}
+}
+
+/// Vec as tuple of fixed-size items filling a region of known byte size.  
+/// (Usage: [`CountFromSize::<ITEM_SIZE, _>(byte_size, item_seeder)`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct CountFromSize<const ELEM: usize, ItemSeeder>(pub usize, pub ItemSeeder);
+impl<'de, const ELEM: usize, T: DeTupleNable, ItemSeeder: Clone + DeSeeder<'de, T::Item>>
+	DeSeeder<'de, T> for CountFromSize<ELEM, ItemSeeder>
+{
+	type Seed = CountFromSizeSeed<ELEM, T, ItemSeeder>;
+	fn seed(self) -> Self::Seed {
+		CountFromSizeSeed(self.0, self.1, PhantomData)
+	}
+}
+impl<const ELEM: usize, T: SerTupleNable, ItemSeeder: SerSeeder<T::Item>> SerSeeder<T>
+	for CountFromSize<ELEM, ItemSeeder>
+{
+	fn seeded<'s>(&'s self, value: &'s T) -> Seeded<'s> {
+		Box::new(CountFromSizeSeeded::<ELEM, _, _>(value, self.0, &self.1))
+	}
+}
+
+/// `ELEM` as item size. Using it fails to compile if it's zero.
+struct ItemSize<const ELEM: usize>;
+impl<const ELEM: usize> ItemSize<ELEM> {
+	const ELEM: usize = {
+		assert!(ELEM != 0, "Items can't be 0 bytes long");
+		ELEM
+	};
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct CountFromSizeSeed<const ELEM: usize, T, ItemSeeder>(usize, ItemSeeder, PhantomData<T>);
+impl<'de, const ELEM: usize, T: DeTupleNable, ItemSeeder: Clone + DeSeeder<'de, T::Item>>
+	de::DeserializeSeed<'de> for CountFromSizeSeed<ELEM, T, ItemSeeder>
+{
+	type Value = T;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let elem = ItemSize::<ELEM>::ELEM;
+		if self.0 % elem != 0 {
+			return Err(de::Error::invalid_value(
+				de::Unexpected::Unsigned(self.0 as u64),
+				&format!("byte size divisible by {}", elem).as_str(),
+			));
+		}
+		TupleNSeed(self.0 / elem, self.1, PhantomData).deserialize(deserializer)
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct CountFromSizeSeeded<'a, const ELEM: usize, T, ItemSeeder>(&'a T, usize, &'a ItemSeeder);
+impl<'a, const ELEM: usize, T: SerTupleNable, ItemSeeder: SerSeeder<T::Item>> ser::Serialize
+	for CountFromSizeSeeded<'a, ELEM, T, ItemSeeder>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let size = self
+			.0
+			.len()
+			.checked_mul(ItemSize::<ELEM>::ELEM)
+			.ok_or_else(|| {
+				ser::Error::custom(format_args!(
+					"Byte size of {} {}-byte items overflows",
+					self.0.len(),
+					ELEM
+				))
+			})?;
+		if size != self.1 {
+			return Err(ser::Error::custom(format_args!(
+				"Tried to serialise CountFromSize::<{}, _>({}, _) from a .len = {}",
+				ELEM,
+				self.1,
+				self.0.len()
+			)));
+		}
+		TupleNSeeded(self.0, self.0.len(), self.2).serialize(serializer)
+	}
+}
+
+/// C-style enum as its [`num_enum`] primitive discriminant.  
+/// (Parameters: primitive [`Seeder`])
+#[cfg(feature = "num_enum")]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct EnumCode<IntSeeder>(pub IntSeeder);
+#[cfg(feature = "num_enum")]
+impl<'de, E: num_enum::TryFromPrimitive, IntSeeder: DeSeeder<'de, E::Primitive>> DeSeeder<'de, E>
+	for EnumCode<IntSeeder>
+{
+	type Seed = EnumCodeSeed<E, IntSeeder>;
+	fn seed(self) -> Self::Seed {
+		EnumCodeSeed(self.0, PhantomData)
+	}
+}
+#[cfg(feature = "num_enum")]
+impl<
+		E: num_enum::TryFromPrimitive + Copy + Into<E::Primitive>,
+		IntSeeder: SerSeeder<E::Primitive>,
+	> SerSeeder<E> for EnumCode<IntSeeder>
+{
+	fn seeded<'s>(&'s self, value: &'s E) -> Seeded<'s> {
+		Box::new(EnumCodeSeeded(value, &self.0))
+	}
+}
+
+#[cfg(feature = "num_enum")]
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct EnumCodeSeed<E, IntSeeder>(IntSeeder, PhantomData<E>);
+#[cfg(feature = "num_enum")]
+impl<'de, E: num_enum::TryFromPrimitive, IntSeeder: DeSeeder<'de, E::Primitive>>
+	de::DeserializeSeed<'de> for EnumCodeSeed<E, IntSeeder>
+{
+	type Value = E;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		self.0
+			.seed()
+			.deserialize(deserializer)?
+			.pipe(E::try_from_primitive)
+			.map_err(de::Error::custom)
+	}
+}
+
+#[cfg(feature = "num_enum")]
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct EnumCodeSeeded<'a, E, IntSeeder>(&'a E, &'a IntSeeder);
+#[cfg(feature = "num_enum")]
+impl<
+		'a,
+		E: num_enum::TryFromPrimitive + Copy + Into<E::Primitive>,
+		IntSeeder: SerSeeder<E::Primitive>,
+	> ser::Serialize for EnumCodeSeeded<'a, E, IntSeeder>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		(*self.0)
+			.into()
+			.pipe(|code| self.1.seeded(&code).serialize(serializer))
+	}
+}
+
+/// Value read from at most a given number of bytes, so that length fields inside it can't claim more than that.  
+/// Only the inner value is written.
+/// Requires a [`raw::Deserializer`] to read.  
+/// (Parameters: maximum byte size, inner [`Seeder`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Budget<Inner>(pub usize, pub Inner);
+impl<'de, T, Inner: DeSeeder<'de, T>> DeSeeder<'de, T> for Budget<Inner> {
+	type Seed = BudgetSeed<T, Inner>;
+	fn seed(self) -> Self::Seed {
+		BudgetSeed(self.0, self.1, PhantomData)
+	}
+}
+impl<T, Inner: SerSeeder<T>> SerSeeder<T> for Budget<Inner> {
+	fn seeded<'s>(&'s self, value: &'s T) -> Seeded<'s> {
+		self.1.seeded(value)
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct BudgetSeed<T, Inner>(usize, Inner, PhantomData<T>);
+impl<'de, T, Inner: DeSeeder<'de, T>> de::DeserializeSeed<'de> for BudgetSeed<T, Inner> {
+	type Value = T;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<T, Inner>(usize, Inner, PhantomData<T>);
+		impl<'de, T, Inner: DeSeeder<'de, T>> de::Visitor<'de> for Visitor<T, Inner> {
+			type Value = T;
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "Budget({}, _)", self.0)
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let Visitor(budget, inner, _) = self;
+				raw::next_nested(
+					&mut seq,
+					|remaining| Ok(&remaining[..remaining.len().min(budget)]),
+					|limited| inner.seed().deserialize(limited),
+					|error| {
+						de::Error::custom(format_args!(
+							"{} (within Budget of {} bytes)",
+							error, budget
+						))
+					},
+				)
+				.map(|(value, _)| value)
+			}
+		}
+
+		deserializer.deserialize_tuple(2, Visitor(self.0, self.1, PhantomData))
+	}
+}
+
+/// `(header, items)` stored as header, item count, zero padding to align the items and then the items.  
+/// The alignment is relative to the start of the header.
+/// Requires a [`raw::Deserializer`] to read and a serializer that writes like [`raw::Serializer`] to write.  
+/// (Usage: [`HeaderThenAlignedArray::<ALIGN, _, _, _>(header_seeder, count_seeder, item_seeder)`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct HeaderThenAlignedArray<const ALIGN: usize, HeaderSeeder, CountSeeder, ItemSeeder>(
+	pub HeaderSeeder,
+	pub CountSeeder,
+	pub ItemSeeder,
+);
+impl<
+		'de,
+		const ALIGN: usize,
+		H,
+		T,
+		HeaderSeeder: DeSeeder<'de, H>,
+		CountSeeder: DeSeeder<'de, usize>,
+		ItemSeeder: Clone + DeSeeder<'de, T>,
+	> DeSeeder<'de, (H, Vec<T>)>
+	for HeaderThenAlignedArray<ALIGN, HeaderSeeder, CountSeeder, ItemSeeder>
+{
+	type Seed = HeaderThenAlignedArraySeed<ALIGN, H, T, HeaderSeeder, CountSeeder, ItemSeeder>;
+	fn seed(self) -> Self::Seed {
+		HeaderThenAlignedArraySeed(self.0, self.1, self.2, PhantomData)
+	}
+}
+impl<
+		const ALIGN: usize,
+		H,
+		T,
+		HeaderSeeder: SerSeeder<H>,
+		CountSeeder: SerSeeder<usize>,
+		ItemSeeder: SerSeeder<T>,
+	> SerSeeder<(H, Vec<T>)> for HeaderThenAlignedArray<ALIGN, HeaderSeeder, CountSeeder, ItemSeeder>
+{
+	fn seeded<'s>(&'s self, value: &'s (H, Vec<T>)) -> Seeded<'s> {
+		Box::new(HeaderThenAlignedArraySeeded::<ALIGN, _, _, _, _, _>(
+			value, &self.0, &self.1, &self.2,
+		))
+	}
+}
+
+/// `ALIGN` as alignment. Using it fails to compile if it's zero.
+struct Alignment<const ALIGN: usize>;
+impl<const ALIGN: usize> Alignment<ALIGN> {
+	const ALIGN: usize = {
+		assert!(ALIGN != 0, "Can't align to a multiple of 0");
+		ALIGN
+	};
+}
+
+fn padding_to<const ALIGN: usize>(len: usize) -> usize {
+	let align = Alignment::<ALIGN>::ALIGN;
+	(align - len % align) % align
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct HeaderThenAlignedArraySeed<
+	const ALIGN: usize,
+	H,
+	T,
+	HeaderSeeder,
+	CountSeeder,
+	ItemSeeder,
+>(HeaderSeeder, CountSeeder, ItemSeeder, PhantomData<(H, T)>);
+impl<
+		'de,
+		const ALIGN: usize,
+		H,
+		T,
+		HeaderSeeder: DeSeeder<'de, H>,
+		CountSeeder: DeSeeder<'de, usize>,
+		ItemSeeder: Clone + DeSeeder<'de, T>,
+	> de::DeserializeSeed<'de>
+	for HeaderThenAlignedArraySeed<ALIGN, H, T, HeaderSeeder, CountSeeder, ItemSeeder>
+{
+	type Value = (H, Vec<T>);
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<const ALIGN: usize, H, T, HeaderSeeder, CountSeeder, ItemSeeder>(
+			HeaderSeeder,
+			CountSeeder,
+			ItemSeeder,
+			PhantomData<(H, T)>,
+		);
+		impl<
+				'de,
+				const ALIGN: usize,
+				H,
+				T,
+				HeaderSeeder: DeSeeder<'de, H>,
+				CountSeeder: DeSeeder<'de, usize>,
+				ItemSeeder: Clone + DeSeeder<'de, T>,
+			> de::Visitor<'de> for Visitor<ALIGN, H, T, HeaderSeeder, CountSeeder, ItemSeeder>
+		{
+			type Value = (H, Vec<T>);
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "header, count, padding to {} and items", ALIGN)
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let expected = &"header, count, padding and items";
+				let start = seq
+					.next_element_seed(raw::OffsetSeed)?
+					.ok_or_else(|| de::Error::invalid_length(0, expected))?;
+				let header = seq
+					.next_element_seed(self.0.seed())?
+					.ok_or_else(|| de::Error::invalid_length(1, expected))?;
+				let count = seq
+					.next_element_seed(self.1.seed())?
+					.ok_or_else(|| de::Error::invalid_length(2, expected))?;
+				let end = seq
+					.next_element_seed(raw::OffsetSeed)?
+					.ok_or_else(|| de::Error::invalid_length(3, expected))?;
+				seq.next_element_seed(raw::SkipSeed(padding_to::<ALIGN>(end - start)))?
+					.ok_or_else(|| de::Error::invalid_length(4, expected))?;
+				let items = seq
+					.next_element_seed(TupleNSeed(count, self.2, PhantomData))?
+					.ok_or_else(|| de::Error::invalid_length(5, expected))?;
+				Ok((header, items))
+			}
+		}
+
+		deserializer.deserialize_tuple(
+			6,
+			Visitor::<ALIGN, _, _, _, _, _>(self.0, self.1, self.2, PhantomData),
+		)
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct HeaderThenAlignedArraySeeded<
+	'a,
+	const ALIGN: usize,
+	H,
+	T,
+	HeaderSeeder,
+	CountSeeder,
+	ItemSeeder,
+>(
+	&'a (H, Vec<T>),
+	&'a HeaderSeeder,
+	&'a CountSeeder,
+	&'a ItemSeeder,
+);
+impl<
+		'a,
+		const ALIGN: usize,
+		H,
+		T,
+		HeaderSeeder: SerSeeder<H>,
+		CountSeeder: SerSeeder<usize>,
+		ItemSeeder: SerSeeder<T>,
+	> ser::Serialize
+	for HeaderThenAlignedArraySeeded<'a, ALIGN, H, T, HeaderSeeder, CountSeeder, ItemSeeder>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let (header, items) = self.0;
+		let mut measure = raw::Serializer::new();
+		self.1
+			.seeded(header)
+			.serialize(&mut measure)
+			.and_then(|()| self.2.seeded(&items.len()).serialize(&mut measure))
+			.map_err(ser::Error::custom)?;
+		let padding = vec![0_u8; padding_to::<ALIGN>(measure.output().len())];
+
+		// The units stand in for the offsets read while deserializing, which take up no space.
+		let mut serialize_tuple = serializer.serialize_tuple(6)?;
+		serialize_tuple.serialize_element(&())?;
+		serialize_tuple.serialize_element(&self.1.seeded(header))?;
+		serialize_tuple.serialize_element(&self.2.seeded(&items.len()))?;
+		serialize_tuple.serialize_element(&())?;
+		serialize_tuple.serialize_element(&TupleNSeeded(&padding, padding.len(), &SerdeLike))?;
+		serialize_tuple.serialize_element(&TupleNSeeded(items, items.len(), self.3))?;
+		serialize_tuple.end()
+	}
+}
+
+/// Value from a lookup table, stored as its index.  
+/// Serialising looks up the first matching entry in a reverse map, which [`Lut::new`] builds once.  
+/// (Usage: [`Lut::<_, Index, _>::new(index_seeder, table)`])
+#[derive(Debug, Clone)]
+pub struct Lut<'a, IndexSeeder, Index, T>(
+	IndexSeeder,
+	&'a [T],
+	BTreeMap<&'a T, usize>,
+	PhantomData<Index>,
+);
+impl<'a, IndexSeeder, Index, T: Ord> Lut<'a, IndexSeeder, Index, T> {
+	pub fn new(index_seeder: IndexSeeder, table: &'a [T]) -> Self {
+		let mut reverse = BTreeMap::new();
+		for (i, entry) in table.iter().enumerate() {
+			reverse.entry(entry).or_insert(i);
+		}
+		Self(index_seeder, table, reverse, PhantomData)
+	}
+}
+impl<
+		'a,
+		'de,
+		IndexSeeder: DeSeeder<'de, Index>,
+		Index: TryInto<usize> + Copy + Debug,
+		T: Clone,
+	> DeSeeder<'de, T> for Lut<'a, IndexSeeder, Index, T>
+{
+	type Seed = Self;
+	fn seed(self) -> Self::Seed {
+		self
+	}
+}
+impl<'a, IndexSeeder: SerSeeder<Index>, Index: TryFrom<usize>, T: Ord + Debug> SerSeeder<T>
+	for Lut<'a, IndexSeeder, Index, T>
+{
+	fn seeded<'s>(&'s self, value: &'s T) -> Seeded<'s> {
+		Box::new(LutSeeded(value, &self.0, &self.2, PhantomData))
+	}
+}
+impl<
+		'a,
+		'de,
+		IndexSeeder: DeSeeder<'de, Index>,
+		Index: TryInto<usize> + Copy + Debug,
+		T: Clone,
+	> de::DeserializeSeed<'de> for Lut<'a, IndexSeeder, Index, T>
+{
+	type Value = T;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let Self(index_seeder, table, ..) = self;
+		let index = index_seeder.seed().deserialize(deserializer)?;
+		index
+			.try_into()
+			.ok()
+			.and_then(|index| table.get(index))
+			.cloned()
+			.ok_or_else(|| {
+				de::Error::custom(format_args!(
+					"Lut index {:?} out of range for {} entries",
+					index,
+					table.len()
+				))
+			})
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct LutSeeded<'a, T, IndexSeeder, Index>(
+	&'a T,
+	&'a IndexSeeder,
+	&'a BTreeMap<&'a T, usize>,
+	PhantomData<Index>,
+);
+impl<'a, T: Ord + Debug, IndexSeeder: SerSeeder<Index>, Index: TryFrom<usize>> ser::Serialize
+	for LutSeeded<'a, T, IndexSeeder, Index>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let index = *self
+			.2
+			.get(self.0)
+			.ok_or_else(|| ser::Error::custom(format_args!("{:?} is not in the Lut", self.0)))?;
+		Index::try_from(index)
+			.map_err(|_| {
+				ser::Error::custom(format_args!(
+					"Lut index {} doesn't fit the index type",
+					index
+				))
+			})?
+			.pipe(|index| self.1.seeded(&index).serialize(serializer))
+	}
+}
+
+/// Length-prefixed region kept as borrowed raw bytes, for deferred parsing.
+/// Requires a [`raw::Deserializer`] to read.  
+/// (Parameters: length [`Seeder`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct RawRegion<LengthSeeder>(pub LengthSeeder);
+impl<'de, LengthSeeder: DeSeeder<'de, usize>> DeSeeder<'de, &'de [u8]> for RawRegion<LengthSeeder> {
+	type Seed = RawRegionSeed<LengthSeeder>;
+	fn seed(self) -> Self::Seed {
+		RawRegionSeed(self.0)
+	}
+}
+impl<'a, LengthSeeder: SerSeeder<usize>> SerSeeder<&'a [u8]> for RawRegion<LengthSeeder> {
+	fn seeded<'s>(&'s self, value: &'s &'a [u8]) -> Seeded<'s> {
+		Box::new(RawRegionSeeded(value, &self.0))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct RawRegionSeed<LengthSeeder>(LengthSeeder);
+impl<'de, LengthSeeder: DeSeeder<'de, usize>> de::DeserializeSeed<'de>
+	for RawRegionSeed<LengthSeeder>
+{
+	type Value = &'de [u8];
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<LengthSeeder>(LengthSeeder);
+		impl<'de, LengthSeeder: DeSeeder<'de, usize>> de::Visitor<'de> for Visitor<LengthSeeder> {
+			type Value = &'de [u8];
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "length and raw region")
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let length = seq
+					.next_element_seed(self.0.seed())?
+					.ok_or_else(|| de::Error::invalid_length(0, &"length and raw region"))?;
+				let remaining = raw::next_remaining(&mut seq)?;
+				let region = remaining.get(..length).ok_or_else(|| {
+					de::Error::invalid_length(
+						remaining.len(),
+						&format!("{} bytes", length).as_str(),
+					)
+				})?;
+				seq.next_element_seed(raw::SkipSeed(length))?
+					.ok_or_else(|| de::Error::invalid_length(2, &"length and raw region"))?;
+				Ok(region)
+			}
+		}
+
+		deserializer.deserialize_tuple(3, Visitor(self.0))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct RawRegionSeeded<'a, 'b, LengthSeeder>(&'a &'b [u8], &'a LengthSeeder);
+impl<'a, 'b, LengthSeeder: SerSeeder<usize>> ser::Serialize
+	for RawRegionSeeded<'a, 'b, LengthSeeder>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let mut serialize_tuple = serializer.serialize_tuple(2)?;
+		serialize_tuple.serialize_element(&self.1.seeded(&self.0.len()))?;
+		serialize_tuple.serialize_element(&Literal(self.0))?;
+		serialize_tuple.end()
+	}
+}
+
+/// [`Vec<_>`] whose items carry a sequential index that must count up from 0 without gaps.  
+/// Only the [`Vec<_>`] is written.  
+/// (Parameters: [`Vec<_>`] [`Seeder`], `Fn(&Item) -> usize`)
+#[derive(Debug, Copy, Clone, Default)]
+pub struct DenseIndexed<ItemsSeeder, IndexFn>(pub ItemsSeeder, pub IndexFn);
+impl<'de, Item, ItemsSeeder: DeSeeder<'de, Vec<Item>>, IndexFn: Fn(&Item) -> usize>
+	DeSeeder<'de, Vec<Item>> for DenseIndexed<ItemsSeeder, IndexFn>
+{
+	type Seed = DenseIndexedSeed<ItemsSeeder, IndexFn, Item>;
+	fn seed(self) -> Self::Seed {
+		DenseIndexedSeed(self.0, self.1, PhantomData)
+	}
+}
+impl<Item, ItemsSeeder: SerSeeder<Vec<Item>>, IndexFn> SerSeeder<Vec<Item>>
+	for DenseIndexed<ItemsSeeder, IndexFn>
+{
+	fn seeded<'s>(&'s self, value: &'s Vec<Item>) -> Seeded<'s> {
+		self.0.seeded(value)
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct DenseIndexedSeed<ItemsSeeder, IndexFn, Item>(ItemsSeeder, IndexFn, PhantomData<Item>);
+impl<'de, Item, ItemsSeeder: DeSeeder<'de, Vec<Item>>, IndexFn: Fn(&Item) -> usize>
+	de::DeserializeSeed<'de> for DenseIndexedSeed<ItemsSeeder, IndexFn, Item>
+{
+	type Value = Vec<Item>;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let items = self.0.seed().deserialize(deserializer)?;
+		for (i, item) in items.iter().enumerate() {
+			let index = self.1(item);
+			if index != i {
+				return Err(de::Error::custom(format_args!(
+					"Expected index {} in DenseIndexed but found {}",
+					i, index
+				)));
+			}
+		}
+		Ok(items)
+	}
+}
+
+/// Unit of an angle decoded by [`BinaryAngle`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum AngleUnit {
+	#[default]
+	Degrees,
+	Radians,
+}
+impl AngleUnit {
+	fn full_turn(self) -> f64 {
+		match self {
+			AngleUnit::Degrees => 360.,
+			AngleUnit::Radians => core::f64::consts::TAU,
+		}
+	}
+}
+
+/// [`f64`] angle stored as unsigned fraction of a full turn (binary angular measurement).  
+/// Angles outside of one turn wrap around when serialized.  
+/// (Usage: [`BinaryAngle::degrees(int_seeder)`], [`BinaryAngle::radians(int_seeder)`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct BinaryAngle<IntSeeder, Int>(IntSeeder, AngleUnit, PhantomData<Int>);
+impl<IntSeeder, Int> BinaryAngle<IntSeeder, Int> {
+	pub fn degrees(int_seeder: IntSeeder) -> Self {
+		Self(int_seeder, AngleUnit::Degrees, PhantomData)
+	}
+
+	pub fn radians(int_seeder: IntSeeder) -> Self {
+		Self(int_seeder, AngleUnit::Radians, PhantomData)
+	}
+}
+impl<'de, Int: BinaryAngleable, IntSeeder: DeSeeder<'de, Int>> DeSeeder<'de, f64>
+	for BinaryAngle<IntSeeder, Int>
+{
+	type Seed = BinaryAngleSeed<IntSeeder, Int>;
+	fn seed(self) -> Self::Seed {
+		BinaryAngleSeed(self.0, self.1, PhantomData)
+	}
+}
+impl<Int: BinaryAngleable, IntSeeder: SerSeeder<Int>> SerSeeder<f64>
+	for BinaryAngle<IntSeeder, Int>
+{
+	fn seeded<'s>(&'s self, value: &'s f64) -> Seeded<'s> {
+		Box::new(BinaryAngleSeeded(value, &self.0, self.1, self.2))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct BinaryAngleSeed<IntSeeder, Int>(IntSeeder, AngleUnit, PhantomData<Int>);
+impl<'de, Int: BinaryAngleable, IntSeeder: DeSeeder<'de, Int>> de::DeserializeSeed<'de>
+	for BinaryAngleSeed<IntSeeder, Int>
+{
+	type Value = f64;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let repr = self.0.seed().deserialize(deserializer)?;
+		Ok(repr.to_f64() / Int::TURN * self.1.full_turn())
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone)]
+pub struct BinaryAngleSeeded<'a, IntSeeder, Int>(
+	&'a f64,
+	&'a IntSeeder,
+	AngleUnit,
+	PhantomData<Int>,
+);
+impl<'a, Int: BinaryAngleable, IntSeeder: SerSeeder<Int>> ser::Serialize
+	for BinaryAngleSeeded<'a, IntSeeder, Int>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		if !self.0.is_finite() {
+			return Err(ser::Error::custom(format_args!(
+				"Can't serialize non-finite angle {} as BinaryAngle",
+				self.0
+			)));
+		}
+		// `f64::rem_euclid` and `f64::round` aren't available in `core`.
+		let fraction = match (self.0 / self.2.full_turn()) % 1. {
+			fraction if fraction < 0. => fraction + 1.,
+			fraction => fraction,
+		};
+		let steps = {
+			let scaled = fraction * Int::TURN;
+			let truncated = scaled as u128 as f64;
+			if scaled - truncated >= 0.5 {
+				truncated + 1.
+			} else {
+				truncated
+			}
+		};
+		// Rounding up to a full turn wraps back to 0.
+		let repr = Int::from_f64(if steps >= Int::TURN { 0. } else { steps });
+		repr.pipe(|repr| self.1.seeded(&repr).serialize(serializer))
+	}
+}
+
+/// See [`BinaryAngle`].
+pub trait BinaryAngleable: Sized {
+	/// The number of steps in one full turn, i.e. one more than the maximum value.
+	const TURN: f64;
+	fn to_f64(self) -> f64;
+	/// `steps` is always integral and in `0. .. Self::TURN`.
+	fn from_f64(steps: f64) -> Self;
+}
+macro_rules! impl_binary_angleable {
+	($($ty:ty),*$(,)?) => {$(
+		impl BinaryAngleable for $ty {
+			const TURN: f64 = (<$ty>::MAX as f64) + 1.;
+			fn to_f64(self) -> f64 {
+				self as f64
+			}
+			fn from_f64(steps: f64) -> Self {
+				steps as $ty
+			}
+		}
+	)*};
+}
+impl_binary_angleable!(u8, u16, u32);
+
+/// Opcode-prefixed items up to and including an end opcode, which is not part of the value.  
+/// Each item is decoded by the [`Seeder`] its opcode dispatches to. The opcodes are kept alongside the items so they can be written back.  
+/// (Parameters: opcode [`Seeder`], `Fn(&Opcode) -> ItemSeeder`, end opcode)
+#[derive(Debug, Copy, Clone, Default)]
+pub struct OpcodeStream<OpcodeSeeder, DispatchFn, Opcode>(
+	pub OpcodeSeeder,
+	pub DispatchFn,
+	pub Opcode,
+);
+impl<
+		'de,
+		T,
+		Opcode: PartialEq,
+		OpcodeSeeder: Clone + DeSeeder<'de, Opcode>,
+		ItemSeeder: DeSeeder<'de, T>,
+		DispatchFn: Fn(&Opcode) -> ItemSeeder,
+	> DeSeeder<'de, Vec<(Opcode, T)>> for OpcodeStream<OpcodeSeeder, DispatchFn, Opcode>
+{
+	type Seed = OpcodeStreamSeed<T, OpcodeSeeder, DispatchFn, Opcode>;
+	fn seed(self) -> Self::Seed {
+		OpcodeStreamSeed(self.0, self.1, self.2, PhantomData)
+	}
+}
+impl<
+		T,
+		Opcode: PartialEq,
+		OpcodeSeeder: SerSeeder<Opcode>,
+		ItemSeeder: SerSeeder<T>,
+		DispatchFn: Fn(&Opcode) -> ItemSeeder,
+	> SerSeeder<Vec<(Opcode, T)>> for OpcodeStream<OpcodeSeeder, DispatchFn, Opcode>
+{
+	fn seeded<'s>(&'s self, value: &'s Vec<(Opcode, T)>) -> Seeded<'s> {
+		Box::new(OpcodeStreamSeeded(value, &self.0, &self.1, &self.2))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct OpcodeStreamSeed<T, OpcodeSeeder, DispatchFn, Opcode>(
+	OpcodeSeeder,
+	DispatchFn,
+	Opcode,
+	PhantomData<T>,
+);
+impl<
+		'de,
+		T,
+		Opcode: PartialEq,
+		OpcodeSeeder: Clone + DeSeeder<'de, Opcode>,
+		ItemSeeder: DeSeeder<'de, T>,
+		DispatchFn: Fn(&Opcode) -> ItemSeeder,
+	> de::DeserializeSeed<'de> for OpcodeStreamSeed<T, OpcodeSeeder, DispatchFn, Opcode>
+{
+	type Value = Vec<(Opcode, T)>;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<T, OpcodeSeeder, DispatchFn, Opcode>(
+			OpcodeSeeder,
+			DispatchFn,
+			Opcode,
+			PhantomData<T>,
+		);
+		impl<
+				'de,
+				T,
+				Opcode: PartialEq,
+				OpcodeSeeder: Clone + DeSeeder<'de, Opcode>,
+				ItemSeeder: DeSeeder<'de, T>,
+				DispatchFn: Fn(&Opcode) -> ItemSeeder,
+			> de::Visitor<'de> for Visitor<T, OpcodeSeeder, DispatchFn, Opcode>
+		{
+			type Value = Vec<(Opcode, T)>;
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "OpcodeStream")
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let mut items = Vec::new();
+				loop {
+					let opcode =
+						seq.next_element_seed(self.0.clone().seed())?
+							.ok_or_else(|| {
+								de::Error::custom("OpcodeStream ended without end opcode")
+							})?;
+					if opcode == self.2 {
+						break;
+					}
+					let item = seq
+						.next_element_seed(self.1(&opcode).seed())?
+						.ok_or_else(|| de::Error::invalid_length(items.len() * 2 + 1, &self))?;
+					items.push((opcode, item));
+				}
+				trace!("Read {} OpcodeStream items.", items.len());
+				Ok(items)
+			}
+		}
+
+		deserializer.deserialize_seq(Visitor(self.0, self.1, self.2, PhantomData))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct OpcodeStreamSeeded<'a, T, OpcodeSeeder, DispatchFn, Opcode>(
+	&'a Vec<(Opcode, T)>,
+	&'a OpcodeSeeder,
+	&'a DispatchFn,
+	&'a Opcode,
+);
+impl<
+		'a,
+		T,
+		Opcode: PartialEq,
+		OpcodeSeeder: SerSeeder<Opcode>,
+		ItemSeeder: SerSeeder<T>,
+		DispatchFn: Fn(&Opcode) -> ItemSeeder,
+	> ser::Serialize for OpcodeStreamSeeded<'a, T, OpcodeSeeder, DispatchFn, Opcode>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let mut serialize_seq = serializer.serialize_seq(Some(self.0.len() * 2 + 1))?;
+		for (opcode, item) in self.0 {
+			if opcode == self.3 {
+				return Err(ser::Error::custom(
+					"Can't serialize end opcode as OpcodeStream item",
+				));
+			}
+			serialize_seq.serialize_element(&self.1.seeded(opcode))?;
+			let item_seeder = self.2(opcode);
+			serialize_seq.serialize_element(&item_seeder.seeded(item))?;
+		}
+		serialize_seq.serialize_element(&self.1.seeded(self.3))?;
+		serialize_seq.end()
+	}
+}
+
+/// [`Vec<_>`] as seq, alongside the wrapping [`u32`] sum of the bytes its items were read from.  
+/// Requires a [`raw::Deserializer`] to read. The checksum is not stored, so only the items are written.  
+/// (Parameters: item [`Seeder`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ChecksummedSeq<ItemSeeder>(pub ItemSeeder);
+impl<'de, T, ItemSeeder: Clone + DeSeeder<'de, T>> DeSeeder<'de, (Vec<T>, u32)>
+	for ChecksummedSeq<ItemSeeder>
+{
+	type Seed = ChecksummedSeqSeed<T, ItemSeeder>;
+	fn seed(self) -> Self::Seed {
+		ChecksummedSeqSeed(self.0, PhantomData)
+	}
+}
+impl<T, ItemSeeder: SerSeeder<T>> SerSeeder<(Vec<T>, u32)> for ChecksummedSeq<ItemSeeder> {
+	fn seeded<'s>(&'s self, value: &'s (Vec<T>, u32)) -> Seeded<'s> {
+		Box::new(SeqSeeded(&value.0, &self.0))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ChecksummedSeqSeed<T, ItemSeeder>(ItemSeeder, PhantomData<T>);
+impl<'de, T, ItemSeeder: Clone + DeSeeder<'de, T>> de::DeserializeSeed<'de>
+	for ChecksummedSeqSeed<T, ItemSeeder>
+{
+	type Value = (Vec<T>, u32);
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<T, ItemSeeder>(ItemSeeder, PhantomData<T>);
+		impl<'de, T, ItemSeeder: Clone + DeSeeder<'de, T>> de::Visitor<'de> for Visitor<T, ItemSeeder> {
+			type Value = (Vec<T>, u32);
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "ChecksummedSeq")
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let mut items = Vec::new();
+				let mut read: &'de [u8] = &[];
+				while let Some(remaining) = seq.next_element_seed(raw::RemainingSeed)? {
+					if items.is_empty() {
+						read = remaining;
+					}
+					let item = seq
+						.next_element_seed(self.0.clone().seed())?
+						.ok_or_else(|| de::Error::invalid_length(items.len(), &self))?;
+					items.push(item);
+				}
+				// The seq only ends once the input is exhausted, so all of it was read.
+				let checksum = read
+					.iter()
+					.fold(0_u32, |checksum, byte| checksum.wrapping_add(*byte as u32));
+				trace!(
+					"ChecksummedSeq read {} items with checksum {:#010X}.",
+					items.len(),
+					checksum
+				);
+				Ok((items, checksum))
+			}
+		}
+
+		deserializer.deserialize_seq(Visitor(self.0, PhantomData))
+	}
+}
+
+/// Unsigned value that must be a multiple of `TO`, checked in both directions.  
+/// (Parameters: inner [`Seeder`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct AlignedValue<const TO: usize, Inner>(pub Inner);
+impl<'de, const TO: usize, T: Copy + Display + TryInto<u64>, Inner: DeSeeder<'de, T>>
+	DeSeeder<'de, T> for AlignedValue<TO, Inner>
+{
+	type Seed = AlignedValueSeed<TO, T, Inner>;
+	fn seed(self) -> Self::Seed {
+		AlignedValueSeed(self.0, PhantomData)
+	}
+}
+impl<const TO: usize, T: Copy + Display + TryInto<u64>, Inner: SerSeeder<T>> SerSeeder<T>
+	for AlignedValue<TO, Inner>
+{
+	fn seeded<'s>(&'s self, value: &'s T) -> Seeded<'s> {
+		Box::new(AlignedValueSeeded::<TO, T, Inner>(value, &self.0))
+	}
+}
+
+fn is_aligned<const TO: usize, T: Copy + TryInto<u64>>(value: T) -> bool {
+	value
+		.try_into()
+		.map(|value| value % Alignment::<TO>::ALIGN as u64 == 0)
+		.unwrap_or(false)
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct AlignedValueSeed<const TO: usize, T, Inner>(Inner, PhantomData<T>);
+impl<'de, const TO: usize, T: Copy + Display + TryInto<u64>, Inner: DeSeeder<'de, T>>
+	de::DeserializeSeed<'de> for AlignedValueSeed<TO, T, Inner>
+{
+	type Value = T;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let value = self.0.seed().deserialize(deserializer)?;
+		if !is_aligned::<TO, _>(value) {
+			return Err(de::Error::custom(format_args!(
+				"Expected a multiple of {} but found {}",
+				TO, value
+			)));
+		}
+		Ok(value)
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct AlignedValueSeeded<'a, const TO: usize, T, Inner>(&'a T, &'a Inner);
+impl<'a, const TO: usize, T: Copy + Display + TryInto<u64>, Inner: SerSeeder<T>> ser::Serialize
+	for AlignedValueSeeded<'a, TO, T, Inner>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		if !is_aligned::<TO, _>(*self.0) {
+			return Err(ser::Error::custom(format_args!(
+				"Can't serialize {} as multiple of {}",
+				self.0, TO
+			)));
+		}
+		self.1.seeded(self.0).serialize(serializer)
+	}
+}
+
+/// IFF/RIFF-style chunks, each stored as four-byte type, payload size and payload, up to the end of input.  
+/// Each payload is decoded by the [`Seeder`] its type dispatches to and must be consumed completely.
+/// Requires a [`raw::Deserializer`] to read and a serializer that writes like [`raw::Serializer`] to write.  
+/// (Parameters: size [`Seeder`], `Fn(&[u8; 4]) -> PayloadSeeder`)
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Chunks<SizeSeeder, DispatchFn>(pub SizeSeeder, pub DispatchFn);
+impl<
+		'de,
+		T,
+		SizeSeeder: Clone + DeSeeder<'de, usize>,
+		PayloadSeeder: DeSeeder<'de, T>,
+		DispatchFn: Fn(&[u8; 4]) -> PayloadSeeder,
+	> DeSeeder<'de, Vec<([u8; 4], T)>> for Chunks<SizeSeeder, DispatchFn>
+{
+	type Seed = ChunksSeed<T, SizeSeeder, DispatchFn>;
+	fn seed(self) -> Self::Seed {
+		ChunksSeed(self.0, self.1, PhantomData)
+	}
+}
+impl<
+		T,
+		SizeSeeder: SerSeeder<usize>,
+		PayloadSeeder: SerSeeder<T>,
+		DispatchFn: Fn(&[u8; 4]) -> PayloadSeeder,
+	> SerSeeder<Vec<([u8; 4], T)>> for Chunks<SizeSeeder, DispatchFn>
+{
+	fn seeded<'s>(&'s self, value: &'s Vec<([u8; 4], T)>) -> Seeded<'s> {
+		Box::new(ChunksSeeded(value, &self.0, &self.1))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ChunksSeed<T, SizeSeeder, DispatchFn>(SizeSeeder, DispatchFn, PhantomData<T>);
+impl<
+		'de,
+		T,
+		SizeSeeder: Clone + DeSeeder<'de, usize>,
+		PayloadSeeder: DeSeeder<'de, T>,
+		DispatchFn: Fn(&[u8; 4]) -> PayloadSeeder,
+	> de::DeserializeSeed<'de> for ChunksSeed<T, SizeSeeder, DispatchFn>
+{
+	type Value = Vec<([u8; 4], T)>;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<T, SizeSeeder, DispatchFn>(SizeSeeder, DispatchFn, PhantomData<T>);
+		impl<
+				'de,
+				T,
+				SizeSeeder: Clone + DeSeeder<'de, usize>,
+				PayloadSeeder: DeSeeder<'de, T>,
+				DispatchFn: Fn(&[u8; 4]) -> PayloadSeeder,
+			> de::Visitor<'de> for Visitor<T, SizeSeeder, DispatchFn>
+		{
+			type Value = Vec<([u8; 4], T)>;
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "Chunks")
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let mut chunks = Vec::new();
+				while let Some(chunk_type) = seq.next_element_seed(PhantomData::<[u8; 4]>)? {
+					let size = seq
+						.next_element_seed(self.0.clone().seed())?
+						.ok_or_else(|| de::Error::invalid_length(chunks.len(), &self))?;
+					let (value, _) = raw::next_nested(
+						&mut seq,
+						|remaining| {
+							remaining.get(..size).ok_or_else(|| {
+								de::Error::custom(format_args!(
+									"Chunk {:?} is {} bytes long, but only {} remain",
+									String::from_utf8_lossy(&chunk_type),
+									size,
+									remaining.len()
+								))
+							})
+						},
+						|limited| {
+							let value = self.1(&chunk_type).seed().deserialize(&mut *limited)?;
+							limited.end().map(|()| value)
+						},
+						|error| {
+							de::Error::custom(format_args!(
+								"{} (in chunk {:?})",
+								error,
+								String::from_utf8_lossy(&chunk_type)
+							))
+						},
+					)?;
+					trace!(
+						"Read chunk {:?} of {} bytes.",
+						String::from_utf8_lossy(&chunk_type),
+						size
+					);
+					chunks.push((chunk_type, value));
+				}
+				Ok(chunks)
+			}
+		}
+
+		deserializer.deserialize_seq(Visitor(self.0, self.1, PhantomData))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct ChunksSeeded<'a, T, SizeSeeder, DispatchFn>(
+	&'a Vec<([u8; 4], T)>,
+	&'a SizeSeeder,
+	&'a DispatchFn,
+);
+impl<
+		'a,
+		T,
+		SizeSeeder: SerSeeder<usize>,
+		PayloadSeeder: SerSeeder<T>,
+		DispatchFn: Fn(&[u8; 4]) -> PayloadSeeder,
+	> ser::Serialize for ChunksSeeded<'a, T, SizeSeeder, DispatchFn>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let mut serialize_seq = serializer.serialize_seq(Some(self.0.len() * 3))?;
+		for (chunk_type, value) in self.0 {
+			let mut payload = raw::Serializer::new();
+			self.2(chunk_type)
+				.seeded(value)
+				.serialize(&mut payload)
+				.map_err(ser::Error::custom)?;
+			let payload = payload.into_inner();
+			serialize_seq.serialize_element(&Literal(chunk_type))?;
+			serialize_seq.serialize_element(&self.1.seeded(&payload.len()))?;
+			serialize_seq.serialize_element(&Literal(&payload))?;
+		}
+		serialize_seq.end()
+	}
+}
+
+/// Position of the flag bit in a [`FlagAndValue`] byte.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum FlagBit {
+	/// The flag is bit 7, the value occupies the lowest `VALUE_BITS` bits.
+	#[default]
+	High,
+	/// The flag is bit 0, the value occupies the `VALUE_BITS` bits above it.
+	Low,
+}
+
+/// `(flag, value)` packed into one byte. Unused bits must be zero.  
+/// (Parameters: [`FlagBit`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct FlagAndValue<const VALUE_BITS: u32>(pub FlagBit);
+impl<const VALUE_BITS: u32> FlagAndValue<VALUE_BITS> {
+	/// Using this fails to compile if the value doesn't fit beside the flag.
+	const VALUE_MASK: u8 = {
+		assert!(
+			VALUE_BITS <= 7,
+			"FlagAndValue's VALUE_BITS leaves no room for the flag"
+		);
+		((1_u16 << VALUE_BITS) - 1) as u8
+	};
+
+	fn unpack(self, byte: u8) -> Option<(bool, u8)> {
+		let mask = Self::VALUE_MASK;
+		let (flag, value) = match self.0 {
+			FlagBit::High => (byte & 0x80 != 0, byte & 0x7F),
+			FlagBit::Low => (byte & 1 != 0, byte >> 1),
+		};
+		if value & !mask == 0 {
+			Some((flag, value))
+		} else {
+			None
+		}
+	}
+
+	fn pack(self, (flag, value): (bool, u8)) -> Option<u8> {
+		if value & !Self::VALUE_MASK != 0 {
+			return None;
+		}
+		Some(match self.0 {
+			FlagBit::High => (flag as u8) << 7 | value,
+			FlagBit::Low => value << 1 | flag as u8,
+		})
+	}
+}
+impl<'de, const VALUE_BITS: u32> DeSeeder<'de, (bool, u8)> for FlagAndValue<VALUE_BITS> {
+	type Seed = Self;
+	fn seed(self) -> Self::Seed {
+		self
+	}
+}
+impl<const VALUE_BITS: u32> SerSeeder<(bool, u8)> for FlagAndValue<VALUE_BITS> {
+	fn seeded<'s>(&'s self, value: &'s (bool, u8)) -> Seeded<'s> {
+		Box::new(FlagAndValueSeeded(value, *self))
+	}
+}
+impl<'de, const VALUE_BITS: u32> de::DeserializeSeed<'de> for FlagAndValue<VALUE_BITS> {
+	type Value = (bool, u8);
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let byte: u8 = PhantomData.deserialize(deserializer)?;
+		self.unpack(byte).ok_or_else(|| {
+			de::Error::invalid_value(
+				de::Unexpected::Unsigned(byte.into()),
+				&format!("flag and {}-bit value", VALUE_BITS).as_str(),
+			)
+		})
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone)]
+pub struct FlagAndValueSeeded<'a, const VALUE_BITS: u32>(&'a (bool, u8), FlagAndValue<VALUE_BITS>);
+impl<'a, const VALUE_BITS: u32> ser::Serialize for FlagAndValueSeeded<'a, VALUE_BITS> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		self.1
+			.pack(*self.0)
+			.ok_or_else(|| {
+				ser::Error::custom(format_args!(
+					"Can't fit {} into {} value bits of FlagAndValue",
+					self.0 .1, VALUE_BITS
+				))
+			})?
+			.serialize(serializer)
+	}
+}
+
+/// Flat [`Vec<_>`] held as `(run length, value)` pairs of consecutive equal items.  
+/// (Parameters: [`Vec<_>`] [`Seeder`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct CompressRuns<ItemsSeeder>(pub ItemsSeeder);
+impl<'de, T: PartialEq, ItemsSeeder: DeSeeder<'de, Vec<T>>> DeSeeder<'de, Vec<(usize, T)>>
+	for CompressRuns<ItemsSeeder>
+{
+	type Seed = CompressRunsSeed<T, ItemsSeeder>;
+	fn seed(self) -> Self::Seed {
+		CompressRunsSeed(self.0, PhantomData)
+	}
+}
+impl<T: Clone, ItemsSeeder: SerSeeder<Vec<T>>> SerSeeder<Vec<(usize, T)>>
+	for CompressRuns<ItemsSeeder>
+{
+	fn seeded<'s>(&'s self, value: &'s Vec<(usize, T)>) -> Seeded<'s> {
+		Box::new(CompressRunsSeeded(value, &self.0))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct CompressRunsSeed<T, ItemsSeeder>(ItemsSeeder, PhantomData<T>);
+impl<'de, T: PartialEq, ItemsSeeder: DeSeeder<'de, Vec<T>>> de::DeserializeSeed<'de>
+	for CompressRunsSeed<T, ItemsSeeder>
+{
+	type Value = Vec<(usize, T)>;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let mut runs: Vec<(usize, T)> = Vec::new();
+		for item in self.0.seed().deserialize(deserializer)? {
+			match runs.last_mut() {
+				Some((length, value)) if *value == item => *length += 1,
+				_ => runs.push((1, item)),
+			}
+		}
+		Ok(runs)
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct CompressRunsSeeded<'a, T, ItemsSeeder>(&'a Vec<(usize, T)>, &'a ItemsSeeder);
+impl<'a, T: Clone, ItemsSeeder: SerSeeder<Vec<T>>> ser::Serialize
+	for CompressRunsSeeded<'a, T, ItemsSeeder>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		self.0
+			.iter()
+			.flat_map(|(length, value)| iter::repeat(value).take(*length).cloned())
+			.collect::<Vec<_>>()
+			.pipe(|flat| self.1.seeded(&flat).serialize(serializer))
+	}
+}
+
+/// UTF-8 [`String`] prefixed with its length in bytes, limited to a maximum number of [`char`]s.  
+/// (Parameters: length [`Seeder`], maximum [`char`] count)
+#[derive(Debug, Copy, Clone, Default)]
+pub struct LengthPrefixedUtf8<LengthSeeder>(pub LengthSeeder, pub usize);
+impl<'de, LengthSeeder: DeSeeder<'de, usize>> DeSeeder<'de, String>
+	for LengthPrefixedUtf8<LengthSeeder>
+{
+	type Seed = LengthPrefixedUtf8Seed<LengthSeeder>;
+	fn seed(self) -> Self::Seed {
+		LengthPrefixedUtf8Seed(self.0, self.1)
+	}
+}
+impl<LengthSeeder: SerSeeder<usize>> SerSeeder<String> for LengthPrefixedUtf8<LengthSeeder> {
+	fn seeded<'s>(&'s self, value: &'s String) -> Seeded<'s> {
+		Box::new(LengthPrefixedUtf8Seeded(value, &self.0, self.1))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct LengthPrefixedUtf8Seed<LengthSeeder>(LengthSeeder, usize);
+impl<'de, LengthSeeder: DeSeeder<'de, usize>> de::DeserializeSeed<'de>
+	for LengthPrefixedUtf8Seed<LengthSeeder>
+{
+	type Value = String;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<LengthSeeder>(LengthSeeder, usize);
+		impl<'de, LengthSeeder: DeSeeder<'de, usize>> de::Visitor<'de> for Visitor<LengthSeeder> {
+			type Value = String;
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "length-prefixed UTF-8 of at most {} chars", self.1)
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let Visitor(length_seeder, max_chars) = self;
+				let length = seq
+					.next_element_seed(length_seeder.seed())?
+					.ok_or_else(|| de::Error::invalid_length(0, &"length and UTF-8"))?;
+				let bytes: Vec<u8> = seq
+					.next_element_seed(TupleNSeed(length, SerdeLike, PhantomData))?
+					.ok_or_else(|| de::Error::invalid_length(1, &"length and UTF-8"))?;
+				let string = String::from_utf8(bytes).map_err(de::Error::custom)?;
+				let chars = string.chars().count();
+				if chars > max_chars {
+					return Err(de::Error::invalid_length(
+						chars,
+						&format!("at most {} chars", max_chars).as_str(),
+					));
+				}
+				Ok(string)
+			}
+		}
+
+		deserializer.deserialize_tuple(2, Visitor(self.0, self.1))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct LengthPrefixedUtf8Seeded<'a, LengthSeeder>(&'a String, &'a LengthSeeder, usize);
+impl<'a, LengthSeeder: SerSeeder<usize>> ser::Serialize
+	for LengthPrefixedUtf8Seeded<'a, LengthSeeder>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let chars = self.0.chars().count();
+		if chars > self.2 {
+			return Err(ser::Error::custom(format_args!(
+				"Can't serialize {} chars as LengthPrefixedUtf8 of at most {}",
+				chars, self.2
+			)));
+		}
+		let mut serialize_tuple = serializer.serialize_tuple(2)?;
+		serialize_tuple.serialize_element(&self.1.seeded(&self.0.len()))?;
+		serialize_tuple.serialize_element(&Literal(self.0.as_bytes()))?;
+		serialize_tuple.end()
+	}
+}
+
+/// Floating point number that must be finite.  
+/// As [`Option<_>`], NaN and infinities are read as [`None`] instead of failing, which is written as NaN.  
+/// (Parameters: float [`Seeder`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Finite<FloatSeeder>(pub FloatSeeder);
+impl<'de, F: Finiteable, FloatSeeder: DeSeeder<'de, F>> DeSeeder<'de, F> for Finite<FloatSeeder> {
+	type Seed = FiniteSeed<F, FloatSeeder>;
+	fn seed(self) -> Self::Seed {
+		FiniteSeed(self.0, PhantomData)
+	}
+}
+impl<'de, F: Finiteable, FloatSeeder: DeSeeder<'de, F>> DeSeeder<'de, Option<F>>
+	for Finite<FloatSeeder>
+{
+	type Seed = FiniteOptionSeed<F, FloatSeeder>;
+	fn seed(self) -> Self::Seed {
+		FiniteOptionSeed(self.0, PhantomData)
+	}
+}
+impl<F: Finiteable, FloatSeeder: SerSeeder<F>> SerSeeder<F> for Finite<FloatSeeder> {
+	fn seeded<'s>(&'s self, value: &'s F) -> Seeded<'s> {
+		Box::new(FiniteSeeded(value, &self.0))
+	}
+}
+impl<F: Finiteable, FloatSeeder: SerSeeder<F>> SerSeeder<Option<F>> for Finite<FloatSeeder> {
+	fn seeded<'s>(&'s self, value: &'s Option<F>) -> Seeded<'s> {
+		Box::new(FiniteOptionSeeded(value, &self.0))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct FiniteSeed<F, FloatSeeder>(FloatSeeder, PhantomData<F>);
+impl<'de, F: Finiteable, FloatSeeder: DeSeeder<'de, F>> de::DeserializeSeed<'de>
+	for FiniteSeed<F, FloatSeeder>
+{
+	type Value = F;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let value = self.0.seed().deserialize(deserializer)?;
+		if value.is_finite() {
+			Ok(value)
+		} else {
+			Err(de::Error::invalid_value(
+				de::Unexpected::Float(value.to_f64()),
+				&"finite number",
+			))
+		}
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct FiniteOptionSeed<F, FloatSeeder>(FloatSeeder, PhantomData<F>);
+impl<'de, F: Finiteable, FloatSeeder: DeSeeder<'de, F>> de::DeserializeSeed<'de>
+	for FiniteOptionSeed<F, FloatSeeder>
+{
+	type Value = Option<F>;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let value = self.0.seed().deserialize(deserializer)?;
+		Ok(if value.is_finite() { Some(value) } else { None })
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq)]
+pub struct FiniteSeeded<'a, F, FloatSeeder>(&'a F, &'a FloatSeeder);
+impl<'a, F: Finiteable, FloatSeeder: SerSeeder<F>> ser::Serialize
+	for FiniteSeeded<'a, F, FloatSeeder>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		if !self.0.is_finite() {
+			return Err(ser::Error::custom(format_args!(
+				"Can't serialize {} as Finite",
+				self.0.to_f64()
+			)));
+		}
+		self.1.seeded(self.0).serialize(serializer)
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq)]
+pub struct FiniteOptionSeeded<'a, F, FloatSeeder>(&'a Option<F>, &'a FloatSeeder);
+impl<'a, F: Finiteable, FloatSeeder: SerSeeder<F>> ser::Serialize
+	for FiniteOptionSeeded<'a, F, FloatSeeder>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		match self.0 {
+			Some(value) => FiniteSeeded(value, self.1).serialize(serializer),
+			None => F::NAN.pipe(|nan| self.1.seeded(&nan).serialize(serializer)),
+		}
+	}
+}
+
+/// See [`Finite`].
+pub trait Finiteable: Copy {
+	const NAN: Self;
+	fn is_finite(self) -> bool;
+	fn to_f64(self) -> f64;
+}
+impl Finiteable for f32 {
+	const NAN: Self = f32::NAN;
+	fn is_finite(self) -> bool {
+		self.is_finite()
+	}
+	fn to_f64(self) -> f64 {
+		self.into()
+	}
+}
+impl Finiteable for f64 {
+	const NAN: Self = f64::NAN;
+	fn is_finite(self) -> bool {
+		self.is_finite()
+	}
+	fn to_f64(self) -> f64 {
+		self
+	}
+}
+
+/// [`Vec<_>`] as tuple prefixed with its length minus `BIAS`.  
+/// (Usage: [`OffsetCount::<BIAS, _, _>(length_seeder, item_seeder)`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct OffsetCount<const BIAS: i64, LengthSeeder, ItemSeeder>(pub LengthSeeder, pub ItemSeeder);
+impl<
+		'de,
+		const BIAS: i64,
+		T,
+		LengthSeeder: DeSeeder<'de, usize>,
+		ItemSeeder: Clone + DeSeeder<'de, T>,
+	> DeSeeder<'de, Vec<T>> for OffsetCount<BIAS, LengthSeeder, ItemSeeder>
+{
+	type Seed = OffsetCountSeed<BIAS, T, LengthSeeder, ItemSeeder>;
+	fn seed(self) -> Self::Seed {
+		OffsetCountSeed(self.0, self.1, PhantomData)
+	}
+}
+impl<const BIAS: i64, T, LengthSeeder: SerSeeder<usize>, ItemSeeder: SerSeeder<T>> SerSeeder<Vec<T>>
+	for OffsetCount<BIAS, LengthSeeder, ItemSeeder>
+{
+	fn seeded<'s>(&'s self, value: &'s Vec<T>) -> Seeded<'s> {
+		Box::new(OffsetCountSeeded::<BIAS, _, _, _>(value, &self.0, &self.1))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct OffsetCountSeed<const BIAS: i64, T, LengthSeeder, ItemSeeder>(
+	LengthSeeder,
+	ItemSeeder,
+	PhantomData<T>,
+);
+impl<
+		'de,
+		const BIAS: i64,
+		T,
+		LengthSeeder: DeSeeder<'de, usize>,
+		ItemSeeder: Clone + DeSeeder<'de, T>,
+	> de::DeserializeSeed<'de> for OffsetCountSeed<BIAS, T, LengthSeeder, ItemSeeder>
+{
+	type Value = Vec<T>;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<const BIAS: i64, T, LengthSeeder, ItemSeeder>(
+			LengthSeeder,
+			ItemSeeder,
+			PhantomData<T>,
+		);
+		impl<
+				'de,
+				const BIAS: i64,
+				T,
+				LengthSeeder: DeSeeder<'de, usize>,
+				ItemSeeder: Clone + DeSeeder<'de, T>,
+			> de::Visitor<'de> for Visitor<BIAS, T, LengthSeeder, ItemSeeder>
+		{
+			type Value = Vec<T>;
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "OffsetCount with bias {}", BIAS)
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let Visitor(length_seeder, item_seeder, _) = self;
+				let stored = seq
+					.next_element_seed(length_seeder.seed())?
+					.ok_or_else(|| de::Error::invalid_length(0, &"biased count and items"))?;
+				let length = i64::try_from(stored)
+					.ok()
+					.and_then(|stored| stored.checked_add(BIAS))
+					.and_then(|length| usize::try_from(length).ok())
+					.ok_or_else(|| {
+						de::Error::custom(format_args!(
+							"Stored count {} with bias {} is out of range",
+							stored, BIAS
+						))
+					})?;
+				seq.next_element_seed(TupleNSeed(length, item_seeder, PhantomData))?
+					.ok_or_else(|| de::Error::invalid_length(1, &"biased count and items"))
+			}
+		}
+
+		deserializer.deserialize_tuple(2, Visitor::<BIAS, _, _, _>(self.0, self.1, PhantomData))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct OffsetCountSeeded<'a, const BIAS: i64, T, LengthSeeder, ItemSeeder>(
+	&'a Vec<T>,
+	&'a LengthSeeder,
+	&'a ItemSeeder,
+);
+impl<'a, const BIAS: i64, T, LengthSeeder: SerSeeder<usize>, ItemSeeder: SerSeeder<T>>
+	ser::Serialize for OffsetCountSeeded<'a, BIAS, T, LengthSeeder, ItemSeeder>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let stored = i64::try_from(self.0.len())
+			.ok()
+			.and_then(|length| length.checked_sub(BIAS))
+			.and_then(|stored| usize::try_from(stored).ok())
+			.ok_or_else(|| {
+				ser::Error::custom(format_args!(
+					"Can't store length {} with bias {}",
+					self.0.len(),
+					BIAS
+				))
+			})?;
+		let mut serialize_tuple = serializer.serialize_tuple(2)?;
+		serialize_tuple.serialize_element(&self.1.seeded(&stored))?;
+		serialize_tuple.serialize_element(&TupleNSeeded(self.0, self.0.len(), self.2))?;
+		serialize_tuple.end()
+	}
+}
+
+/// Two [`Vec<_>`]s of equal length, stored as one shared length followed by each as tuple.  
+/// (Parameters: length [`Seeder`], `A` [`Seeder`], `B` [`Seeder`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ParallelArrays<LengthSeeder, ASeeder, BSeeder>(
+	pub LengthSeeder,
+	pub ASeeder,
+	pub BSeeder,
+);
+impl<
+		'de,
+		A,
+		B,
+		LengthSeeder: DeSeeder<'de, usize>,
+		ASeeder: Clone + DeSeeder<'de, A>,
+		BSeeder: Clone + DeSeeder<'de, B>,
+	> DeSeeder<'de, (Vec<A>, Vec<B>)> for ParallelArrays<LengthSeeder, ASeeder, BSeeder>
+{
+	type Seed = ParallelArraysSeed<A, B, LengthSeeder, ASeeder, BSeeder>;
+	fn seed(self) -> Self::Seed {
+		ParallelArraysSeed(self.0, self.1, self.2, PhantomData)
+	}
+}
+impl<A, B, LengthSeeder: SerSeeder<usize>, ASeeder: SerSeeder<A>, BSeeder: SerSeeder<B>>
+	SerSeeder<(Vec<A>, Vec<B>)> for ParallelArrays<LengthSeeder, ASeeder, BSeeder>
+{
+	fn seeded<'s>(&'s self, value: &'s (Vec<A>, Vec<B>)) -> Seeded<'s> {
+		Box::new(ParallelArraysSeeded(value, &self.0, &self.1, &self.2))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ParallelArraysSeed<A, B, LengthSeeder, ASeeder, BSeeder>(
+	LengthSeeder,
+	ASeeder,
+	BSeeder,
+	PhantomData<(A, B)>,
+);
+impl<
+		'de,
+		A,
+		B,
+		LengthSeeder: DeSeeder<'de, usize>,
+		ASeeder: Clone + DeSeeder<'de, A>,
+		BSeeder: Clone + DeSeeder<'de, B>,
+	> de::DeserializeSeed<'de> for ParallelArraysSeed<A, B, LengthSeeder, ASeeder, BSeeder>
+{
+	type Value = (Vec<A>, Vec<B>);
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<A, B, LengthSeeder, ASeeder, BSeeder>(
+			LengthSeeder,
+			ASeeder,
+			BSeeder,
+			PhantomData<(A, B)>,
+		);
+		impl<
+				'de,
+				A,
+				B,
+				LengthSeeder: DeSeeder<'de, usize>,
+				ASeeder: Clone + DeSeeder<'de, A>,
+				BSeeder: Clone + DeSeeder<'de, B>,
+			> de::Visitor<'de> for Visitor<A, B, LengthSeeder, ASeeder, BSeeder>
+		{
+			type Value = (Vec<A>, Vec<B>);
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "ParallelArrays")
+			}
+
+			fn visit_seq<S: de::SeqAccess<'de>>(self, mut seq: S) -> Result<Self::Value, S::Error> {
+				let Visitor(length_seeder, a_seeder, b_seeder, _) = self;
+				let length = seq
+					.next_element_seed(length_seeder.seed())?
+					.ok_or_else(|| de::Error::invalid_length(0, &"length and two arrays"))?;
+				let a = seq
+					.next_element_seed(TupleNSeed(length, a_seeder, PhantomData))?
+					.ok_or_else(|| de::Error::invalid_length(1, &"length and two arrays"))?;
+				let b = seq
+					.next_element_seed(TupleNSeed(length, b_seeder, PhantomData))?
+					.ok_or_else(|| de::Error::invalid_length(2, &"length and two arrays"))?;
+				Ok((a, b))
+			}
+		}
+
+		deserializer.deserialize_tuple(3, Visitor(self.0, self.1, self.2, PhantomData))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct ParallelArraysSeeded<'a, A, B, LengthSeeder, ASeeder, BSeeder>(
+	&'a (Vec<A>, Vec<B>),
+	&'a LengthSeeder,
+	&'a ASeeder,
+	&'a BSeeder,
+);
+impl<'a, A, B, LengthSeeder: SerSeeder<usize>, ASeeder: SerSeeder<A>, BSeeder: SerSeeder<B>>
+	ser::Serialize for ParallelArraysSeeded<'a, A, B, LengthSeeder, ASeeder, BSeeder>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let (a, b) = self.0;
+		if a.len() != b.len() {
+			return Err(ser::Error::custom(format_args!(
+				"Can't serialize ParallelArrays of different lengths {} and {}",
+				a.len(),
+				b.len()
+			)));
+		}
+		let mut serialize_tuple = serializer.serialize_tuple(3)?;
+		serialize_tuple.serialize_element(&self.1.seeded(&a.len()))?;
+		serialize_tuple.serialize_element(&TupleNSeeded(a, a.len(), self.2))?;
+		serialize_tuple.serialize_element(&TupleNSeeded(b, b.len(), self.3))?;
+		serialize_tuple.end()
+	}
+}
+
+/// [`uuid::Uuid`] in Microsoft GUID layout, with its first three fields little-endian.
+#[cfg(feature = "uuid")]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Guid;
+#[cfg(feature = "uuid")]
+impl Guid {
+	/// Converts between [`uuid::Uuid`] byte order and GUID byte order (in either direction).
+	fn swap(mut bytes: [u8; 16]) -> [u8; 16] {
+		bytes[0..4].reverse();
+		bytes[4..6].reverse();
+		bytes[6..8].reverse();
+		bytes
+	}
+}
+#[cfg(feature = "uuid")]
+impl<'de> DeSeeder<'de, uuid::Uuid> for Guid {
+	type Seed = Self;
+	fn seed(self) -> Self::Seed {
+		self
+	}
+}
+#[cfg(feature = "uuid")]
+impl SerSeeder<uuid::Uuid> for Guid {
+	fn seeded<'s>(&self, value: &'s uuid::Uuid) -> Seeded<'s> {
+		Box::new(Guid::swap(*value.as_bytes()))
+	}
+}
+#[cfg(feature = "uuid")]
+impl<'de> de::DeserializeSeed<'de> for Guid {
+	type Value = uuid::Uuid;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let bytes: [u8; 16] = PhantomData.deserialize(deserializer)?;
+		uuid::Uuid::from_bytes(Guid::swap(bytes)).pipe(Ok)
+	}
+}
+
+/// Value parsed from a fixed-size region that must not be entirely `0xFF`, as in erased flash memory.  
+/// Requires a [`raw::Deserializer`] to read and a serializer that writes like [`raw::Serializer`] to write.
+/// The value is padded to `N` bytes with `0xFF` when written.  
+/// (Parameters: inner [`Seeder`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Initialized<const N: usize, Inner>(pub Inner);
+impl<'de, const N: usize, T, Inner: DeSeeder<'de, T>> DeSeeder<'de, T> for Initialized<N, Inner> {
+	type Seed = InitializedSeed<N, T, Inner>;
+	fn seed(self) -> Self::Seed {
+		InitializedSeed(self.0, PhantomData)
+	}
+}
+impl<const N: usize, T, Inner: SerSeeder<T>> SerSeeder<T> for Initialized<N, Inner> {
+	fn seeded<'s>(&'s self, value: &'s T) -> Seeded<'s> {
+		Box::new(InitializedSeeded::<N, _, _>(value, &self.0))
+	}
+}
+
+fn is_erased(region: &[u8]) -> bool {
+	region.iter().all(|byte| *byte == 0xFF)
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct InitializedSeed<const N: usize, T, Inner>(Inner, PhantomData<T>);
+impl<'de, const N: usize, T, Inner: DeSeeder<'de, T>> de::DeserializeSeed<'de>
+	for InitializedSeed<N, T, Inner>
+{
+	type Value = T;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<const N: usize, T, Inner>(Inner, PhantomData<T>);
+		impl<'de, const N: usize, T, Inner: DeSeeder<'de, T>> de::Visitor<'de> for Visitor<N, T, Inner> {
+			type Value = T;
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "{} initialized bytes", N)
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let Visitor(inner, _) = self;
+				raw::next_nested(
+					&mut seq,
+					|remaining| {
+						let region = remaining.get(..N).ok_or_else(|| {
+							de::Error::invalid_length(
+								remaining.len(),
+								&format!("{} bytes", N).as_str(),
+							)
+						})?;
+						if is_erased(region) {
+							return Err(de::Error::custom(format_args!(
+								"Region of {} bytes is uninitialized (all 0xFF)",
+								N
+							)));
+						}
+						Ok(region)
+					},
+					|region| {
+						let value = inner.seed().deserialize(&mut *region)?;
+						// The whole region is consumed, even if the value is shorter.
+						raw::SkipSeed(N - region.offset()).deserialize(region)?;
+						Ok(value)
+					},
+					de::Error::custom,
+				)
+				.map(|(value, _)| value)
+			}
+		}
+
+		deserializer.deserialize_tuple(2, Visitor::<N, _, _>(self.0, PhantomData))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct InitializedSeeded<'a, const N: usize, T, Inner>(&'a T, &'a Inner);
+impl<'a, const N: usize, T, Inner: SerSeeder<T>> ser::Serialize
+	for InitializedSeeded<'a, N, T, Inner>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let mut region = raw::Serializer::new();
+		self.1
+			.seeded(self.0)
+			.serialize(&mut region)
+			.map_err(ser::Error::custom)?;
+		let mut region = region.into_inner();
+		if region.len() > N {
+			return Err(ser::Error::custom(format_args!(
+				"Can't fit {} bytes into Initialized region of {}",
+				region.len(),
+				N
+			)));
+		}
+		region.resize(N, 0xFF);
+		if is_erased(&region) {
+			return Err(ser::Error::custom(
+				"Can't serialize a value that reads as uninitialized (all 0xFF)",
+			));
+		}
+		Literal(&region).serialize(serializer)
+	}
+}
+
+/// [`Vec<_>`] of length-prefixed frames up to the end of input, each containing exactly one item.  
+/// Requires a [`raw::Deserializer`] to read and a serializer that writes like [`raw::Serializer`] to write.  
+/// (Parameters: frame length [`Seeder`], item [`Seeder`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct FramedStream<LengthSeeder, ItemSeeder>(pub LengthSeeder, pub ItemSeeder);
+impl<'de, T, LengthSeeder: Clone + DeSeeder<'de, usize>, ItemSeeder: Clone + DeSeeder<'de, T>>
+	DeSeeder<'de, Vec<T>> for FramedStream<LengthSeeder, ItemSeeder>
+{
+	type Seed = FramedStreamSeed<T, LengthSeeder, ItemSeeder>;
+	fn seed(self) -> Self::Seed {
+		FramedStreamSeed(self.0, self.1, PhantomData)
+	}
+}
+impl<T, LengthSeeder: SerSeeder<usize>, ItemSeeder: SerSeeder<T>> SerSeeder<Vec<T>>
+	for FramedStream<LengthSeeder, ItemSeeder>
+{
+	fn seeded<'s>(&'s self, value: &'s Vec<T>) -> Seeded<'s> {
+		Box::new(FramedStreamSeeded(value, &self.0, &self.1))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct FramedStreamSeed<T, LengthSeeder, ItemSeeder>(LengthSeeder, ItemSeeder, PhantomData<T>);
+impl<'de, T, LengthSeeder: Clone + DeSeeder<'de, usize>, ItemSeeder: Clone + DeSeeder<'de, T>>
+	de::DeserializeSeed<'de> for FramedStreamSeed<T, LengthSeeder, ItemSeeder>
+{
+	type Value = Vec<T>;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<T, LengthSeeder, ItemSeeder>(LengthSeeder, ItemSeeder, PhantomData<T>);
+		impl<
+				'de,
+				T,
+				LengthSeeder: Clone + DeSeeder<'de, usize>,
+				ItemSeeder: Clone + DeSeeder<'de, T>,
+			> de::Visitor<'de> for Visitor<T, LengthSeeder, ItemSeeder>
+		{
+			type Value = Vec<T>;
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "FramedStream")
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let mut items = Vec::new();
+				while let Some(length) = seq.next_element_seed(self.0.clone().seed())? {
+					let (item, _) = raw::next_nested(
+						&mut seq,
+						|remaining| {
+							remaining.get(..length).ok_or_else(|| {
+								de::Error::custom(format_args!(
+									"Frame {} is {} bytes long, but only {} remain",
+									items.len(),
+									length,
+									remaining.len()
+								))
+							})
+						},
+						|limited| {
+							let item = self.1.clone().seed().deserialize(&mut *limited)?;
+							limited.end().map(|()| item)
+						},
+						|error| {
+							de::Error::custom(format_args!("{} (in frame {})", error, items.len()))
+						},
+					)?;
+					items.push(item);
+				}
+				trace!("Read {} frames.", items.len());
+				Ok(items)
+			}
+		}
+
+		deserializer.deserialize_seq(Visitor(self.0, self.1, PhantomData))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct FramedStreamSeeded<'a, T, LengthSeeder, ItemSeeder>(
+	&'a Vec<T>,
+	&'a LengthSeeder,
+	&'a ItemSeeder,
+);
+impl<'a, T, LengthSeeder: SerSeeder<usize>, ItemSeeder: SerSeeder<T>> ser::Serialize
+	for FramedStreamSeeded<'a, T, LengthSeeder, ItemSeeder>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let mut serialize_seq = serializer.serialize_seq(Some(self.0.len() * 2))?;
+		for item in self.0 {
+			let mut frame = raw::Serializer::new();
+			self.2
+				.seeded(item)
+				.serialize(&mut frame)
+				.map_err(ser::Error::custom)?;
+			let frame = frame.into_inner();
+			serialize_seq.serialize_element(&self.1.seeded(&frame.len()))?;
+			serialize_seq.serialize_element(&Literal(&frame))?;
+		}
+		serialize_seq.end()
+	}
+}
+
+/// [`time::Date`] stored as integer count of days since an explicit epoch.  
+/// (Usage: [`DaysSinceEpoch::new(int_seeder, epoch)`])
+#[cfg(feature = "time")]
+#[derive(Debug, Copy, Clone)]
+pub struct DaysSinceEpoch<IntSeeder, Int>(IntSeeder, time::Date, PhantomData<Int>);
+#[cfg(feature = "time")]
+impl<IntSeeder, Int> DaysSinceEpoch<IntSeeder, Int> {
+	pub fn new(int_seeder: IntSeeder, epoch: time::Date) -> Self {
+		Self(int_seeder, epoch, PhantomData)
+	}
+}
+#[cfg(feature = "time")]
+impl<'de, Int: Into<i64>, IntSeeder: DeSeeder<'de, Int>> DeSeeder<'de, time::Date>
+	for DaysSinceEpoch<IntSeeder, Int>
+{
+	type Seed = DaysSinceEpochSeed<IntSeeder, Int>;
+	fn seed(self) -> Self::Seed {
+		DaysSinceEpochSeed(self.0, self.1, PhantomData)
+	}
+}
+#[cfg(feature = "time")]
+impl<Int: TryFrom<i64>, IntSeeder: SerSeeder<Int>> SerSeeder<time::Date>
+	for DaysSinceEpoch<IntSeeder, Int>
+{
+	fn seeded<'s>(&'s self, value: &'s time::Date) -> Seeded<'s> {
+		Box::new(DaysSinceEpochSeeded(value, &self.0, self.1, self.2))
+	}
+}
+
+#[cfg(feature = "time")]
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone)]
+pub struct DaysSinceEpochSeed<IntSeeder, Int>(IntSeeder, time::Date, PhantomData<Int>);
+#[cfg(feature = "time")]
+impl<'de, Int: Into<i64>, IntSeeder: DeSeeder<'de, Int>> de::DeserializeSeed<'de>
+	for DaysSinceEpochSeed<IntSeeder, Int>
+{
+	type Value = time::Date;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let DaysSinceEpochSeed(int_seeder, epoch, _) = self;
+		let days: i64 = int_seeder.seed().deserialize(deserializer)?.into();
+		i64::from(epoch.to_julian_day())
+			.checked_add(days)
+			.and_then(|julian_day| i32::try_from(julian_day).ok())
+			.and_then(|julian_day| time::Date::from_julian_day(julian_day).ok())
+			.ok_or_else(|| {
+				de::Error::custom(format_args!(
+					"{} days since {} is out of range",
+					days, epoch
+				))
+			})
+	}
+}
+
+#[cfg(feature = "time")]
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone)]
+pub struct DaysSinceEpochSeeded<'a, IntSeeder, Int>(
+	&'a time::Date,
+	&'a IntSeeder,
+	time::Date,
+	PhantomData<Int>,
+);
+#[cfg(feature = "time")]
+impl<'a, Int: TryFrom<i64>, IntSeeder: SerSeeder<Int>> ser::Serialize
+	for DaysSinceEpochSeeded<'a, IntSeeder, Int>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let days = i64::from(self.0.to_julian_day()) - i64::from(self.2.to_julian_day());
+		Int::try_from(days)
+			.map_err(|_| {
+				ser::Error::custom(format_args!(
+					"Can't store {} ({} days since {}) in {}",
+					self.0,
+					days,
+					self.2,
+					core::any::type_name::<Int>()
+				))
+			})?
+			.pipe(|days| self.1.seeded(&days).serialize(serializer))
+	}
+}
+
+/// [`Vec<_>`] split across pages, each of which may point to the next one.  
+/// Pages are read as `(items, marker)`, and the marker is turned into the next page's offset (if any), relative to the start of the first page.
+/// Only the first page is consumed from the input. This is read-only and requires a [`raw::Deserializer`].  
+/// (Usage: [`Paged::new(page_seeder, next_fn: Fn(&Marker) -> Option<usize>)`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Paged<PageSeeder, NextFn, Marker>(PageSeeder, NextFn, PhantomData<Marker>);
+impl<PageSeeder, NextFn, Marker> Paged<PageSeeder, NextFn, Marker> {
+	pub fn new(page_seeder: PageSeeder, next_fn: NextFn) -> Self {
+		Self(page_seeder, next_fn, PhantomData)
+	}
+}
+impl<
+		'de,
+		T,
+		Marker,
+		PageSeeder: Clone + DeSeeder<'de, (Vec<T>, Marker)>,
+		NextFn: Fn(&Marker) -> Option<usize>,
+	> DeSeeder<'de, Vec<T>> for Paged<PageSeeder, NextFn, Marker>
+{
+	type Seed = PagedSeed<T, Marker, PageSeeder, NextFn>;
+	fn seed(self) -> Self::Seed {
+		PagedSeed(self.0, self.1, PhantomData)
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct PagedSeed<T, Marker, PageSeeder, NextFn>(PageSeeder, NextFn, PhantomData<(T, Marker)>);
+impl<
+		'de,
+		T,
+		Marker,
+		PageSeeder: Clone + DeSeeder<'de, (Vec<T>, Marker)>,
+		NextFn: Fn(&Marker) -> Option<usize>,
+	> de::DeserializeSeed<'de> for PagedSeed<T, Marker, PageSeeder, NextFn>
+{
+	type Value = Vec<T>;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<T, Marker, PageSeeder, NextFn>(PageSeeder, NextFn, PhantomData<(T, Marker)>);
+		impl<
+				'de,
+				T,
+				Marker,
+				PageSeeder: Clone + DeSeeder<'de, (Vec<T>, Marker)>,
+				NextFn: Fn(&Marker) -> Option<usize>,
+			> de::Visitor<'de> for Visitor<T, Marker, PageSeeder, NextFn>
+		{
+			type Value = Vec<T>;
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "Paged")
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let Visitor(page_seeder, next, _) = self;
+				let base = raw::next_remaining(&mut seq)?;
+
+				let mut first = raw::Deserializer::new(base);
+				let (mut items, mut marker) = page_seeder
+					.clone()
+					.seed()
+					.deserialize(&mut first)
+					.map_err(de::Error::custom)?;
+				let mut visited = BTreeSet::new();
+				visited.insert(0);
+				while let Some(offset) = next(&marker) {
+					if !visited.insert(offset) {
+						return Err(de::Error::custom(format_args!(
+							"Page at offset {:#X} visited twice",
+							offset
+						)));
+					}
+					let page = base.get(offset..).ok_or_else(|| {
+						de::Error::custom(format_args!(
+							"Page offset {:#X} is out of bounds ({:#X} bytes)",
+							offset,
+							base.len()
+						))
+					})?;
+					let (page_items, page_marker) = page_seeder
+						.clone()
+						.seed()
+						.deserialize(&mut raw::Deserializer::new(page))
+						.map_err(|error| {
+							de::Error::custom(format_args!("{} (in page at {:#X})", error, offset))
+						})?;
+					trace!(
+						"Read {} items from page at {:#X}.",
+						page_items.len(),
+						offset
+					);
+					items.extend(page_items);
+					marker = page_marker;
+				}
+
+				seq.next_element_seed(raw::SkipSeed(first.offset()))?
+					.ok_or_else(|| de::Error::invalid_length(1, &"consumed input"))?;
+				Ok(items)
+			}
+		}
+
+		deserializer.deserialize_tuple(2, Visitor(self.0, self.1, PhantomData))
+	}
+}
+
+/// Signed integer representation selected for a [`SignedRepr`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum SignedEncoding {
+	/// The top bit has negative weight.
+	#[default]
+	TwosComplement,
+	/// The stored value is the signed one plus `2^(BITS - 1)` (excess-K).
+	OffsetBinary,
+}
+
+impl SignedEncoding {
+	fn decode(self, bits: u32, raw: u64) -> i64 {
+		let half = 1_i128 << (bits - 1);
+		let raw = raw as i128;
+		(match self {
+			SignedEncoding::TwosComplement if raw >= half => raw - 2 * half,
+			SignedEncoding::TwosComplement => raw,
+			SignedEncoding::OffsetBinary => raw - half,
+		}) as i64
+	}
+
+	fn encode(self, bits: u32, value: i64) -> Option<u64> {
+		let half = 1_i128 << (bits - 1);
+		let value = value as i128;
+		if value < -half || value >= half {
+			return None;
+		}
+		Some(match self {
+			SignedEncoding::TwosComplement => (value & (2 * half - 1)) as u64,
+			SignedEncoding::OffsetBinary => (value + half) as u64,
+		})
+	}
+}
+
+/// [`i64`] stored in the low `BITS` bits of an unsigned integer. Any higher bits must be zero.  
+/// (Usage: [`SignedRepr::<BITS, _, _>::twos_complement(inner)`], [`SignedRepr::<BITS, _, _>::offset_binary(inner)`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct SignedRepr<const BITS: u32, Inner, U>(Inner, SignedEncoding, PhantomData<U>);
+impl<const BITS: u32, Inner, U> SignedRepr<BITS, Inner, U> {
+	pub fn twos_complement(inner: Inner) -> Self {
+		Self(inner, SignedEncoding::TwosComplement, PhantomData)
+	}
+
+	pub fn offset_binary(inner: Inner) -> Self {
+		Self(inner, SignedEncoding::OffsetBinary, PhantomData)
+	}
+}
+impl<const BITS: u32, Inner, U: Bitfieldable> SignedRepr<BITS, Inner, U> {
+	/// Using this fails to compile if `BITS` is zero or wider than `U`.
+	const BITS: u32 = {
+		assert!(
+			BITS != 0 && BITS <= U::BITS,
+			"SignedRepr's BITS must be nonzero and fit the unsigned integer"
+		);
+		BITS
+	};
+}
+impl<'de, const BITS: u32, U: Bitfieldable, Inner: DeSeeder<'de, U>> DeSeeder<'de, i64>
+	for SignedRepr<BITS, Inner, U>
+{
+	type Seed = SignedReprSeed<BITS, Inner, U>;
+	fn seed(self) -> Self::Seed {
+		SignedReprSeed(self.0, self.1, PhantomData)
+	}
+}
+impl<const BITS: u32, U: Bitfieldable, Inner: SerSeeder<U>> SerSeeder<i64>
+	for SignedRepr<BITS, Inner, U>
+{
+	fn seeded<'s>(&'s self, value: &'s i64) -> Seeded<'s> {
+		Box::new(SignedReprSeeded::<BITS, _, U>(
+			value,
+			&self.0,
+			self.1,
+			PhantomData,
+		))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct SignedReprSeed<const BITS: u32, Inner, U>(Inner, SignedEncoding, PhantomData<U>);
+impl<'de, const BITS: u32, U: Bitfieldable, Inner: DeSeeder<'de, U>> de::DeserializeSeed<'de>
+	for SignedReprSeed<BITS, Inner, U>
+{
+	type Value = i64;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let bits = SignedRepr::<BITS, Inner, U>::BITS;
+		let SignedReprSeed(inner, encoding, _) = self;
+		let raw = inner.seed().deserialize(deserializer)?.to_u64();
+		if raw & !low_bits_mask(bits) != 0 {
+			return Err(de::Error::invalid_value(
+				de::Unexpected::Unsigned(raw),
+				&format!("{}-bit value", bits).as_str(),
+			));
+		}
+		Ok(encoding.decode(bits, raw))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone)]
+pub struct SignedReprSeeded<'a, const BITS: u32, Inner, U>(
+	&'a i64,
+	&'a Inner,
+	SignedEncoding,
+	PhantomData<U>,
+);
+impl<'a, const BITS: u32, U: Bitfieldable, Inner: SerSeeder<U>> ser::Serialize
+	for SignedReprSeeded<'a, BITS, Inner, U>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let bits = SignedRepr::<BITS, Inner, U>::BITS;
+		self.2
+			.encode(bits, *self.0)
+			.ok_or_else(|| {
+				ser::Error::custom(format_args!(
+					"Can't fit {} into {}-bit {:?}",
+					self.0, bits, self.2
+				))
+			})?
+			.pipe(U::from_u64)
+			.pipe(|raw| self.1.seeded(&raw).serialize(serializer))
+	}
+}
+
+/// Value that is also recorded into a caller-provided cell whenever it's read or written, for later cross-field checks.  
+/// (Parameters: `&RefCell<Option<T>>`, inner [`Seeder`])
+#[derive(Debug, Copy, Clone)]
+pub struct Capture<'a, T, Inner>(pub &'a RefCell<Option<T>>, pub Inner);
+impl<'a, 'de, T: Clone, Inner: DeSeeder<'de, T>> DeSeeder<'de, T> for Capture<'a, T, Inner> {
+	type Seed = CaptureSeed<'a, T, Inner>;
+	fn seed(self) -> Self::Seed {
+		CaptureSeed(self.0, self.1)
+	}
+}
+impl<'a, T: Clone, Inner: SerSeeder<T>> SerSeeder<T> for Capture<'a, T, Inner> {
+	fn seeded<'s>(&'s self, value: &'s T) -> Seeded<'s> {
+		self.0.replace(Some(value.clone()));
+		self.1.seeded(value)
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone)]
+pub struct CaptureSeed<'a, T, Inner>(&'a RefCell<Option<T>>, Inner);
+impl<'a, 'de, T: Clone, Inner: DeSeeder<'de, T>> de::DeserializeSeed<'de>
+	for CaptureSeed<'a, T, Inner>
+{
+	type Value = T;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let value = self.1.seed().deserialize(deserializer)?;
+		self.0.replace(Some(value.clone()));
+		Ok(value)
+	}
+}
+
+/// [`Vec<_>`] of `CAP` items, stored as count followed by only the items present.  
+/// Missing items are filled in with the default item when read. Trailing default items are written only if requested.  
+/// (Parameters: count [`Seeder`], item [`Seeder`], default item, whether to write trailing defaults)
+#[derive(Debug, Copy, Clone, Default)]
+pub struct PadToLen<const CAP: usize, LengthSeeder, ItemSeeder, T>(
+	pub LengthSeeder,
+	pub ItemSeeder,
+	pub T,
+	pub bool,
+);
+impl<
+		'de,
+		const CAP: usize,
+		T: Clone,
+		LengthSeeder: DeSeeder<'de, usize>,
+		ItemSeeder: Clone + DeSeeder<'de, T>,
+	> DeSeeder<'de, Vec<T>> for PadToLen<CAP, LengthSeeder, ItemSeeder, T>
+{
+	type Seed = PadToLenSeed<CAP, T, LengthSeeder, ItemSeeder>;
+	fn seed(self) -> Self::Seed {
+		PadToLenSeed(self.0, self.1, self.2)
+	}
+}
+impl<const CAP: usize, T: PartialEq, LengthSeeder: SerSeeder<usize>, ItemSeeder: SerSeeder<T>>
+	SerSeeder<Vec<T>> for PadToLen<CAP, LengthSeeder, ItemSeeder, T>
+{
+	fn seeded<'s>(&'s self, value: &'s Vec<T>) -> Seeded<'s> {
+		Box::new(PadToLenSeeded::<CAP, _, _, _>(value, self))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct PadToLenSeed<const CAP: usize, T, LengthSeeder, ItemSeeder>(LengthSeeder, ItemSeeder, T);
+impl<
+		'de,
+		const CAP: usize,
+		T: Clone,
+		LengthSeeder: DeSeeder<'de, usize>,
+		ItemSeeder: Clone + DeSeeder<'de, T>,
+	> de::DeserializeSeed<'de> for PadToLenSeed<CAP, T, LengthSeeder, ItemSeeder>
+{
+	type Value = Vec<T>;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<const CAP: usize, T, LengthSeeder, ItemSeeder>(LengthSeeder, ItemSeeder, T);
+		impl<
+				'de,
+				const CAP: usize,
+				T: Clone,
+				LengthSeeder: DeSeeder<'de, usize>,
+				ItemSeeder: Clone + DeSeeder<'de, T>,
+			> de::Visitor<'de> for Visitor<CAP, T, LengthSeeder, ItemSeeder>
+		{
+			type Value = Vec<T>;
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "count and at most {} items", CAP)
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let Visitor(length_seeder, item_seeder, default) = self;
+				let length = seq
+					.next_element_seed(length_seeder.seed())?
+					.ok_or_else(|| de::Error::invalid_length(0, &"count and items"))?;
+				if length > CAP {
+					return Err(de::Error::invalid_length(
+						length,
+						&format!("at most {} items", CAP).as_str(),
+					));
+				}
+				let mut items: Vec<T> = seq
+					.next_element_seed(TupleNSeed(length, item_seeder, PhantomData))?
+					.ok_or_else(|| de::Error::invalid_length(1, &"count and items"))?;
+				items.resize(CAP, default);
+				Ok(items)
+			}
+		}
+
+		deserializer.deserialize_tuple(2, Visitor::<CAP, _, _, _>(self.0, self.1, self.2))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone)]
+pub struct PadToLenSeeded<'a, const CAP: usize, T, LengthSeeder, ItemSeeder>(
+	&'a Vec<T>,
+	&'a PadToLen<CAP, LengthSeeder, ItemSeeder, T>,
+);
+impl<
+		'a,
+		const CAP: usize,
+		T: PartialEq,
+		LengthSeeder: SerSeeder<usize>,
+		ItemSeeder: SerSeeder<T>,
+	> ser::Serialize for PadToLenSeeded<'a, CAP, T, LengthSeeder, ItemSeeder>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let PadToLen(length_seeder, item_seeder, default, write_defaults) = self.1;
+		if self.0.len() > CAP {
+			return Err(ser::Error::custom(format_args!(
+				"Can't serialize {} items as PadToLen with capacity {}",
+				self.0.len(),
+				CAP
+			)));
+		}
+		let mut items = self.0.as_slice();
+		if !write_defaults {
+			while let Some((last, rest)) = items.split_last() {
+				if last != default {
+					break;
+				}
+				items = rest;
+			}
+		}
+		let mut serialize_tuple = serializer.serialize_tuple(2)?;
+		serialize_tuple.serialize_element(&length_seeder.seeded(&items.len()))?;
+		serialize_tuple.serialize_element(&TupleNSeeded(items, items.len(), item_seeder))?;
+		serialize_tuple.end()
+	}
+}
+
+/// [`Vec<_>`] stored as exactly `n` items, the missing ones written as `fill`. More than `n` items are an error when writing.  
+/// Trailing `fill` items are removed when reading if the last parameter is `true`.  
+/// (Parameters: item count, fill item, item [`Seeder`], whether to trim trailing fill items when reading)
+#[derive(Debug, Copy, Clone, Default)]
+pub struct PaddedTuple<T, ItemSeeder>(pub usize, pub T, pub ItemSeeder, pub bool);
+impl<'de, T: PartialEq, ItemSeeder: Clone + DeSeeder<'de, T>> DeSeeder<'de, Vec<T>>
+	for PaddedTuple<T, ItemSeeder>
+{
+	type Seed = Self;
+	fn seed(self) -> Self::Seed {
+		self
+	}
+}
+impl<T, ItemSeeder: SerSeeder<T>> SerSeeder<Vec<T>> for PaddedTuple<T, ItemSeeder> {
+	fn seeded<'s>(&'s self, value: &'s Vec<T>) -> Seeded<'s> {
+		Box::new(PaddedTupleSeeded(value, self))
+	}
+}
+impl<'de, T: PartialEq, ItemSeeder: Clone + DeSeeder<'de, T>> de::DeserializeSeed<'de>
+	for PaddedTuple<T, ItemSeeder>
+{
+	type Value = Vec<T>;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let PaddedTuple(count, fill, item_seeder, trim) = self;
+		let mut items: Vec<T> =
+			TupleNSeed(count, item_seeder, PhantomData).deserialize(deserializer)?;
+		if trim {
+			while items.last() == Some(&fill) {
+				items.pop();
+			}
+		}
+		Ok(items)
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone)]
+pub struct PaddedTupleSeeded<'a, T, ItemSeeder>(&'a Vec<T>, &'a PaddedTuple<T, ItemSeeder>);
+impl<'a, T, ItemSeeder: SerSeeder<T>> ser::Serialize for PaddedTupleSeeded<'a, T, ItemSeeder> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let PaddedTuple(count, fill, item_seeder, _) = self.1;
+		if self.0.len() > *count {
+			return Err(ser::Error::custom(format_args!(
+				"Can't serialize {} items as PaddedTuple of {}",
+				self.0.len(),
+				count
+			)));
+		}
+		let mut serialize_tuple = serializer.serialize_tuple(*count)?;
+		for item in self.0.iter().chain(iter::repeat(fill)).take(*count) {
+			serialize_tuple.serialize_element(&item_seeder.seeded(item))?
+		}
+		serialize_tuple.end()
+	}
+}
+
+/// Value with an embedded check digit, validated when read and recomputed when written.  
+/// The function returns its argument with the check digit corrected, as for example [`luhn`] does.  
+/// (Parameters: inner [`Seeder`], `Fn(&T) -> T`)
+#[derive(Debug, Copy, Clone, Default)]
+pub struct CheckDigit<Inner, FixFn>(pub Inner, pub FixFn);
+impl<'de, T: PartialEq + Debug, Inner: DeSeeder<'de, T>, FixFn: Fn(&T) -> T> DeSeeder<'de, T>
+	for CheckDigit<Inner, FixFn>
+{
+	type Seed = CheckDigitSeed<T, Inner, FixFn>;
+	fn seed(self) -> Self::Seed {
+		CheckDigitSeed(self.0, self.1, PhantomData)
+	}
+}
+impl<T, Inner: SerSeeder<T>, FixFn: Fn(&T) -> T> SerSeeder<T> for CheckDigit<Inner, FixFn> {
+	fn seeded<'s>(&'s self, value: &'s T) -> Seeded<'s> {
+		Box::new(CheckDigitSeeded(value, &self.0, &self.1))
+	}
+}
+
+/// Replaces the last decimal digit of `value` with its Luhn check digit.
+pub fn luhn(value: &u64) -> u64 {
+	let payload = value / 10;
+	let mut sum = 0;
+	let mut rest = payload;
+	let mut double = true;
+	while rest > 0 {
+		let digit = rest % 10;
+		sum += match digit * (double as u64 + 1) {
+			doubled if doubled > 9 => doubled - 9,
+			digit => digit,
+		};
+		double = !double;
+		rest /= 10;
+	}
+	payload * 10 + (10 - sum % 10) % 10
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct CheckDigitSeed<T, Inner, FixFn>(Inner, FixFn, PhantomData<T>);
+impl<'de, T: PartialEq + Debug, Inner: DeSeeder<'de, T>, FixFn: Fn(&T) -> T>
+	de::DeserializeSeed<'de> for CheckDigitSeed<T, Inner, FixFn>
+{
+	type Value = T;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let value = self.0.seed().deserialize(deserializer)?;
+		let fixed = self.1(&value);
+		if fixed != value {
+			return Err(de::Error::custom(format_args!(
+				"Check digit mismatch: Read {:?} but expected {:?}",
+				value, fixed
+			)));
+		}
+		Ok(value)
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct CheckDigitSeeded<'a, T, Inner, FixFn>(&'a T, &'a Inner, &'a FixFn);
+impl<'a, T, Inner: SerSeeder<T>, FixFn: Fn(&T) -> T> ser::Serialize
+	for CheckDigitSeeded<'a, T, Inner, FixFn>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		self.2(self.0).pipe(|fixed| self.1.seeded(&fixed).serialize(serializer))
+	}
+}
+
+/// Value that must satisfy a predicate, checked after reading and, if the last parameter is `true`, also before writing.  
+/// The predicate's error message is reported through the (de)serializer's error. See [`in_range`] for a common one.  
+/// (Parameters: inner [`Seeder`], `Fn(&T) -> Result<(), String>`, whether to validate before writing)
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Validated<Inner, Predicate>(pub Inner, pub Predicate, pub bool);
+impl<'de, T, Inner: DeSeeder<'de, T>, Predicate: Fn(&T) -> Result<(), String>> DeSeeder<'de, T>
+	for Validated<Inner, Predicate>
+{
+	type Seed = ValidatedSeed<T, Inner, Predicate>;
+	fn seed(self) -> Self::Seed {
+		ValidatedSeed(self.0, self.1, PhantomData)
+	}
+}
+impl<T, Inner: SerSeeder<T>, Predicate: Fn(&T) -> Result<(), String>> SerSeeder<T>
+	for Validated<Inner, Predicate>
+{
+	fn seeded<'s>(&'s self, value: &'s T) -> Seeded<'s> {
+		Box::new(ValidatedSeeded(value, self))
+	}
+}
+
+/// A [`Validated`] predicate that accepts values within `range`.
+pub fn in_range<T: PartialOrd + Debug>(
+	range: impl RangeBounds<T> + Debug + Clone,
+) -> impl Fn(&T) -> Result<(), String> + Clone {
+	move |value| {
+		if range.contains(value) {
+			Ok(())
+		} else {
+			Err(format!("{:?} is out of range {:?}", value, range))
+		}
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ValidatedSeed<T, Inner, Predicate>(Inner, Predicate, PhantomData<T>);
+impl<'de, T, Inner: DeSeeder<'de, T>, Predicate: Fn(&T) -> Result<(), String>>
+	de::DeserializeSeed<'de> for ValidatedSeed<T, Inner, Predicate>
+{
+	type Value = T;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let value = self.0.seed().deserialize(deserializer)?;
+		self.1(&value).map_err(de::Error::custom)?;
+		Ok(value)
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone)]
+pub struct ValidatedSeeded<'a, T, Inner, Predicate>(&'a T, &'a Validated<Inner, Predicate>);
+impl<'a, T, Inner: SerSeeder<T>, Predicate: Fn(&T) -> Result<(), String>> ser::Serialize
+	for ValidatedSeeded<'a, T, Inner, Predicate>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let Validated(inner, predicate, validate_on_write) = self.1;
+		if *validate_on_write {
+			predicate(self.0).map_err(ser::Error::custom)?;
+		}
+		inner.seeded(self.0).serialize(serializer)
+	}
+}
+
+/// [`Vec<_>`] of `ELEM`-byte items as tuple, prefixed with its size in bytes rather than its length.  
+/// (Usage: [`ByteCountFixed::<ELEM, _, _>(length_seeder, item_seeder)`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ByteCountFixed<const ELEM: usize, LengthSeeder, ItemSeeder>(
+	pub LengthSeeder,
+	pub ItemSeeder,
+);
+impl<
+		'de,
+		const ELEM: usize,
+		T,
+		LengthSeeder: DeSeeder<'de, usize>,
+		ItemSeeder: Clone + DeSeeder<'de, T>,
+	> DeSeeder<'de, Vec<T>> for ByteCountFixed<ELEM, LengthSeeder, ItemSeeder>
+{
+	type Seed = ByteCountFixedSeed<ELEM, T, LengthSeeder, ItemSeeder>;
+	fn seed(self) -> Self::Seed {
+		ByteCountFixedSeed(self.0, self.1, PhantomData)
+	}
+}
+impl<const ELEM: usize, T, LengthSeeder: SerSeeder<usize>, ItemSeeder: SerSeeder<T>>
+	SerSeeder<Vec<T>> for ByteCountFixed<ELEM, LengthSeeder, ItemSeeder>
+{
+	fn seeded<'s>(&'s self, value: &'s Vec<T>) -> Seeded<'s> {
+		Box::new(ByteCountFixedSeeded::<ELEM, _, _, _>(
+			value, &self.0, &self.1,
+		))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ByteCountFixedSeed<const ELEM: usize, T, LengthSeeder, ItemSeeder>(
+	LengthSeeder,
+	ItemSeeder,
+	PhantomData<T>,
+);
+impl<
+		'de,
+		const ELEM: usize,
+		T,
+		LengthSeeder: DeSeeder<'de, usize>,
+		ItemSeeder: Clone + DeSeeder<'de, T>,
+	> de::DeserializeSeed<'de> for ByteCountFixedSeed<ELEM, T, LengthSeeder, ItemSeeder>
+{
+	type Value = Vec<T>;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<const ELEM: usize, T, LengthSeeder, ItemSeeder>(
+			LengthSeeder,
+			ItemSeeder,
+			PhantomData<T>,
+		);
+		impl<
+				'de,
+				const ELEM: usize,
+				T,
+				LengthSeeder: DeSeeder<'de, usize>,
+				ItemSeeder: Clone + DeSeeder<'de, T>,
+			> de::Visitor<'de> for Visitor<ELEM, T, LengthSeeder, ItemSeeder>
+		{
+			type Value = Vec<T>;
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "byte size and {}-byte items", ELEM)
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let Visitor(length_seeder, item_seeder, _) = self;
+				let size = seq
+					.next_element_seed(length_seeder.seed())?
+					.ok_or_else(|| de::Error::invalid_length(0, &"byte size and items"))?;
+				seq.next_element_seed(CountFromSizeSeed::<ELEM, _, _>(
+					size,
+					item_seeder,
+					PhantomData,
+				))?
+				.ok_or_else(|| de::Error::invalid_length(1, &"byte size and items"))
+			}
+		}
+
+		deserializer.deserialize_tuple(2, Visitor::<ELEM, _, _, _>(self.0, self.1, PhantomData))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct ByteCountFixedSeeded<'a, const ELEM: usize, T, LengthSeeder, ItemSeeder>(
+	&'a Vec<T>,
+	&'a LengthSeeder,
+	&'a ItemSeeder,
+);
+impl<'a, const ELEM: usize, T, LengthSeeder: SerSeeder<usize>, ItemSeeder: SerSeeder<T>>
+	ser::Serialize for ByteCountFixedSeeded<'a, ELEM, T, LengthSeeder, ItemSeeder>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let size = self
+			.0
+			.len()
+			.checked_mul(ItemSize::<ELEM>::ELEM)
+			.ok_or_else(|| {
+				ser::Error::custom(format_args!(
+					"Byte size of {} {}-byte items overflows",
+					self.0.len(),
+					ELEM
+				))
+			})?;
+		let mut serialize_tuple = serializer.serialize_tuple(2)?;
+		serialize_tuple.serialize_element(&self.1.seeded(&size))?;
+		serialize_tuple.serialize_element(&TupleNSeeded(self.0, self.0.len(), self.2))?;
+		serialize_tuple.end()
+	}
+}
+
+/// Seq whose items are passed to a callback as they're read, instead of being collected.  
+/// This is read-only.  
+/// (Usage: [`ForEach::new(item_seeder, callback: FnMut(T))`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ForEach<ItemSeeder, F, T>(ItemSeeder, F, PhantomData<T>);
+impl<ItemSeeder, F, T> ForEach<ItemSeeder, F, T> {
+	pub fn new(item_seeder: ItemSeeder, callback: F) -> Self {
+		Self(item_seeder, callback, PhantomData)
+	}
+}
+impl<'de, T, ItemSeeder: Clone + DeSeeder<'de, T>, F: FnMut(T)> DeSeeder<'de, ()>
+	for ForEach<ItemSeeder, F, T>
+{
+	type Seed = Self;
+	fn seed(self) -> Self::Seed {
+		self
+	}
+}
+impl<'de, T, ItemSeeder: Clone + DeSeeder<'de, T>, F: FnMut(T)> de::DeserializeSeed<'de>
+	for ForEach<ItemSeeder, F, T>
+{
+	type Value = ();
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<T, ItemSeeder, F>(ItemSeeder, F, PhantomData<T>);
+		impl<'de, T, ItemSeeder: Clone + DeSeeder<'de, T>, F: FnMut(T)> de::Visitor<'de>
+			for Visitor<T, ItemSeeder, F>
+		{
+			type Value = ();
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "ForEach")
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let Visitor(item_seeder, mut callback, _) = self;
+				let mut count = 0_usize;
+				while let Some(item) = seq.next_element_seed(item_seeder.clone().seed())? {
+					callback(item);
+					count += 1;
+				}
+				trace!("ForEach visited {} items.", count);
+				Ok(())
+			}
+		}
+
+		deserializer.deserialize_seq(Visitor(self.0, self.1, PhantomData))
+	}
+}
+
+/// `[f32; N]` or `[f64; N]` stored as little-endian IEEE 754, converted in bulk from one (possibly unaligned) byte blob.  
+/// This is equivalent to a [`Tuple`] of [`IEEE754`]`(LittleEndian)` but faster. Requires a [`raw::Deserializer`] to read.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct FloatArrayLe<const N: usize>;
+impl<'de, const N: usize, F: FloatArrayLeable> DeSeeder<'de, [F; N]> for FloatArrayLe<N> {
+	type Seed = FloatArrayLeSeed<N, F>;
+	fn seed(self) -> Self::Seed {
+		FloatArrayLeSeed(PhantomData)
+	}
+}
+impl<const N: usize, F: FloatArrayLeable> SerSeeder<[F; N]> for FloatArrayLe<N> {
+	fn seeded<'s>(&'s self, value: &'s [F; N]) -> Seeded<'s> {
+		Box::new(FloatArrayLeSeeded(value))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct FloatArrayLeSeed<const N: usize, F>(PhantomData<F>);
+impl<'de, const N: usize, F: FloatArrayLeable> de::DeserializeSeed<'de> for FloatArrayLeSeed<N, F> {
+	type Value = [F; N];
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<const N: usize, F>(PhantomData<F>);
+		impl<'de, const N: usize, F: FloatArrayLeable> de::Visitor<'de> for Visitor<N, F> {
+			type Value = [F; N];
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "{} bytes of little-endian floats", N * F::SIZE)
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let size = N * F::SIZE;
+				let remaining = raw::next_remaining(&mut seq)?;
+				let blob = remaining
+					.get(..size)
+					.ok_or_else(|| de::Error::invalid_length(remaining.len(), &self))?;
+				let mut floats = [F::default(); N];
+				for (float, chunk) in floats.iter_mut().zip(blob.chunks_exact(F::SIZE)) {
+					*float = F::Bytes::try_from(chunk)
+						.map_err(|_| de::Error::invalid_length(chunk.len(), &self))?
+						.pipe(F::from_le_bytes);
+				}
+				seq.next_element_seed(raw::SkipSeed(size))?;
+				Ok(floats)
+			}
+		}
+
+		deserializer.deserialize_tuple(2, Visitor::<N, F>(PhantomData))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FloatArrayLeSeeded<'a, const N: usize, F>(&'a [F; N]);
+impl<'a, const N: usize, F: FloatArrayLeable> ser::Serialize for FloatArrayLeSeeded<'a, N, F> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let mut blob = Vec::with_capacity(N * F::SIZE);
+		for float in self.0 {
+			blob.extend_from_slice(float.to_le_bytes().as_ref());
+		}
+		serializer.serialize_bytes(&blob)
+	}
+}
+
+/// See [`FloatArrayLe`].
+pub trait FloatArrayLeable: Copy + Default {
+	const SIZE: usize;
+	/// `[u8; Self::SIZE]`.
+	type Bytes: AsRef<[u8]> + for<'a> TryFrom<&'a [u8]>;
+	fn from_le_bytes(bytes: Self::Bytes) -> Self;
+	fn to_le_bytes(self) -> Self::Bytes;
+}
+macro_rules! impl_float_array_leable {
+	($($ty:ty),*$(,)?) => {$(
+		impl FloatArrayLeable for $ty {
+			const SIZE: usize = mem::size_of::<$ty>();
+			type Bytes = [u8; mem::size_of::<$ty>()];
+			fn from_le_bytes(bytes: Self::Bytes) -> Self {
+				<$ty>::from_le_bytes(bytes)
+			}
+			fn to_le_bytes(self) -> Self::Bytes {
+				<$ty>::to_le_bytes(self)
+			}
+		}
+	)*};
+}
+impl_float_array_leable!(f32, f64);
+
+/// [`Vec<_>`] whose items' tags must all be among the allowed ones, checked in both directions.  
+/// (Parameters: [`Vec<_>`] [`Seeder`], `Fn(&Item) -> u32`, allowed tags)
+#[derive(Debug, Copy, Clone)]
+pub struct RestrictTags<'a, ItemsSeeder, TagFn>(pub ItemsSeeder, pub TagFn, pub &'a [u32]);
+impl<'a, 'de, Item, ItemsSeeder: DeSeeder<'de, Vec<Item>>, TagFn: Fn(&Item) -> u32>
+	DeSeeder<'de, Vec<Item>> for RestrictTags<'a, ItemsSeeder, TagFn>
+{
+	type Seed = RestrictTagsSeed<'a, ItemsSeeder, TagFn, Item>;
+	fn seed(self) -> Self::Seed {
+		RestrictTagsSeed(self.0, self.1, self.2, PhantomData)
+	}
+}
+impl<'a, Item, ItemsSeeder: SerSeeder<Vec<Item>>, TagFn: Fn(&Item) -> u32> SerSeeder<Vec<Item>>
+	for RestrictTags<'a, ItemsSeeder, TagFn>
+{
+	fn seeded<'s>(&'s self, value: &'s Vec<Item>) -> Seeded<'s> {
+		Box::new(RestrictTagsSeeded(value, self))
+	}
+}
+
+fn find_disallowed_tag<Item>(
+	items: &[Item],
+	tag: impl Fn(&Item) -> u32,
+	allowed: &[u32],
+) -> Option<(usize, u32)> {
+	items
+		.iter()
+		.map(tag)
+		.enumerate()
+		.find(|(_, tag)| !allowed.contains(tag))
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone)]
+pub struct RestrictTagsSeed<'a, ItemsSeeder, TagFn, Item>(
+	ItemsSeeder,
+	TagFn,
+	&'a [u32],
+	PhantomData<Item>,
+);
+impl<'a, 'de, Item, ItemsSeeder: DeSeeder<'de, Vec<Item>>, TagFn: Fn(&Item) -> u32>
+	de::DeserializeSeed<'de> for RestrictTagsSeed<'a, ItemsSeeder, TagFn, Item>
+{
+	type Value = Vec<Item>;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let items = self.0.seed().deserialize(deserializer)?;
+		if let Some((i, tag)) = find_disallowed_tag(&items, &self.1, self.2) {
+			return Err(de::Error::custom(format_args!(
+				"Tag {} at index {} is not one of {:?}",
+				tag, i, self.2
+			)));
+		}
+		Ok(items)
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone)]
+pub struct RestrictTagsSeeded<'a, 'b, Item, ItemsSeeder, TagFn>(
+	&'a Vec<Item>,
+	&'a RestrictTags<'b, ItemsSeeder, TagFn>,
+);
+impl<'a, 'b, Item, ItemsSeeder: SerSeeder<Vec<Item>>, TagFn: Fn(&Item) -> u32> ser::Serialize
+	for RestrictTagsSeeded<'a, 'b, Item, ItemsSeeder, TagFn>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let RestrictTags(items_seeder, tag_fn, allowed) = self.1;
+		if let Some((i, tag)) = find_disallowed_tag(self.0, tag_fn, allowed) {
+			return Err(ser::Error::custom(format_args!(
+				"Can't serialize tag {} at index {}, which is not one of {:?}",
+				tag, i, allowed
+			)));
+		}
+		items_seeder.seeded(self.0).serialize(serializer)
+	}
+}
+
+/// Value stored zlib-compressed, prefixed with its uncompressed and compressed sizes.  
+/// The value is parsed from and written as [`raw`] format, and the decompressed size must match the declared one.  
+/// (Parameters: size [`Seeder`], inner [`Seeder`])
+#[cfg(feature = "flate2")]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Deflated<SizeSeeder, Inner>(pub SizeSeeder, pub Inner);
+#[cfg(feature = "flate2")]
+impl<'de, T, SizeSeeder: Clone + DeSeeder<'de, usize>, Inner: for<'a> DeSeeder<'a, T>>
+	DeSeeder<'de, T> for Deflated<SizeSeeder, Inner>
+{
+	type Seed = DeflatedSeed<T, SizeSeeder, Inner>;
+	fn seed(self) -> Self::Seed {
+		DeflatedSeed(self.0, self.1, PhantomData)
+	}
+}
+#[cfg(feature = "flate2")]
+impl<T, SizeSeeder: SerSeeder<usize>, Inner: SerSeeder<T>> SerSeeder<T>
+	for Deflated<SizeSeeder, Inner>
+{
+	fn seeded<'s>(&'s self, value: &'s T) -> Seeded<'s> {
+		Box::new(DeflatedSeeded(value, &self.0, &self.1))
+	}
+}
+
+#[cfg(feature = "flate2")]
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct DeflatedSeed<T, SizeSeeder, Inner>(SizeSeeder, Inner, PhantomData<T>);
+#[cfg(feature = "flate2")]
+impl<'de, T, SizeSeeder: Clone + DeSeeder<'de, usize>, Inner: for<'a> DeSeeder<'a, T>>
+	de::DeserializeSeed<'de> for DeflatedSeed<T, SizeSeeder, Inner>
+{
+	type Value = T;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<T, SizeSeeder, Inner>(SizeSeeder, Inner, PhantomData<T>);
+		impl<'de, T, SizeSeeder: Clone + DeSeeder<'de, usize>, Inner: for<'a> DeSeeder<'a, T>>
+			de::Visitor<'de> for Visitor<T, SizeSeeder, Inner>
+		{
+			type Value = T;
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "sizes and zlib-compressed data")
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				use std::io::Read as _;
+
+				let Visitor(size_seeder, inner, _) = self;
+				let uncompressed_size = seq
+					.next_element_seed(size_seeder.clone().seed())?
+					.ok_or_else(|| de::Error::invalid_length(0, &"sizes and compressed data"))?;
+				let compressed_size = seq
+					.next_element_seed(size_seeder.seed())?
+					.ok_or_else(|| de::Error::invalid_length(1, &"sizes and compressed data"))?;
+				let compressed: Vec<u8> = seq
+					.next_element_seed(TupleNSeed(compressed_size, SerdeLike, PhantomData))?
+					.ok_or_else(|| de::Error::invalid_length(2, &"sizes and compressed data"))?;
+
+				// Read one byte more than declared to detect oversized data without inflating all of it.
+				let mut decompressed =
+					Vec::with_capacity(cautious_capacity::<u8>(Some(uncompressed_size)));
+				flate2::read::ZlibDecoder::new(compressed.as_slice())
+					.take((uncompressed_size as u64).saturating_add(1))
+					.read_to_end(&mut decompressed)
+					.map_err(de::Error::custom)?;
+				if decompressed.len() != uncompressed_size {
+					return Err(de::Error::custom(format_args!(
+						"Decompressed {}{} bytes, but {} were declared",
+						decompressed.len(),
+						if decompressed.len() > uncompressed_size {
+							"+"
+						} else {
+							""
+						},
+						uncompressed_size
+					)));
+				}
+				trace!(
+					"Inflated {} bytes to {}.",
+					compressed_size,
+					uncompressed_size
+				);
+
+				let mut decompressed = raw::Deserializer::new(&decompressed);
+				inner
+					.seed()
+					.deserialize(&mut decompressed)
+					.and_then(|value| decompressed.end().map(|()| value))
+					.map_err(|error| de::Error::custom(format_args!("{} (in Deflated)", error)))
+			}
+		}
+
+		deserializer.deserialize_tuple(3, Visitor(self.0, self.1, PhantomData))
+	}
+}
+
+#[cfg(feature = "flate2")]
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct DeflatedSeeded<'a, T, SizeSeeder, Inner>(&'a T, &'a SizeSeeder, &'a Inner);
+#[cfg(feature = "flate2")]
+impl<'a, T, SizeSeeder: SerSeeder<usize>, Inner: SerSeeder<T>> ser::Serialize
+	for DeflatedSeeded<'a, T, SizeSeeder, Inner>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use std::io::Write as _;
+
+		let mut uncompressed = raw::Serializer::new();
+		self.2
+			.seeded(self.0)
+			.serialize(&mut uncompressed)
+			.map_err(ser::Error::custom)?;
+		let uncompressed = uncompressed.into_inner();
+		let mut encoder =
+			flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+		let compressed = encoder
+			.write_all(&uncompressed)
+			.and_then(|()| encoder.finish())
+			.map_err(ser::Error::custom)?;
+
+		let mut serialize_tuple = serializer.serialize_tuple(3)?;
+		serialize_tuple.serialize_element(&self.1.seeded(&uncompressed.len()))?;
+		serialize_tuple.serialize_element(&self.1.seeded(&compressed.len()))?;
+		serialize_tuple.serialize_element(&Literal(&compressed))?;
+		serialize_tuple.end()
+	}
+}
+
+/// LEB128 variable-length integer, 7 bits per byte with the least significant group first.  
+/// Values that don't fit the target type are rejected, as are overlong encodings beyond 128 bits.  
+/// (Usage: [`Leb128::unsigned()`], [`Leb128::signed()`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Leb128 {
+	signed: bool,
+}
+impl Leb128 {
+	pub fn unsigned() -> Self {
+		Self { signed: false }
+	}
+
+	pub fn signed() -> Self {
+		Self { signed: true }
+	}
+}
+impl<'de, T: Leb128able> DeSeeder<'de, T> for Leb128 {
+	type Seed = Leb128Seed<T>;
+	fn seed(self) -> Self::Seed {
+		Leb128Seed(self, PhantomData)
+	}
+}
+impl<T: Leb128able> SerSeeder<T> for Leb128 {
+	fn seeded<'s>(&'s self, value: &'s T) -> Seeded<'s> {
+		Box::new(Leb128Seeded(value, *self))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Leb128Seed<T>(Leb128, PhantomData<T>);
+impl<'de, T: Leb128able> de::DeserializeSeed<'de> for Leb128Seed<T> {
+	type Value = T;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<T>(Leb128, PhantomData<T>);
+		impl<'de, T: Leb128able> de::Visitor<'de> for Visitor<T> {
+			type Value = T;
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(
+					f,
+					"{} LEB128 fitting {}",
+					if self.0.signed { "signed" } else { "unsigned" },
+					core::any::type_name::<T>()
+				)
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let mut bits = 0_u128;
+				let mut shift = 0_u32;
+				let byte = loop {
+					let byte: u8 = seq
+						.next_element()?
+						.ok_or_else(|| de::Error::invalid_length((shift / 7) as usize, &self))?;
+					let group = (byte & 0x7F) as u128;
+					let fits = match 128_u32.checked_sub(shift) {
+						None | Some(0) => false,
+						Some(room) if room >= 7 => true,
+						// Signed, the excess bits must all repeat the sign bit.
+						Some(room) if self.0.signed => {
+							let top = group >> (room - 1);
+							top == 0 || top == 0x7F >> (room - 1)
+						}
+						Some(room) => group >> room == 0,
+					};
+					if !fits {
+						return Err(de::Error::custom(format_args!(
+							"LEB128 overflows 128 bits after {} bytes",
+							shift / 7 + 1
+						)));
+					}
+					bits |= group << shift;
+					shift += 7;
+					if byte & 0x80 == 0 {
+						break byte;
+					}
+				};
+				let value = if self.0.signed {
+					// Sign-extend from the last group's top bit.
+					let value = if shift < 128 && byte & 0x40 != 0 {
+						(bits | !0 << shift) as i128
+					} else {
+						bits as i128
+					};
+					T::from_i128(value).ok_or_else(|| {
+						de::Error::invalid_value(
+							de::Unexpected::Other("out-of-range LEB128"),
+							&self,
+						)
+					})
+				} else {
+					T::from_u128(bits).ok_or_else(|| {
+						de::Error::invalid_value(
+							de::Unexpected::Other("out-of-range LEB128"),
+							&self,
+						)
+					})
+				}?;
+				Ok(value)
+			}
+		}
+
+		deserializer.deserialize_seq(Visitor(self.0, PhantomData))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone)]
+pub struct Leb128Seeded<'a, T>(&'a T, Leb128);
+impl<'a, T: Leb128able> ser::Serialize for Leb128Seeded<'a, T> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let mut bytes = Vec::new();
+		if self.1.signed {
+			let mut value = self.0.to_i128().ok_or_else(|| {
+				ser::Error::custom("Can't serialize value out of i128 range as signed LEB128")
+			})?;
+			loop {
+				let group = (value & 0x7F) as u8;
+				value >>= 7;
+				if (value == 0 && group & 0x40 == 0) || (value == -1 && group & 0x40 != 0) {
+					bytes.push(group);
+					break;
+				}
+				bytes.push(group | 0x80);
+			}
+		} else {
+			let mut value = self.0.to_u128().ok_or_else(|| {
+				ser::Error::custom("Can't serialize negative value as unsigned LEB128")
+			})?;
+			loop {
+				let group = (value & 0x7F) as u8;
+				value >>= 7;
+				if value == 0 {
+					bytes.push(group);
+					break;
+				}
+				bytes.push(group | 0x80);
+			}
+		}
+		serializer.serialize_bytes(&bytes)
+	}
+}
+
+/// See [`Leb128`].
+pub trait Leb128able: Sized {
+	fn from_u128(value: u128) -> Option<Self>;
+	fn from_i128(value: i128) -> Option<Self>;
+	fn to_u128(&self) -> Option<u128>;
+	fn to_i128(&self) -> Option<i128>;
+}
+macro_rules! impl_leb128able {
+	($($ty:ty),*$(,)?) => {$(
+		impl Leb128able for $ty {
+			fn from_u128(value: u128) -> Option<Self> {
+				value.try_into().ok()
+			}
+			fn from_i128(value: i128) -> Option<Self> {
+				value.try_into().ok()
+			}
+			fn to_u128(&self) -> Option<u128> {
+				(*self).try_into().ok()
+			}
+			fn to_i128(&self) -> Option<i128> {
+				(*self).try_into().ok()
+			}
+		}
+	)*};
+}
+impl_leb128able!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize);
+
+/// Bytes terminated by (and not containing) `0x00`, as C strings are stored.  
+/// Combine it with an encoding like [`Windows1252`] to read text.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct NulTerminated;
+impl<'de> DeSeeder<'de, Vec<u8>> for NulTerminated {
+	type Seed = Self;
+	fn seed(self) -> Self::Seed {
+		self
+	}
+}
+impl SerSeeder<Vec<u8>> for NulTerminated {
+	fn seeded<'s>(&'s self, value: &'s Vec<u8>) -> Seeded<'s> {
+		Box::new(NulTerminatedSeeded(value))
+	}
+}
+impl<'de> de::DeserializeSeed<'de> for NulTerminated {
+	type Value = Vec<u8>;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor;
+		impl<'de> de::Visitor<'de> for Visitor {
+			type Value = Vec<u8>;
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "NUL-terminated bytes")
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let mut bytes = Vec::new();
+				loop {
+					match seq.next_element::<u8>()? {
+						Some(0) => return Ok(bytes),
+						Some(byte) => bytes.push(byte),
+						None => return Err(de::Error::custom("Missing NUL terminator")),
+					}
+				}
+			}
+		}
+
+		deserializer.deserialize_seq(Visitor)
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct NulTerminatedSeeded<'a>(&'a Vec<u8>);
+impl<'a> ser::Serialize for NulTerminatedSeeded<'a> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		if let Some(i) = self.0.iter().position(|byte| *byte == 0) {
+			return Err(ser::Error::custom(format_args!(
+				"Can't serialize interior NUL at index {} as NulTerminated",
+				i
+			)));
+		}
+		let mut serialize_tuple = serializer.serialize_tuple(2)?;
+		serialize_tuple.serialize_element(&Literal(self.0))?;
+		serialize_tuple.serialize_element(&0_u8)?;
+		serialize_tuple.end()
+	}
+}
+
+/// Bytes in a fixed-size field, right-padded with a padding byte. Trailing padding is trimmed when read,
+/// so values that end with the padding byte are rejected when written.  
+/// Combine it with an encoding like [`Windows1252`] to read text.  
+/// (Parameters: field size, padding byte)
+#[derive(Debug, Copy, Clone, Default)]
+pub struct FixedString(pub usize, pub u8);
+impl<'de> DeSeeder<'de, Vec<u8>> for FixedString {
+	type Seed = Self;
+	fn seed(self) -> Self::Seed {
+		self
+	}
+}
+impl SerSeeder<Vec<u8>> for FixedString {
+	fn seeded<'s>(&'s self, value: &'s Vec<u8>) -> Seeded<'s> {
+		Box::new(FixedStringSeeded(value, *self))
+	}
+}
+impl<'de> de::DeserializeSeed<'de> for FixedString {
+	type Value = Vec<u8>;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let mut bytes: Vec<u8> =
+			TupleNSeed(self.0, SerdeLike, PhantomData).deserialize(deserializer)?;
+		let len = bytes
+			.iter()
+			.rposition(|byte| *byte != self.1)
+			.map_or(0, |last| last + 1);
+		bytes.truncate(len);
+		Ok(bytes)
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone)]
+pub struct FixedStringSeeded<'a>(&'a Vec<u8>, FixedString);
+impl<'a> ser::Serialize for FixedStringSeeded<'a> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let FixedString(size, padding) = self.1;
+		if self.0.len() > size {
+			return Err(ser::Error::custom(format_args!(
+				"Can't fit {} bytes into FixedString of {}",
+				self.0.len(),
+				size
+			)));
+		}
+		if self.0.last() == Some(&padding) {
+			return Err(ser::Error::custom(format_args!(
+				"Can't serialise {:?} as FixedString padded with {:#04X}, since it ends with the padding byte",
+				self.0, padding
+			)));
+		}
+		let mut serialize_tuple = serializer.serialize_tuple(size)?;
+		for byte in self
+			.0
+			.iter()
+			.chain(iter::repeat(&padding).take(size - self.0.len()))
+		{
+			serialize_tuple.serialize_element(byte)?;
+		}
+		serialize_tuple.end()
+	}
+}
+
+/// [`bool`] stored as an integer: zero is `false` and anything else is `true`, unless strict, in which case only 1 is `true`.  
+/// Serializes as 1 or 0.  
+/// (Usage: [`Bool::lenient(int_seeder)`], [`Bool::strict(int_seeder)`], e.g. `Bool::<_, u32>::lenient(LittleEndian)`)
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Bool<IntSeeder, Int>(IntSeeder, bool, PhantomData<Int>);
+impl<IntSeeder, Int> Bool<IntSeeder, Int> {
+	pub fn lenient(int_seeder: IntSeeder) -> Self {
+		Self(int_seeder, false, PhantomData)
+	}
+
+	pub fn strict(int_seeder: IntSeeder) -> Self {
+		Self(int_seeder, true, PhantomData)
+	}
+}
+impl<'de, Int: Boolable, IntSeeder: DeSeeder<'de, Int>> DeSeeder<'de, bool>
+	for Bool<IntSeeder, Int>
+{
+	type Seed = BoolSeed<IntSeeder, Int>;
+	fn seed(self) -> Self::Seed {
+		BoolSeed(self.0, self.1, PhantomData)
+	}
+}
+impl<Int: Boolable, IntSeeder: SerSeeder<Int>> SerSeeder<bool> for Bool<IntSeeder, Int> {
+	fn seeded<'s>(&'s self, value: &'s bool) -> Seeded<'s> {
+		Box::new(BoolSeeded(value, &self.0, self.2))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct BoolSeed<IntSeeder, Int>(IntSeeder, bool, PhantomData<Int>);
+impl<'de, Int: Boolable, IntSeeder: DeSeeder<'de, Int>> de::DeserializeSeed<'de>
+	for BoolSeed<IntSeeder, Int>
+{
+	type Value = bool;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let repr = self.0.seed().deserialize(deserializer)?;
+		if repr == Int::FALSE {
+			Ok(false)
+		} else if repr == Int::TRUE || !self.1 {
+			Ok(true)
+		} else {
+			Err(de::Error::custom(format_args!(
+				"Expected 0 or 1 for strict Bool, found {:?}",
+				repr
+			)))
+		}
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone)]
+pub struct BoolSeeded<'a, IntSeeder, Int>(&'a bool, &'a IntSeeder, PhantomData<Int>);
+impl<'a, Int: Boolable, IntSeeder: SerSeeder<Int>> ser::Serialize
+	for BoolSeeded<'a, IntSeeder, Int>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let repr = if *self.0 { Int::TRUE } else { Int::FALSE };
+		repr.pipe(|repr| self.1.seeded(&repr).serialize(serializer))
+	}
+}
+
+/// See [`Bool`].
+pub trait Boolable: Copy + Debug + PartialEq {
+	const FALSE: Self;
+	const TRUE: Self;
+}
+macro_rules! impl_boolable {
+	($($ty:ty),*$(,)?) => {$(
+		impl Boolable for $ty {
+			const FALSE: Self = 0;
+			const TRUE: Self = 1;
+		}
+	)*};
+}
+impl_boolable!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128);
+
+/// [`Option`] preceded by a presence flag. The inner value is only stored if the flag is `true`.  
+/// (Parameters: [`bool`] [`Seeder`] (e.g. [`Bool`]), inner [`Seeder`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Optional<FlagSeeder, Inner>(pub FlagSeeder, pub Inner);
+impl<'de, T, FlagSeeder: DeSeeder<'de, bool>, Inner: DeSeeder<'de, T>> DeSeeder<'de, Option<T>>
+	for Optional<FlagSeeder, Inner>
+{
+	type Seed = OptionalSeed<T, FlagSeeder, Inner>;
+	fn seed(self) -> Self::Seed {
+		OptionalSeed(self.0, self.1, PhantomData)
+	}
+}
+impl<T, FlagSeeder: SerSeeder<bool>, Inner: SerSeeder<T>> SerSeeder<Option<T>>
+	for Optional<FlagSeeder, Inner>
+{
+	fn seeded<'s>(&'s self, value: &'s Option<T>) -> Seeded<'s> {
+		Box::new(OptionalSeeded(value, &self.0, &self.1))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct OptionalSeed<T, FlagSeeder, Inner>(FlagSeeder, Inner, PhantomData<T>);
+impl<'de, T, FlagSeeder: DeSeeder<'de, bool>, Inner: DeSeeder<'de, T>> de::DeserializeSeed<'de>
+	for OptionalSeed<T, FlagSeeder, Inner>
+{
+	type Value = Option<T>;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<T, FlagSeeder, Inner>(FlagSeeder, Inner, PhantomData<T>);
+		impl<'de, T, FlagSeeder: DeSeeder<'de, bool>, Inner: DeSeeder<'de, T>> de::Visitor<'de>
+			for Visitor<T, FlagSeeder, Inner>
+		{
+			type Value = Option<T>;
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "Optional")
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let Visitor(flag_seeder, inner, _) = self;
+				let present = seq
+					.next_element_seed(flag_seeder.seed())?
+					.ok_or_else(|| de::Error::invalid_length(0, &"Optional flag"))?;
+				if !present {
+					return Ok(None);
+				}
+				seq.next_element_seed(inner.seed())?
+					.ok_or_else(|| de::Error::invalid_length(1, &"Optional value"))
+					.map(Some)
+			}
+		}
+
+		deserializer.deserialize_tuple(2, Visitor(self.0, self.1, PhantomData))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct OptionalSeeded<'a, T, FlagSeeder, Inner>(&'a Option<T>, &'a FlagSeeder, &'a Inner);
+impl<'a, T, FlagSeeder: SerSeeder<bool>, Inner: SerSeeder<T>> ser::Serialize
+	for OptionalSeeded<'a, T, FlagSeeder, Inner>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let mut serialize_tuple = serializer.serialize_tuple(1 + self.0.is_some() as usize)?;
+		serialize_tuple.serialize_element(&self.1.seeded(&self.0.is_some()))?;
+		if let Some(value) = self.0 {
+			serialize_tuple.serialize_element(&self.2.seeded(value))?;
+		}
+		serialize_tuple.end()
+	}
+}
+
+/// [`Option`] that's present exactly if `condition` is `true`, typically a previously read field. Nothing is stored otherwise.  
+/// Writing fails if the value's presence doesn't match `condition`.  
+/// (Parameters: condition, inner [`Seeder`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct If<Inner>(pub bool, pub Inner);
+impl<'de, T, Inner: DeSeeder<'de, T>> DeSeeder<'de, Option<T>> for If<Inner> {
+	type Seed = IfSeed<T, Inner>;
+	fn seed(self) -> Self::Seed {
+		IfSeed(self.0, self.1, PhantomData)
+	}
+}
+impl<T, Inner: SerSeeder<T>> SerSeeder<Option<T>> for If<Inner> {
+	fn seeded<'s>(&'s self, value: &'s Option<T>) -> Seeded<'s> {
+		Box::new(IfSeeded(value, self.0, &self.1))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct IfSeed<T, Inner>(bool, Inner, PhantomData<T>);
+impl<'de, T, Inner: DeSeeder<'de, T>> de::DeserializeSeed<'de> for IfSeed<T, Inner> {
+	type Value = Option<T>;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<T, Inner>(bool, Inner, PhantomData<T>);
+		impl<'de, T, Inner: DeSeeder<'de, T>> de::Visitor<'de> for Visitor<T, Inner> {
+			type Value = Option<T>;
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "If({}, _)", self.0)
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let Visitor(condition, inner, _) = self;
+				if !condition {
+					return Ok(None);
+				}
+				seq.next_element_seed(inner.seed())?
+					.ok_or_else(|| de::Error::invalid_length(0, &"If value"))
+					.map(Some)
+			}
+		}
+
+		deserializer.deserialize_tuple(self.0 as usize, Visitor(self.0, self.1, PhantomData))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct IfSeeded<'a, T, Inner>(&'a Option<T>, bool, &'a Inner);
+impl<'a, T, Inner: SerSeeder<T>> ser::Serialize for IfSeeded<'a, T, Inner> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		if self.0.is_some() != self.1 {
+			return Err(ser::Error::custom(format_args!(
+				"Tried to serialise If({}, _) from {}",
+				self.1,
+				if self.0.is_some() { "Some(_)" } else { "None" }
+			)));
+		}
+		let mut serialize_tuple = serializer.serialize_tuple(self.1 as usize)?;
+		if let Some(value) = self.0 {
+			serialize_tuple.serialize_element(&self.2.seeded(value))?;
+		}
+		serialize_tuple.end()
+	}
+}
+
+/// Value followed by a checksum of its bytes, which is verified when reading.  
+/// Requires a [`raw::Deserializer`] to read, and the value is written as [`raw`] format.  
+/// (Usage: [`Checksummed::new(inner_seeder, checksum_seeder)`], Parameters: inner [`Seeder`], checksum [`Seeder`], [`Checksum`] algorithm)
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Checksummed<Inner, ChecksumSeeder, Algorithm>(
+	pub Inner,
+	pub ChecksumSeeder,
+	pub PhantomData<Algorithm>,
+);
+impl<Inner, ChecksumSeeder, Algorithm> Checksummed<Inner, ChecksumSeeder, Algorithm> {
+	pub fn new(inner_seeder: Inner, checksum_seeder: ChecksumSeeder) -> Self {
+		Self(inner_seeder, checksum_seeder, PhantomData)
+	}
+}
+impl<
+		'de,
+		T,
+		Inner: DeSeeder<'de, T>,
+		ChecksumSeeder: DeSeeder<'de, Algorithm::Digest>,
+		Algorithm: Checksum,
+	> DeSeeder<'de, T> for Checksummed<Inner, ChecksumSeeder, Algorithm>
+{
+	type Seed = ChecksummedSeed<T, Inner, ChecksumSeeder, Algorithm>;
+	fn seed(self) -> Self::Seed {
+		ChecksummedSeed(self.0, self.1, PhantomData)
+	}
+}
+impl<T, Inner: SerSeeder<T>, ChecksumSeeder: SerSeeder<Algorithm::Digest>, Algorithm: Checksum>
+	SerSeeder<T> for Checksummed<Inner, ChecksumSeeder, Algorithm>
+{
+	fn seeded<'s>(&'s self, value: &'s T) -> Seeded<'s> {
+		Box::new(ChecksummedSeeded(
+			value,
+			&self.0,
+			&self.1,
+			PhantomData::<Algorithm>,
+		))
+	}
+}
+
+/// Value followed by the CRC-32 (as in zlib, PNG and zip) of its bytes, which is verified when reading.  
+/// Requires a [`raw::Deserializer`] to read, and the value is written as [`raw`] format.  
+/// (Parameters: inner [`Seeder`], [`u32`] checksum [`Seeder`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Crc32<Inner, ChecksumSeeder>(pub Inner, pub ChecksumSeeder);
+impl<'de, T, Inner: DeSeeder<'de, T>, ChecksumSeeder: DeSeeder<'de, u32>> DeSeeder<'de, T>
+	for Crc32<Inner, ChecksumSeeder>
+{
+	type Seed = Crc32Seed<T, Inner, ChecksumSeeder>;
+	fn seed(self) -> Self::Seed {
+		ChecksummedSeed(self.0, self.1, PhantomData)
+	}
+}
+impl<T, Inner: SerSeeder<T>, ChecksumSeeder: SerSeeder<u32>> SerSeeder<T>
+	for Crc32<Inner, ChecksumSeeder>
+{
+	fn seeded<'s>(&'s self, value: &'s T) -> Seeded<'s> {
+		Box::new(ChecksummedSeeded(
+			value,
+			&self.0,
+			&self.1,
+			PhantomData::<Crc32Checksum>,
+		))
+	}
+}
+
+#[doc(hidden)]
+pub type Crc32Seed<T, Inner, ChecksumSeeder> =
+	ChecksummedSeed<T, Inner, ChecksumSeeder, Crc32Checksum>;
+
+#[doc(hidden)]
+pub type Crc32Seeded<'a, T, Inner, ChecksumSeeder> =
+	ChecksummedSeeded<'a, T, Inner, ChecksumSeeder, Crc32Checksum>;
+
+/// Value followed by the Adler-32 (as in zlib framing) of its bytes, which is verified when reading.  
+/// Requires a [`raw::Deserializer`] to read, and the value is written as [`raw`] format.  
+/// (Parameters: inner [`Seeder`], [`u32`] checksum [`Seeder`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Adler32<Inner, ChecksumSeeder>(pub Inner, pub ChecksumSeeder);
+impl<'de, T, Inner: DeSeeder<'de, T>, ChecksumSeeder: DeSeeder<'de, u32>> DeSeeder<'de, T>
+	for Adler32<Inner, ChecksumSeeder>
+{
+	type Seed = Adler32Seed<T, Inner, ChecksumSeeder>;
+	fn seed(self) -> Self::Seed {
+		ChecksummedSeed(self.0, self.1, PhantomData)
+	}
+}
+impl<T, Inner: SerSeeder<T>, ChecksumSeeder: SerSeeder<u32>> SerSeeder<T>
+	for Adler32<Inner, ChecksumSeeder>
+{
+	fn seeded<'s>(&'s self, value: &'s T) -> Seeded<'s> {
+		Box::new(ChecksummedSeeded(
+			value,
+			&self.0,
+			&self.1,
+			PhantomData::<Adler32Checksum>,
+		))
+	}
+}
+
+#[doc(hidden)]
+pub type Adler32Seed<T, Inner, ChecksumSeeder> =
+	ChecksummedSeed<T, Inner, ChecksumSeeder, Adler32Checksum>;
+
+#[doc(hidden)]
+pub type Adler32Seeded<'a, T, Inner, ChecksumSeeder> =
+	ChecksummedSeeded<'a, T, Inner, ChecksumSeeder, Adler32Checksum>;
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ChecksummedSeed<T, Inner, ChecksumSeeder, Algorithm>(
+	Inner,
+	ChecksumSeeder,
+	PhantomData<(T, Algorithm)>,
+);
+impl<
+		'de,
+		T,
+		Inner: DeSeeder<'de, T>,
+		ChecksumSeeder: DeSeeder<'de, Algorithm::Digest>,
+		Algorithm: Checksum,
+	> de::DeserializeSeed<'de> for ChecksummedSeed<T, Inner, ChecksumSeeder, Algorithm>
+{
+	type Value = T;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<T, Inner, ChecksumSeeder, Algorithm>(
+			Inner,
+			ChecksumSeeder,
+			PhantomData<(T, Algorithm)>,
+		);
+		impl<
+				'de,
+				T,
+				Inner: DeSeeder<'de, T>,
+				ChecksumSeeder: DeSeeder<'de, Algorithm::Digest>,
+				Algorithm: Checksum,
+			> de::Visitor<'de> for Visitor<T, Inner, ChecksumSeeder, Algorithm>
+		{
+			type Value = T;
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "value and {}", Algorithm::NAME)
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let Visitor(inner, checksum_seeder, _) = self;
+				let (value, payload) = raw::next_nested(
+					&mut seq,
+					Ok,
+					|payload| inner.seed().deserialize(payload),
+					|error| de::Error::custom(format_args!("{} (in {})", error, Algorithm::NAME)),
+				)?;
+				let actual = Algorithm::checksum(payload);
+				let expected = seq
+					.next_element_seed(checksum_seeder.seed())?
+					.ok_or_else(|| de::Error::invalid_length(2, &"value and checksum"))?;
+				if actual != expected {
+					return Err(de::Error::custom(format_args!(
+						"{} mismatch: stored {:08X}, computed {:08X}",
+						Algorithm::NAME,
+						expected,
+						actual
+					)));
+				}
+				Ok(value)
+			}
+		}
+
+		deserializer.deserialize_tuple(
+			3,
+			Visitor::<T, Inner, ChecksumSeeder, Algorithm>(self.0, self.1, PhantomData),
+		)
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct ChecksummedSeeded<'a, T, Inner, ChecksumSeeder, Algorithm>(
+	&'a T,
+	&'a Inner,
+	&'a ChecksumSeeder,
+	PhantomData<Algorithm>,
+);
+impl<
+		'a,
+		T,
+		Inner: SerSeeder<T>,
+		ChecksumSeeder: SerSeeder<Algorithm::Digest>,
+		Algorithm: Checksum,
+	> ser::Serialize for ChecksummedSeeded<'a, T, Inner, ChecksumSeeder, Algorithm>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let mut payload = raw::Serializer::new();
+		self.1
+			.seeded(self.0)
+			.serialize(&mut payload)
+			.map_err(ser::Error::custom)?;
+		let payload = payload.into_inner();
+
+		let mut serialize_tuple = serializer.serialize_tuple(2)?;
+		serialize_tuple.serialize_element(&Literal(&payload))?;
+		serialize_tuple.serialize_element(&self.2.seeded(&Algorithm::checksum(&payload)))?;
+		serialize_tuple.end()
+	}
+}
+
+/// A checksum algorithm for [`Checksummed`].  
+/// Implement this on a marker type to plug in your own.
+pub trait Checksum {
+	type Digest: Copy + Eq + Debug + UpperHex;
+	/// Used in error messages.
+	const NAME: &'static str;
+	fn checksum(bytes: &[u8]) -> Self::Digest;
+}
+
+/// CRC-32 (IEEE 802.3, reflected). See [`Crc32`] and [`crc32`].
+#[derive(Debug, Copy, Clone, Default, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Crc32Checksum;
+impl Checksum for Crc32Checksum {
+	type Digest = u32;
+	const NAME: &'static str = "CRC-32";
+	fn checksum(bytes: &[u8]) -> u32 {
+		crc32(bytes)
+	}
+}
+
+/// Adler-32 (RFC 1950). See [`Adler32`] and [`adler32`].
+#[derive(Debug, Copy, Clone, Default, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Adler32Checksum;
+impl Checksum for Adler32Checksum {
+	type Digest = u32;
+	const NAME: &'static str = "Adler-32";
+	fn checksum(bytes: &[u8]) -> u32 {
+		adler32(bytes)
+	}
+}
+
+/// The Adler-32 (RFC 1950) of `bytes`. See [`Adler32`].
+pub fn adler32(bytes: &[u8]) -> u32 {
+	const MOD_ADLER: u32 = 65521;
+	// 5552 is the most bytes that can be summed before `b` could overflow.
+	let (a, b) = bytes.chunks(5552).fold((1, 0), |(mut a, mut b), chunk| {
+		for &byte in chunk {
+			a += byte as u32;
+			b += a;
+		}
+		(a % MOD_ADLER, b % MOD_ADLER)
+	});
+	b << 16 | a
+}
+
+const CRC32_TABLE: [u32; 256] = {
+	let mut table = [0; 256];
+	let mut i = 0;
+	while i < 256 {
+		let mut crc = i as u32;
+		let mut bit = 0;
+		while bit < 8 {
+			crc = if crc & 1 == 1 {
+				0xEDB8_8320 ^ (crc >> 1)
+			} else {
+				crc >> 1
+			};
+			bit += 1;
+		}
+		table[i] = crc;
+		i += 1;
+	}
+	table
+};
+
+/// The CRC-32 (IEEE 802.3, reflected) of `bytes`. See [`Crc32`].
+pub fn crc32(bytes: &[u8]) -> u32 {
+	!bytes.iter().fold(!0, |crc, &byte| {
+		CRC32_TABLE[((crc ^ byte as u32) & 0xFF) as usize] ^ (crc >> 8)
+	})
+}
+
+/// `()` stored as a number of filler bytes.  
+/// The filler is only checked when reading if the third parameter is `true`.  
+/// (Parameters: byte count, fill byte, whether to check the fill byte when reading)
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Padding(pub usize, pub u8, pub bool);
+impl<'de> DeSeeder<'de, ()> for Padding {
+	type Seed = Self;
+	fn seed(self) -> Self::Seed {
+		self
+	}
+}
+impl SerSeeder<()> for Padding {
+	fn seeded<'s>(&'s self, _: &()) -> Seeded<'s> {
+		Box::new(*self)
+	}
+}
+impl ser::Serialize for Padding {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		Literal(&vec![self.1; self.0]).serialize(serializer)
+	}
+}
+impl<'de> de::DeserializeSeed<'de> for Padding {
+	type Value = ();
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor(Padding);
+		impl<'de> de::Visitor<'de> for Visitor {
+			type Value = ();
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "{} padding bytes", (self.0).0)
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let Padding(count, fill, check) = self.0;
+				for i in 0..count {
+					let received: u8 = seq
+						.next_element()?
+						.ok_or_else(|| de::Error::invalid_length(i, &self))?;
+					if check && received != fill {
+						return Err(de::Error::custom(format_args!(
+							"Expected padding byte {:02X} at {} but found {:02X}",
+							fill, i, received
+						)));
+					}
+				}
+				Ok(())
+			}
+		}
+
+		deserializer.deserialize_tuple(self.0, Visitor(self))
+	}
+}
+
+/// Value followed by zero bytes up to the next multiple of `N` bytes from the start of the [`raw`] input or output.  
+/// The padding isn't checked when reading.
+/// Requires a [`raw::Deserializer`] to read and a [`raw::Serializer`] to write, as other formats don't expose their offset.  
+/// (Usage: [`Align::<N, _>(inner_seeder)`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Align<const N: usize, Inner>(pub Inner);
+impl<'de, const N: usize, T, Inner: DeSeeder<'de, T>> DeSeeder<'de, T> for Align<N, Inner> {
+	type Seed = AlignSeed<N, T, Inner>;
+	fn seed(self) -> Self::Seed {
+		AlignSeed(self.0, PhantomData)
+	}
+}
+impl<const N: usize, T, Inner: SerSeeder<T>> SerSeeder<T> for Align<N, Inner> {
+	fn seeded<'s>(&'s self, value: &'s T) -> Seeded<'s> {
+		Box::new(AlignSeeded::<N, T, Inner>(value, &self.0))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct AlignSeed<const N: usize, T, Inner>(Inner, PhantomData<T>);
+impl<'de, const N: usize, T, Inner: DeSeeder<'de, T>> de::DeserializeSeed<'de>
+	for AlignSeed<N, T, Inner>
+{
+	type Value = T;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<const N: usize, T, Inner>(Inner, PhantomData<T>);
+		impl<'de, const N: usize, T, Inner: DeSeeder<'de, T>> de::Visitor<'de> for Visitor<N, T, Inner> {
+			type Value = T;
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "value aligned to {} bytes", N)
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let expected = &"value, offset and padding";
+				let value = seq
+					.next_element_seed(self.0.seed())
+					.map_err(|error| {
+						de::Error::custom(format_args!("{} (in Align::<{}, _>)", error, N))
+					})?
+					.ok_or_else(|| de::Error::invalid_length(0, expected))?;
+				let offset = seq
+					.next_element_seed(raw::OffsetSeed)?
+					.ok_or_else(|| de::Error::invalid_length(1, expected))?;
+				seq.next_element_seed(raw::SkipSeed(padding_to::<N>(offset)))?
+					.ok_or_else(|| de::Error::invalid_length(2, expected))?;
+				Ok(value)
+			}
+		}
+
+		deserializer.deserialize_tuple(3, Visitor::<N, T, Inner>(self.0, PhantomData))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct AlignSeeded<'a, const N: usize, T, Inner>(&'a T, &'a Inner);
+impl<'a, const N: usize, T, Inner: SerSeeder<T>> ser::Serialize for AlignSeeded<'a, N, T, Inner> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let mut serialize_tuple = serializer.serialize_tuple(2)?;
+		serialize_tuple.serialize_element(&self.1.seeded(self.0))?;
+		serialize_tuple.serialize_element(&raw::AlignTo(Alignment::<N>::ALIGN))?;
+		serialize_tuple.end()
+	}
+}
+
+/// `()` stored as a number of reserved bytes, which are ignored when reading and written as zeros.  
+/// (Usage: [`Skip(count)`], [`Skip::filled(count, fill)`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Skip(pub usize);
+impl Skip {
+	/// Like [`Skip`], but writes `fill` instead of zeros.
+	pub fn filled(count: usize, fill: u8) -> Padding {
+		Padding(count, fill, false)
+	}
+}
+impl<'de> DeSeeder<'de, ()> for Skip {
+	type Seed = Padding;
+	fn seed(self) -> Self::Seed {
+		Padding(self.0, 0, false)
+	}
+}
+impl SerSeeder<()> for Skip {
+	fn seeded<'s>(&'s self, _: &()) -> Seeded<'s> {
+		Box::new(Padding(self.0, 0, false))
+	}
+}
+
+/// `()` stored as a number of reserved bytes that must all equal `expected` when reading, to catch corrupt or misaligned data.  
+/// This is the strict counterpart of [`Skip`].
+/// Requires a [`raw::Deserializer`] to read, so that mismatches can be reported at their offset in the input.  
+/// (Parameters: byte count, expected byte)
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Reserved(pub usize, pub u8);
+impl<'de> DeSeeder<'de, ()> for Reserved {
+	type Seed = ReservedSeed;
+	fn seed(self) -> Self::Seed {
+		ReservedSeed(self.0, self.1)
+	}
+}
+impl SerSeeder<()> for Reserved {
+	fn seeded<'s>(&'s self, _: &()) -> Seeded<'s> {
+		Box::new(Padding(self.0, self.1, true))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ReservedSeed(usize, u8);
+impl<'de> de::DeserializeSeed<'de> for ReservedSeed {
+	type Value = ();
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor(ReservedSeed);
+		impl<'de> de::Visitor<'de> for Visitor {
+			type Value = ();
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "{} reserved bytes", (self.0).0)
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let ReservedSeed(count, expected) = self.0;
+				let offset = seq
+					.next_element_seed(raw::OffsetSeed)?
+					.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+				for i in 0..count {
+					let received: u8 = seq
+						.next_element()?
+						.ok_or_else(|| de::Error::invalid_length(i + 1, &self))?;
+					if received != expected {
+						return Err(de::Error::custom(format_args!(
+							"Expected reserved byte {:02X} at offset {} but found {:02X}",
+							expected,
+							offset + i,
+							received
+						)));
+					}
+				}
+				Ok(())
+			}
+		}
+
+		deserializer.deserialize_tuple(1 + self.0, Visitor(self))
+	}
+}
+
+/// Signed integer stored zigzag-encoded as its unsigned counterpart, so that small magnitudes stay small (as in protobuf `sint32`/`sint64`).  
+/// (Parameters: unsigned integer [`Seeder`], e.g. [`Leb128::unsigned()`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ZigZag<UIntSeeder>(pub UIntSeeder);
+impl<'de, T: ZigZagable, UIntSeeder: DeSeeder<'de, T::Unsigned>> DeSeeder<'de, T>
+	for ZigZag<UIntSeeder>
+{
+	type Seed = ZigZagSeed<T, UIntSeeder>;
+	fn seed(self) -> Self::Seed {
+		ZigZagSeed(self.0, PhantomData)
+	}
+}
+impl<T: ZigZagable, UIntSeeder: SerSeeder<T::Unsigned>> SerSeeder<T> for ZigZag<UIntSeeder> {
+	fn seeded<'s>(&'s self, value: &'s T) -> Seeded<'s> {
+		Box::new(ZigZagSeeded(value, &self.0))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ZigZagSeed<T, UIntSeeder>(UIntSeeder, PhantomData<T>);
+impl<'de, T: ZigZagable, UIntSeeder: DeSeeder<'de, T::Unsigned>> de::DeserializeSeed<'de>
+	for ZigZagSeed<T, UIntSeeder>
+{
+	type Value = T;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		self.0.seed().deserialize(deserializer).map(T::from_zigzag)
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct ZigZagSeeded<'a, T, UIntSeeder>(&'a T, &'a UIntSeeder);
+impl<'a, T: ZigZagable, UIntSeeder: SerSeeder<T::Unsigned>> ser::Serialize
+	for ZigZagSeeded<'a, T, UIntSeeder>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		self.0
+			.to_zigzag()
+			.pipe(|repr| self.1.seeded(&repr).serialize(serializer))
+	}
+}
+
+/// See [`ZigZag`].
+pub trait ZigZagable: Copy {
+	type Unsigned;
+	fn to_zigzag(self) -> Self::Unsigned;
+	fn from_zigzag(repr: Self::Unsigned) -> Self;
+}
+macro_rules! impl_zigzagable {
+	($($signed:ty => $unsigned:ty),*$(,)?) => {$(
+		impl ZigZagable for $signed {
+			type Unsigned = $unsigned;
+			fn to_zigzag(self) -> Self::Unsigned {
+				((self << 1) ^ (self >> (<$signed>::BITS - 1))) as $unsigned
+			}
+			fn from_zigzag(repr: Self::Unsigned) -> Self {
+				((repr >> 1) as $signed) ^ -((repr & 1) as $signed)
+			}
+		}
+	)*};
+}
+impl_zigzagable!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+
+/// Flag set stored as its integer bits, e.g. for types generated by the `bitflags` crate.  
+/// Unknown bits are discarded, unless strict, in which case they are an error.  
+/// (Usage: [`Flags::lenient(int_seeder)`], [`Flags::strict(int_seeder)`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Flags<IntSeeder>(IntSeeder, bool);
+impl<IntSeeder> Flags<IntSeeder> {
+	pub fn lenient(int_seeder: IntSeeder) -> Self {
+		Self(int_seeder, false)
+	}
+
+	pub fn strict(int_seeder: IntSeeder) -> Self {
+		Self(int_seeder, true)
+	}
+}
+impl<'de, T: Flagsable, IntSeeder: DeSeeder<'de, T::Int>> DeSeeder<'de, T> for Flags<IntSeeder> {
+	type Seed = FlagsSeed<T, IntSeeder>;
+	fn seed(self) -> Self::Seed {
+		FlagsSeed(self.0, self.1, PhantomData)
+	}
+}
+impl<T: Flagsable, IntSeeder: SerSeeder<T::Int>> SerSeeder<T> for Flags<IntSeeder> {
+	fn seeded<'s>(&'s self, value: &'s T) -> Seeded<'s> {
+		Box::new(FlagsSeeded(value, &self.0))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct FlagsSeed<T, IntSeeder>(IntSeeder, bool, PhantomData<T>);
+impl<'de, T: Flagsable, IntSeeder: DeSeeder<'de, T::Int>> de::DeserializeSeed<'de>
+	for FlagsSeed<T, IntSeeder>
+{
+	type Value = T;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let bits = self.0.seed().deserialize(deserializer)?;
+		if !self.1 {
+			return Ok(T::from_bits_truncate(bits));
+		}
+		T::from_bits(bits).ok_or_else(|| {
+			de::Error::custom(format_args!(
+				"Unknown bits in {:#X?} for strict Flags",
+				bits
+			))
+		})
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct FlagsSeeded<'a, T, IntSeeder>(&'a T, &'a IntSeeder);
+impl<'a, T: Flagsable, IntSeeder: SerSeeder<T::Int>> ser::Serialize
+	for FlagsSeeded<'a, T, IntSeeder>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		self.0
+			.to_bits()
+			.pipe(|bits| self.1.seeded(&bits).serialize(serializer))
+	}
+}
+
+/// See [`Flags`]. Maps directly onto the `bitflags` crate's generated methods.
+pub trait Flagsable: Sized {
+	type Int: Copy + Debug + UpperHex;
+	/// [`None`] if `bits` contains unknown flags.
+	fn from_bits(bits: Self::Int) -> Option<Self>;
+	fn from_bits_truncate(bits: Self::Int) -> Self;
+	fn to_bits(&self) -> Self::Int;
+}
+
+/// Tagged union: A tag, followed by the fields of the variant it selects.  
+/// The variants are laid out by implementing [`DeTaggable`] and [`SerTaggable`] on the enum.  
+/// (Parameters: tag [`Seeder`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Tagged<TagSeeder>(pub TagSeeder);
+impl<'de, T: DeTaggable<'de>, TagSeeder: DeSeeder<'de, T::Tag>> DeSeeder<'de, T>
+	for Tagged<TagSeeder>
+{
+	type Seed = TaggedSeed<T, TagSeeder>;
+	fn seed(self) -> Self::Seed {
+		TaggedSeed(self.0, PhantomData)
+	}
+}
+impl<T: SerTaggable, TagSeeder: SerSeeder<T::Tag>> SerSeeder<T> for Tagged<TagSeeder> {
+	fn seeded<'s>(&'s self, value: &'s T) -> Seeded<'s> {
+		Box::new(TaggedSeeded(value, &self.0))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct TaggedSeed<T, TagSeeder>(TagSeeder, PhantomData<T>);
+impl<'de, T: DeTaggable<'de>, TagSeeder: DeSeeder<'de, T::Tag>> de::DeserializeSeed<'de>
+	for TaggedSeed<T, TagSeeder>
+{
+	type Value = T;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<T, TagSeeder>(TagSeeder, PhantomData<T>);
+		impl<'de, T: DeTaggable<'de>, TagSeeder: DeSeeder<'de, T::Tag>> de::Visitor<'de>
+			for Visitor<T, TagSeeder>
+		{
+			type Value = T;
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "tag and one of the variants {:?}", T::VARIANTS)
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let tag = seq
+					.next_element_seed(self.0.seed())?
+					.ok_or_else(|| de::Error::invalid_length(0, &"tag and variant"))?;
+				let len = match T::field_count(&tag) {
+					Some(len) => len,
+					None => {
+						return Err(de::Error::unknown_variant(
+							&format!("{:?}", tag),
+							T::VARIANTS,
+						))
+					}
+				};
+				seq.next_element_seed(VariantSeed(tag, len, PhantomData))?
+					.ok_or_else(|| de::Error::invalid_length(1, &"tag and variant"))
+			}
+		}
+
+		struct VariantSeed<'de, T: DeTaggable<'de>>(T::Tag, usize, PhantomData<T>);
+		impl<'de, T: DeTaggable<'de>> de::DeserializeSeed<'de> for VariantSeed<'de, T> {
+			type Value = T;
+			fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				deserializer.deserialize_tuple(self.1, self)
+			}
+		}
+		impl<'de, T: DeTaggable<'de>> de::Visitor<'de> for VariantSeed<'de, T> {
+			type Value = T;
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "{} fields of variant {:?}", self.1, self.0)
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let variant = format!("{:?}", self.0);
+				T::from(self.0, &mut seq)?
+					.ok_or_else(|| de::Error::unknown_variant(&variant, T::VARIANTS))
+			}
+		}
+
+		deserializer.deserialize_tuple(2, Visitor(self.0, PhantomData))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct TaggedSeeded<'a, T, TagSeeder>(&'a T, &'a TagSeeder);
+impl<'a, T: SerTaggable, TagSeeder: SerSeeder<T::Tag>> ser::Serialize
+	for TaggedSeeded<'a, T, TagSeeder>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		struct Variant<'a, T>(&'a T);
+		impl<'a, T: SerTaggable> ser::Serialize for Variant<'a, T> {
+			fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+			where
+				S: serde::Serializer,
+			{
+				let mut serialize_tuple = serializer.serialize_tuple(self.0.len())?;
+				self.0.to(&mut serialize_tuple)?;
+				serialize_tuple.end()
+			}
+		}
+
+		let mut serialize_tuple = serializer.serialize_tuple(2)?;
+		serialize_tuple.serialize_element(&self.1.seeded(&self.0.tag()))?;
+		serialize_tuple.serialize_element(&Variant(self.0))?;
+		serialize_tuple.end()
+	}
+}
+
+/// See [`Tagged`].
+pub trait DeTaggable<'de>: Sized {
+	type Tag: Debug;
+	/// Names of the known variants, for error messages.
+	const VARIANTS: &'static [&'static str];
+	/// The number of fields [`DeTaggable::from`] reads for `tag`, or [`None`] if `tag` is unknown.
+	fn field_count(tag: &Self::Tag) -> Option<usize>;
+	/// Reads the fields of the variant selected by `tag` from `fields`, or returns [`None`] if `tag` is unknown.
+	fn from<A: de::SeqAccess<'de>>(
+		tag: Self::Tag,
+		fields: &mut A,
+	) -> Result<Option<Self>, A::Error>;
+}
+/// See [`Tagged`].
+pub trait SerTaggable {
+	type Tag;
+	fn tag(&self) -> Self::Tag;
+	/// The number of fields [`SerTaggable::to`] writes.
+	fn len(&self) -> usize;
+	fn to<SerializeTuple: ser::SerializeTuple>(
+		&self,
+		serialize_tuple: &mut SerializeTuple,
+	) -> Result<(), SerializeTuple::Error>;
+
+	fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+}
+
+/// Enum stored as one of a fixed set of string tokens, e.g. a keyword.  
+/// The tokens are listed by implementing [`StringEnumerable`] on the enum, and compared as raw bytes.  
+/// (Parameters: bytes [`Seeder`], e.g. [`NulTerminated`] or [`LengthPrefixed`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct StringEnum<BytesSeeder>(pub BytesSeeder);
+impl<'de, T: StringEnumerable, BytesSeeder: DeSeeder<'de, Vec<u8>>> DeSeeder<'de, T>
+	for StringEnum<BytesSeeder>
+{
+	type Seed = StringEnumSeed<T, BytesSeeder>;
+	fn seed(self) -> Self::Seed {
+		StringEnumSeed(self.0, PhantomData)
+	}
+}
+impl<T: StringEnumerable, BytesSeeder: SerSeeder<Vec<u8>>> SerSeeder<T>
+	for StringEnum<BytesSeeder>
+{
+	fn seeded<'s>(&'s self, value: &'s T) -> Seeded<'s> {
+		Box::new(StringEnumSeeded(value, &self.0))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct StringEnumSeed<T, BytesSeeder>(BytesSeeder, PhantomData<T>);
+impl<'de, T: StringEnumerable, BytesSeeder: DeSeeder<'de, Vec<u8>>> de::DeserializeSeed<'de>
+	for StringEnumSeed<T, BytesSeeder>
+{
+	type Value = T;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let bytes = self.0.seed().deserialize(deserializer)?;
+		T::TOKENS
+			.iter()
+			.position(|token| token.as_bytes() == bytes.as_slice())
+			.map(T::from_index)
+			.ok_or_else(|| de::Error::unknown_variant(&String::from_utf8_lossy(&bytes), T::TOKENS))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct StringEnumSeeded<'a, T, BytesSeeder>(&'a T, &'a BytesSeeder);
+impl<'a, T: StringEnumerable, BytesSeeder: SerSeeder<Vec<u8>>> ser::Serialize
+	for StringEnumSeeded<'a, T, BytesSeeder>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let index = self.0.index();
+		let token = T::TOKENS.get(index).ok_or_else(|| {
+			ser::Error::custom(format_args!(
+				"StringEnum index {} is out of range for the tokens {:?}",
+				index,
+				T::TOKENS
+			))
+		})?;
+		token
+			.as_bytes()
+			.to_vec()
+			.pipe(|bytes| self.1.seeded(&bytes).serialize(serializer))
+	}
+}
+
+/// See [`StringEnum`] and [`FourCC`].
+pub trait StringEnumerable: Sized {
+	/// The known tokens. A variant is identified by the index of its token.
+	const TOKENS: &'static [&'static str];
+	/// `index` is always in `0..Self::TOKENS.len()`.
+	fn from_index(index: usize) -> Self;
+	fn index(&self) -> usize;
+}
+
+/// Enum stored as a four-character code, i.e. a 4-byte (usually ASCII) tag like `b"RIFF"`.  
+/// The tokens are listed by implementing [`StringEnumerable`] on the enum, and must all be four bytes long.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct FourCC;
+impl<'de, T: StringEnumerable> DeSeeder<'de, T> for FourCC {
+	type Seed = StringEnumSeed<T, TupleN<SerdeLike>>;
+	fn seed(self) -> Self::Seed {
+		StringEnumSeed(TupleN(4, SerdeLike), PhantomData)
+	}
+}
+impl<T: StringEnumerable> SerSeeder<T> for FourCC {
+	fn seeded<'s>(&'s self, value: &'s T) -> Seeded<'s> {
+		Box::new(StringEnumSeeded(value, &TupleN(4, SerdeLike)))
+	}
+}
+
+/// `()` stored as a constant value, which is checked when reading (e.g. a format version).  
+/// (Parameters: expected value, inner [`Seeder`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Const<T, Inner>(pub T, pub Inner);
+impl<'de, T: PartialEq + Debug, Inner: DeSeeder<'de, T>> DeSeeder<'de, ()> for Const<T, Inner> {
+	type Seed = Self;
+	fn seed(self) -> Self::Seed {
+		self
+	}
+}
+impl<T, Inner: SerSeeder<T>> SerSeeder<()> for Const<T, Inner> {
+	fn seeded<'s>(&'s self, _: &()) -> Seeded<'s> {
+		self.1.seeded(&self.0)
+	}
+}
+impl<'de, T: PartialEq + Debug, Inner: DeSeeder<'de, T>> de::DeserializeSeed<'de>
+	for Const<T, Inner>
+{
+	type Value = ();
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let Const(expected, inner) = self;
+		let actual = inner.seed().deserialize(deserializer)?;
+		if actual != expected {
+			return Err(de::Error::custom(format_args!(
+				"Expected constant {:?} but found {:?}",
+				expected, actual
+			)));
+		}
+		Ok(())
+	}
+}
+
+/// What a [`Map`] does when it reads a key that's already present.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum DuplicateKeys {
+	/// Fail with an error.
+	#[default]
+	Error,
+	/// Log a warning and keep the later value.
+	Warn,
+}
+
+/// [`HashMap`] or [`BTreeMap`] as length-prefixed sequence of key-value pairs.  
+/// Entries are written in key order, so a [`HashMap`]'s keys must be [`Ord`] to write it.  
+/// (Parameters: length [`Seeder`], key [`Seeder`], value [`Seeder`], [`DuplicateKeys`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Map<LengthSeeder, KeySeeder, ValueSeeder>(
+	pub LengthSeeder,
+	pub KeySeeder,
+	pub ValueSeeder,
+	pub DuplicateKeys,
+);
+impl<
+		'de,
+		M: DeMapable,
+		LengthSeeder: DeSeeder<'de, usize>,
+		KeySeeder: Clone + DeSeeder<'de, M::Key>,
+		ValueSeeder: Clone + DeSeeder<'de, M::Value>,
+	> DeSeeder<'de, M> for Map<LengthSeeder, KeySeeder, ValueSeeder>
+{
+	type Seed = MapSeed<M, LengthSeeder, KeySeeder, ValueSeeder>;
+	fn seed(self) -> Self::Seed {
+		MapSeed(self.0, self.1, self.2, self.3, PhantomData)
+	}
+}
+impl<
+		M: SerMapable,
+		LengthSeeder: SerSeeder<usize>,
+		KeySeeder: SerSeeder<M::Key>,
+		ValueSeeder: SerSeeder<M::Value>,
+	> SerSeeder<M> for Map<LengthSeeder, KeySeeder, ValueSeeder>
+{
+	fn seeded<'s>(&'s self, value: &'s M) -> Seeded<'s> {
+		Box::new(MapSeeded(value, &self.0, &self.1, &self.2))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct MapSeed<M, LengthSeeder, KeySeeder, ValueSeeder>(
+	LengthSeeder,
+	KeySeeder,
+	ValueSeeder,
+	DuplicateKeys,
+	PhantomData<M>,
+);
+impl<
+		'de,
+		M: DeMapable,
+		LengthSeeder: DeSeeder<'de, usize>,
+		KeySeeder: Clone + DeSeeder<'de, M::Key>,
+		ValueSeeder: Clone + DeSeeder<'de, M::Value>,
+	> de::DeserializeSeed<'de> for MapSeed<M, LengthSeeder, KeySeeder, ValueSeeder>
+{
+	type Value = M;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<M, LengthSeeder, KeySeeder, ValueSeeder>(
+			LengthSeeder,
+			KeySeeder,
+			ValueSeeder,
+			DuplicateKeys,
+			PhantomData<M>,
+		);
+		impl<
+				'de,
+				M: DeMapable,
+				LengthSeeder: DeSeeder<'de, usize>,
+				KeySeeder: Clone + DeSeeder<'de, M::Key>,
+				ValueSeeder: Clone + DeSeeder<'de, M::Value>,
+			> de::Visitor<'de> for Visitor<M, LengthSeeder, KeySeeder, ValueSeeder>
+		{
+			type Value = M;
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "length-prefixed map")
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let Visitor(length_seeder, key_seeder, value_seeder, duplicate_keys, _) = self;
+				let length = seq
+					.next_element_seed(length_seeder.seed())?
+					.ok_or_else(|| de::Error::invalid_length(0, &"length-prefixed map"))?;
+				seq.next_element_seed(MapEntriesSeed(
+					length,
+					key_seeder,
+					value_seeder,
+					duplicate_keys,
+					PhantomData,
+				))?
+				.ok_or_else(|| de::Error::invalid_length(1, &"length-prefixed map"))
+			}
+		}
+
+		deserializer.deserialize_tuple(2, Visitor(self.0, self.1, self.2, self.3, PhantomData))
+	}
+}
+
+/// The entries of a [`Map`], as flat tuple of alternating keys and values.
+struct MapEntriesSeed<M, KeySeeder, ValueSeeder>(
+	usize,
+	KeySeeder,
+	ValueSeeder,
+	DuplicateKeys,
+	PhantomData<M>,
+);
+impl<
+		'de,
+		M: DeMapable,
+		KeySeeder: Clone + DeSeeder<'de, M::Key>,
+		ValueSeeder: Clone + DeSeeder<'de, M::Value>,
+	> de::Visitor<'de> for MapEntriesSeed<M, KeySeeder, ValueSeeder>
+{
+	type Value = M;
+	fn expecting(
+		&self,
+		f: &mut core::fmt::Formatter<'_>,
+	) -> core::result::Result<(), core::fmt::Error> {
+		write!(f, "{} map entries", self.0)
+	}
+
+	fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+		let mut map = M::default();
+		for i in 0..self.0 {
+			let key = seq
+				.next_element_seed(self.1.clone().seed())?
+				.ok_or_else(|| de::Error::invalid_length(2 * i, &self))?;
+			let value = seq
+				.next_element_seed(self.2.clone().seed())?
+				.ok_or_else(|| de::Error::invalid_length(2 * i + 1, &self))?;
+			if map.contains_key(&key) {
+				match self.3 {
+					DuplicateKeys::Error => {
+						return Err(de::Error::custom(format_args!(
+							"Duplicate map key {:?}",
+							key
+						)))
+					}
+					DuplicateKeys::Warn => warn!("Duplicate map key {:?}", key),
+				}
+			}
+			map.insert(key, value);
+		}
+		Ok(map)
+	}
+}
+impl<
+		'de,
+		M: DeMapable,
+		KeySeeder: Clone + DeSeeder<'de, M::Key>,
+		ValueSeeder: Clone + DeSeeder<'de, M::Value>,
+	> de::DeserializeSeed<'de> for MapEntriesSeed<M, KeySeeder, ValueSeeder>
+{
+	type Value = M;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let len = self.0.saturating_mul(2);
+		deserializer.deserialize_tuple(len, self)
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct MapSeeded<'a, M, LengthSeeder, KeySeeder, ValueSeeder>(
+	&'a M,
+	&'a LengthSeeder,
+	&'a KeySeeder,
+	&'a ValueSeeder,
+);
+impl<
+		'a,
+		M: SerMapable,
+		LengthSeeder: SerSeeder<usize>,
+		KeySeeder: SerSeeder<M::Key>,
+		ValueSeeder: SerSeeder<M::Value>,
+	> ser::Serialize for MapSeeded<'a, M, LengthSeeder, KeySeeder, ValueSeeder>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		struct Entries<'a, M, KeySeeder, ValueSeeder>(&'a M, &'a KeySeeder, &'a ValueSeeder);
+		impl<'a, M: SerMapable, KeySeeder: SerSeeder<M::Key>, ValueSeeder: SerSeeder<M::Value>>
+			ser::Serialize for Entries<'a, M, KeySeeder, ValueSeeder>
+		{
+			fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+			where
+				S: serde::Serializer,
+			{
+				let mut serialize_tuple = serializer.serialize_tuple(2 * self.0.len())?;
+				self.0.to(&mut serialize_tuple, self.1, self.2)?;
+				serialize_tuple.end()
+			}
+		}
+
+		let mut serialize_tuple = serializer.serialize_tuple(2)?;
+		serialize_tuple.serialize_element(&self.1.seeded(&self.0.len()))?;
+		serialize_tuple.serialize_element(&Entries(self.0, self.2, self.3))?;
+		serialize_tuple.end()
+	}
+}
+
+/// See [`Map`].
+pub trait DeMapable: Default {
+	type Key: Debug;
+	type Value;
+	fn contains_key(&self, key: &Self::Key) -> bool;
+	/// Replaces the value if `key` is already present.
+	fn insert(&mut self, key: Self::Key, value: Self::Value);
+}
+/// See [`Map`].
+pub trait SerMapable {
+	type Key;
+	type Value;
+	fn len(&self) -> usize;
+	/// Serializes each entry as key element followed by value element.
+	fn to<SerializeTuple: ser::SerializeTuple, KeySeeder, ValueSeeder>(
+		&self,
+		serialize_tuple: &mut SerializeTuple,
+		key_seeder: &KeySeeder,
+		value_seeder: &ValueSeeder,
+	) -> Result<(), SerializeTuple::Error>
+	where
+		KeySeeder: SerSeeder<Self::Key>,
+		ValueSeeder: SerSeeder<Self::Value>;
+
+	fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+}
+
+impl<K: Debug + Ord, V> DeMapable for BTreeMap<K, V> {
+	type Key = K;
+	type Value = V;
+	fn contains_key(&self, key: &K) -> bool {
+		BTreeMap::contains_key(self, key)
+	}
+	fn insert(&mut self, key: K, value: V) {
+		BTreeMap::insert(self, key, value);
+	}
+}
+impl<K, V> SerMapable for BTreeMap<K, V> {
+	type Key = K;
+	type Value = V;
+	fn len(&self) -> usize {
+		BTreeMap::len(self)
+	}
+	fn to<SerializeTuple: ser::SerializeTuple, KeySeeder, ValueSeeder>(
+		&self,
+		serialize_tuple: &mut SerializeTuple,
+		key_seeder: &KeySeeder,
+		value_seeder: &ValueSeeder,
+	) -> Result<(), SerializeTuple::Error>
+	where
+		KeySeeder: SerSeeder<K>,
+		ValueSeeder: SerSeeder<V>,
+	{
+		for (key, value) in self {
+			serialize_tuple.serialize_element(&key_seeder.seeded(key))?;
+			serialize_tuple.serialize_element(&value_seeder.seeded(value))?;
+		}
+		Ok(())
+	}
+}
+
+macro_rules! heterogeneous_tuple_seeder {
+	($(#[$attr:meta])* $name:ident, $seed:ident, $seeded:ident, $len:literal: $($S:ident $T:ident $i:tt),+) => {
+		$(#[$attr])*
+		#[derive(Debug, Copy, Clone, Default)]
+		pub struct $name<$($S),+>($(pub $S),+);
+		impl<'de, $($T, $S: DeSeeder<'de, $T>),+> DeSeeder<'de, ($($T,)+)> for $name<$($S),+> {
+			type Seed = $seed<$($T, $S),+>;
+			fn seed(self) -> Self::Seed {
+				$seed($(self.$i),+, PhantomData)
+			}
+		}
+		impl<$($T, $S: SerSeeder<$T>),+> SerSeeder<($($T,)+)> for $name<$($S),+> {
+			fn seeded<'s>(&'s self, value: &'s ($($T,)+)) -> Seeded<'s> {
+				Box::new($seeded(value, $(&self.$i),+))
+			}
+		}
+
+		#[doc(hidden)]
+		#[derive(Debug, Copy, Clone, Default)]
+		pub struct $seed<$($T, $S),+>($($S),+, PhantomData<($($T,)+)>);
+		impl<'de, $($T, $S: DeSeeder<'de, $T>),+> de::DeserializeSeed<'de> for $seed<$($T, $S),+> {
+			type Value = ($($T,)+);
+			fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct Visitor<$($T, $S),+>($($S),+, PhantomData<($($T,)+)>);
+				impl<'de, $($T, $S: DeSeeder<'de, $T>),+> de::Visitor<'de> for Visitor<$($T, $S),+> {
+					type Value = ($($T,)+);
+					fn expecting(
+						&self,
+						f: &mut core::fmt::Formatter<'_>,
+					) -> core::result::Result<(), core::fmt::Error> {
+						write!(f, "{}-tuple", $len)
+					}
+
+					#[allow(non_snake_case)]
+					fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+						let Visitor($($S),+, _) = self;
+						Ok(($(
+							seq.next_element_seed($S.seed())?
+								.ok_or_else(|| de::Error::invalid_length($i, &concat!(stringify!($len), "-tuple")))?,
+						)+))
+					}
+				}
+
+				deserializer.deserialize_tuple($len, Visitor($(self.$i),+, PhantomData))
+			}
+		}
+
+		#[doc(hidden)]
+		#[derive(Debug, Copy, Clone)]
+		pub struct $seeded<'a, $($T, $S),+>(&'a ($($T,)+), $(&'a $S),+);
+		impl<'a, $($T, $S: SerSeeder<$T>),+> ser::Serialize for $seeded<'a, $($T, $S),+> {
+			#[allow(non_snake_case)]
+			fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+			where
+				S: serde::Serializer,
+			{
+				let $seeded(($($T,)+), $($S),+) = self;
+				let mut serialize_tuple = serializer.serialize_tuple($len)?;
+				$(serialize_tuple.serialize_element(&$S.seeded($T))?;)+
+				serialize_tuple.end()
+			}
+		}
+	};
+}
+heterogeneous_tuple_seeder! {
+	/// `(A, B)` with a distinct [`Seeder`] for each element.
+	/// (Parameters: first [`Seeder`], second [`Seeder`])
+	Pair, PairSeed, PairSeeded, 2: S0 T0 0, S1 T1 1
+}
+heterogeneous_tuple_seeder! {
+	/// `(A, B, C)` with a distinct [`Seeder`] for each element.
+	/// (Parameters: first [`Seeder`], second [`Seeder`], third [`Seeder`])
+	Triple, TripleSeed, TripleSeeded, 3: S0 T0 0, S1 T1 1, S2 T2 2
+}
+
+/// Tuple of values, each with the [`Seeder`] at the same position in a tuple of seeders, for one-off layouts.  
+/// (`DeSeeder` and `SerSeeder` can't be implemented on bare tuples outside of `serde_seeded`, hence the wrapper.)  
+/// Implemented for up to 12 elements.  
+/// (Parameters: tuple of [`Seeder`]s, e.g. `Tupled((LittleEndian, Windows1252(...), Seq(...)))`)
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Tupled<Seeders>(pub Seeders);
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct TupledSeed<T, Seeders>(Seeders, PhantomData<T>);
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone)]
+pub struct TupledSeeded<'a, T, Seeders>(&'a T, &'a Seeders);
+
+macro_rules! impl_tupled {
+	($($len:literal: $($S:ident $T:ident $i:tt),+;)*) => {$(
+		impl<'de, $($T, $S: DeSeeder<'de, $T>),+> DeSeeder<'de, ($($T,)+)> for Tupled<($($S,)+)> {
+			type Seed = TupledSeed<($($T,)+), ($($S,)+)>;
+			fn seed(self) -> Self::Seed {
+				TupledSeed(self.0, PhantomData)
+			}
+		}
+		impl<$($T, $S: SerSeeder<$T>),+> SerSeeder<($($T,)+)> for Tupled<($($S,)+)> {
+			fn seeded<'s>(&'s self, value: &'s ($($T,)+)) -> Seeded<'s> {
+				Box::new(TupledSeeded(value, &self.0))
+			}
+		}
+
+		impl<'de, $($T, $S: DeSeeder<'de, $T>),+> de::DeserializeSeed<'de>
+			for TupledSeed<($($T,)+), ($($S,)+)>
+		{
+			type Value = ($($T,)+);
+			fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct Visitor<$($T, $S),+>(($($S,)+), PhantomData<($($T,)+)>);
+				impl<'de, $($T, $S: DeSeeder<'de, $T>),+> de::Visitor<'de> for Visitor<$($T, $S),+> {
+					type Value = ($($T,)+);
+					fn expecting(
+						&self,
+						f: &mut core::fmt::Formatter<'_>,
+					) -> core::result::Result<(), core::fmt::Error> {
+						write!(f, "{}-tuple", $len)
+					}
+
+					#[allow(non_snake_case)]
+					fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+						let Visitor(($($S,)+), _) = self;
+						Ok(($(
+							seq.next_element_seed($S.seed())?
+								.ok_or_else(|| de::Error::invalid_length($i, &concat!(stringify!($len), "-tuple")))?,
+						)+))
+					}
+				}
+
+				deserializer.deserialize_tuple($len, Visitor(self.0, PhantomData))
+			}
+		}
+
+		impl<'a, $($T, $S: SerSeeder<$T>),+> ser::Serialize
+			for TupledSeeded<'a, ($($T,)+), ($($S,)+)>
+		{
+			#[allow(non_snake_case)]
+			fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+			where
+				S: serde::Serializer,
+			{
+				let TupledSeeded(($($T,)+), ($($S,)+)) = self;
+				let mut serialize_tuple = serializer.serialize_tuple($len)?;
+				$(serialize_tuple.serialize_element(&$S.seeded($T))?;)+
+				serialize_tuple.end()
+			}
+		}
+	)*};
+}
+impl_tupled! {
+	1: S0 T0 0;
+	2: S0 T0 0, S1 T1 1;
+	3: S0 T0 0, S1 T1 1, S2 T2 2;
+	4: S0 T0 0, S1 T1 1, S2 T2 2, S3 T3 3;
+	5: S0 T0 0, S1 T1 1, S2 T2 2, S3 T3 3, S4 T4 4;
+	6: S0 T0 0, S1 T1 1, S2 T2 2, S3 T3 3, S4 T4 4, S5 T5 5;
+	7: S0 T0 0, S1 T1 1, S2 T2 2, S3 T3 3, S4 T4 4, S5 T5 5, S6 T6 6;
+	8: S0 T0 0, S1 T1 1, S2 T2 2, S3 T3 3, S4 T4 4, S5 T5 5, S6 T6 6, S7 T7 7;
+	9: S0 T0 0, S1 T1 1, S2 T2 2, S3 T3 3, S4 T4 4, S5 T5 5, S6 T6 6, S7 T7 7, S8 T8 8;
+	10: S0 T0 0, S1 T1 1, S2 T2 2, S3 T3 3, S4 T4 4, S5 T5 5, S6 T6 6, S7 T7 7, S8 T8 8, S9 T9 9;
+	11: S0 T0 0, S1 T1 1, S2 T2 2, S3 T3 3, S4 T4 4, S5 T5 5, S6 T6 6, S7 T7 7, S8 T8 8, S9 T9 9, S10 T10 10;
+	12: S0 T0 0, S1 T1 1, S2 T2 2, S3 T3 3, S4 T4 4, S5 T5 5, S6 T6 6, S7 T7 7, S8 T8 8, S9 T9 9, S10 T10 10, S11 T11 11;
+}
+
+/// Bytes up to the end of the input, as [`Vec<u8>`] or borrowed `&[u8]`.  
+/// This is greedy, so it only works as the last field of a layout.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Rest;
+impl<'de> DeSeeder<'de, Vec<u8>> for Rest {
+	type Seed = RestSeed<Vec<u8>>;
+	fn seed(self) -> Self::Seed {
+		RestSeed(PhantomData)
+	}
+}
+impl<'de> DeSeeder<'de, &'de [u8]> for Rest {
+	type Seed = RestSeed<&'de [u8]>;
+	fn seed(self) -> Self::Seed {
+		RestSeed(PhantomData)
+	}
+}
+impl SerSeeder<Vec<u8>> for Rest {
+	fn seeded<'s>(&'s self, value: &'s Vec<u8>) -> Seeded<'s> {
+		Box::new(RestSeeded(value))
+	}
+}
+impl<'a> SerSeeder<&'a [u8]> for Rest {
+	fn seeded<'s>(&'s self, value: &'s &'a [u8]) -> Seeded<'s> {
+		Box::new(RestSeeded(value))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct RestSeed<T>(PhantomData<T>);
+impl<'de> de::DeserializeSeed<'de> for RestSeed<Vec<u8>> {
+	type Value = Vec<u8>;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor;
+		impl<'de> de::Visitor<'de> for Visitor {
+			type Value = Vec<u8>;
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "remaining bytes")
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let mut bytes = Vec::new();
+				while let Some(byte) = seq.next_element()? {
+					bytes.push(byte)
+				}
+				Ok(bytes)
+			}
+
+			fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+				Ok(v.to_vec())
+			}
+		}
+
+		deserializer.deserialize_seq(Visitor)
+	}
+}
+impl<'de> de::DeserializeSeed<'de> for RestSeed<&'de [u8]> {
+	type Value = &'de [u8];
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor;
+		impl<'de> de::Visitor<'de> for Visitor {
+			type Value = &'de [u8];
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "remaining borrowed bytes")
+			}
+
+			fn visit_borrowed_bytes<E: de::Error>(self, v: &'de [u8]) -> Result<Self::Value, E> {
+				Ok(v)
+			}
+		}
+
+		deserializer.deserialize_bytes(Visitor)
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct RestSeeded<'a>(&'a [u8]);
+impl<'a> ser::Serialize for RestSeeded<'a> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let mut serialize_seq = serializer.serialize_seq(Some(self.0.len()))?;
+		for byte in self.0 {
+			serialize_seq.serialize_element(byte)?
+		}
+		serialize_seq.end()
+	}
+}
+
+/// Bytes up to the end of the input, borrowed from the deserializer and converted via [`From<&[u8]>`](`From`).  
+/// Unlike [`Rest`], this works for any zero-copy target like [`Cow<[u8]>`](`alloc::borrow::Cow`) without requiring [`From<Vec<u8>>`](`From`),
+/// but the deserializer must provide borrowed bytes.  
+/// This is greedy, so it only works as the last field of a layout.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct BorrowedBuffer;
+impl<'de, T: From<&'de [u8]>> DeSeeder<'de, T> for BorrowedBuffer {
+	type Seed = BorrowedBufferSeed<T>;
+	fn seed(self) -> Self::Seed {
+		BorrowedBufferSeed(PhantomData)
+	}
+}
+impl<T: AsRef<[u8]>> SerSeeder<T> for BorrowedBuffer {
+	fn seeded<'s>(&'s self, value: &'s T) -> Seeded<'s> {
+		Box::new(RestSeeded(value.as_ref()))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct BorrowedBufferSeed<T>(PhantomData<T>);
+impl<'de, T: From<&'de [u8]>> de::DeserializeSeed<'de> for BorrowedBufferSeed<T> {
+	type Value = T;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		RestSeed::<&'de [u8]>(PhantomData)
+			.deserialize(deserializer)
+			.map(T::from)
+	}
+}
+
+/// What the address read by [`Offset`] is relative to.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum OffsetBase {
+	/// The start of the [`raw::Deserializer`]'s input.
+	#[default]
+	Input,
+	/// The start of the address field itself.
+	Field,
+}
+
+/// Value stored elsewhere in the input, referenced by an address. Only the address is consumed.  
+/// Requires a [`raw::Deserializer`] to read. Writing isn't supported, since the value's location depends on the whole layout.  
+/// (Parameters: address [`Seeder`], inner [`Seeder`], [`OffsetBase`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Offset<AddrSeeder, Inner>(pub AddrSeeder, pub Inner, pub OffsetBase);
+impl<'de, T, AddrSeeder: DeSeeder<'de, usize>, Inner: DeSeeder<'de, T>> DeSeeder<'de, T>
+	for Offset<AddrSeeder, Inner>
+{
+	type Seed = OffsetSeed<T, AddrSeeder, Inner>;
+	fn seed(self) -> Self::Seed {
+		OffsetSeed(self.0, self.1, self.2, PhantomData)
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct OffsetSeed<T, AddrSeeder, Inner>(AddrSeeder, Inner, OffsetBase, PhantomData<T>);
+impl<'de, T, AddrSeeder: DeSeeder<'de, usize>, Inner: DeSeeder<'de, T>> de::DeserializeSeed<'de>
+	for OffsetSeed<T, AddrSeeder, Inner>
+{
+	type Value = T;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<T, AddrSeeder, Inner>(AddrSeeder, Inner, OffsetBase, PhantomData<T>);
+		impl<'de, T, AddrSeeder: DeSeeder<'de, usize>, Inner: DeSeeder<'de, T>> de::Visitor<'de>
+			for Visitor<T, AddrSeeder, Inner>
+		{
+			type Value = T;
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "address of a value")
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let Visitor(addr_seeder, inner, base, _) = self;
+				let input = seq
+					.next_element_seed(raw::InputSeed)?
+					.ok_or_else(|| de::Error::invalid_length(0, &"input"))?;
+				let field = seq
+					.next_element_seed(raw::OffsetSeed)?
+					.ok_or_else(|| de::Error::invalid_length(1, &"offset"))?;
+				let address = seq
+					.next_element_seed(addr_seeder.seed())?
+					.ok_or_else(|| de::Error::invalid_length(2, &"address"))?;
+				let position = match base {
+					OffsetBase::Input => Some(address),
+					OffsetBase::Field => field.checked_add(address),
+				}
+				.filter(|&position| position <= input.len())
+				.ok_or_else(|| {
+					de::Error::custom(format_args!(
+						"Address {} ({:?}-relative) is outside the input of {} bytes",
+						address,
+						base,
+						input.len()
+					))
+				})?;
+				trace!("Following Offset to {}.", position);
+				inner
+					.seed()
+					.deserialize(&mut raw::Deserializer::new(&input[position..]))
+					.map_err(|error| {
+						de::Error::custom(format_args!("{} (at Offset {})", error, position))
+					})
+			}
+		}
+
+		deserializer.deserialize_tuple(3, Visitor(self.0, self.1, self.2, PhantomData))
+	}
+}
+
+/// Trailing value that falls back to [`Default::default`] if its sequence has no elements left (e.g. in older format versions).  
+/// Against a buffered deserializer that ends sequences with its input, the default is used iff no bytes remain,
+/// which makes it suitable only as last field. Malformed data is still an error.  
+/// The value is always written.  
+/// (Parameters: inner [`Seeder`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct OrDefault<Inner>(pub Inner);
+impl<'de, T: Default, Inner: DeSeeder<'de, T>> DeSeeder<'de, T> for OrDefault<Inner> {
+	type Seed = OrDefaultSeed<T, Inner>;
+	fn seed(self) -> Self::Seed {
+		OrDefaultSeed(self.0, PhantomData)
+	}
+}
+impl<T, Inner: SerSeeder<T>> SerSeeder<T> for OrDefault<Inner> {
+	fn seeded<'s>(&'s self, value: &'s T) -> Seeded<'s> {
+		self.0.seeded(value)
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct OrDefaultSeed<T, Inner>(Inner, PhantomData<T>);
+impl<'de, T: Default, Inner: DeSeeder<'de, T>> de::DeserializeSeed<'de>
+	for OrDefaultSeed<T, Inner>
+{
+	type Value = T;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		IfRemainingSeed(self.0, PhantomData)
+			.deserialize(deserializer)?
+			.unwrap_or_else(|| {
+				trace!("OrDefault fell back to default.");
+				T::default()
+			})
+			.pipe(Ok)
+	}
+}
+
+/// [`String`] as UTF-16 code units in the byte order of the u16 [`Seeder`], prefixed by the number of code units.  
+/// A leading byte order mark is removed when reading and, if it's byte-swapped, overrides the byte order.
+/// If the third parameter is `true`, a byte order mark is written (and counted) in front of the text.  
+/// (Parameters: u16 [`Seeder`], length [`Seeder`], whether to write a byte order mark)
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Utf16<ByteOrder, LengthSeeder>(pub ByteOrder, pub LengthSeeder, pub bool);
+impl<'de, ByteOrder: Clone + DeSeeder<'de, u16>, LengthSeeder: DeSeeder<'de, usize>>
+	DeSeeder<'de, String> for Utf16<ByteOrder, LengthSeeder>
+{
+	type Seed = Utf16Seed<ByteOrder, LengthSeeder>;
+	fn seed(self) -> Self::Seed {
+		Utf16Seed(self.0, self.1)
+	}
+}
+impl<ByteOrder: SerSeeder<u16>, LengthSeeder: SerSeeder<usize>> SerSeeder<String>
+	for Utf16<ByteOrder, LengthSeeder>
+{
+	fn seeded<'s>(&'s self, value: &'s String) -> Seeded<'s> {
+		Box::new(Utf16Seeded(value, &self.0, &self.1, self.2))
+	}
+}
+
+const UTF16_BOM: u16 = 0xFEFF;
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Utf16Seed<ByteOrder, LengthSeeder>(ByteOrder, LengthSeeder);
+impl<'de, ByteOrder: Clone + DeSeeder<'de, u16>, LengthSeeder: DeSeeder<'de, usize>>
+	de::DeserializeSeed<'de> for Utf16Seed<ByteOrder, LengthSeeder>
+{
+	type Value = String;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let units: Vec<u16> =
+			LengthPrefixedSeed(self.1, self.0, PhantomData).deserialize(deserializer)?;
+		let value = match units.split_first() {
+			Some((&UTF16_BOM, units)) => String::from_utf16(units),
+			Some((&bom, units)) if bom == UTF16_BOM.swap_bytes() => {
+				trace!("Byte-swapped UTF-16 BOM, swapping code units.");
+				units
+					.iter()
+					.map(|unit| unit.swap_bytes())
+					.collect::<Vec<_>>()
+					.pipe(|units| String::from_utf16(&units))
+			}
+			_ => String::from_utf16(&units),
+		}
+		.map_err(de::Error::custom)?;
+		debug!("Decoded UTF-16: {:?}", value);
+		Ok(value)
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct Utf16Seeded<'a, ByteOrder, LengthSeeder>(
+	&'a String,
+	&'a ByteOrder,
+	&'a LengthSeeder,
+	bool,
+);
+impl<'a, ByteOrder: SerSeeder<u16>, LengthSeeder: SerSeeder<usize>> ser::Serialize
+	for Utf16Seeded<'a, ByteOrder, LengthSeeder>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let units: Vec<u16> = iter::once(UTF16_BOM)
+			.filter(|_| self.3)
+			.chain(self.0.encode_utf16())
+			.collect();
+		LengthPrefixedSeeded(self.2, self.1, &units).serialize(serializer)
+	}
+}
+
+/// Value prefixed by its size in bytes (rather than an element count like [`LengthPrefixed`]).  
+/// The value must use up exactly that many bytes.
+/// Requires a [`raw::Deserializer`] to read, and the value is written as [`raw`] format.  
+/// (Usage: [`ByteLengthPrefixed(length_seeder, inner_seeder)`], [`ByteLengthPrefixed::items(length_seeder, item_seeder)`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ByteLengthPrefixed<LengthSeeder, Inner>(pub LengthSeeder, pub Inner);
+impl<LengthSeeder, ItemSeeder> ByteLengthPrefixed<LengthSeeder, Seq<ItemSeeder>> {
+	/// [`Vec<_>`] of variable-width items, read until the byte length is used up.  
+	/// An item that would overrun it is an error, as are any bytes left over after the last item.
+	pub fn items(length_seeder: LengthSeeder, item_seeder: ItemSeeder) -> Self {
+		Self(length_seeder, Seq(item_seeder))
+	}
+}
+impl<'de, T, LengthSeeder: DeSeeder<'de, usize>, Inner: DeSeeder<'de, T>> DeSeeder<'de, T>
+	for ByteLengthPrefixed<LengthSeeder, Inner>
+{
+	type Seed = ByteLengthPrefixedSeed<T, LengthSeeder, Inner>;
+	fn seed(self) -> Self::Seed {
+		ByteLengthPrefixedSeed(self.0, self.1, PhantomData)
+	}
+}
+impl<T, LengthSeeder: SerSeeder<usize>, Inner: SerSeeder<T>> SerSeeder<T>
+	for ByteLengthPrefixed<LengthSeeder, Inner>
+{
+	fn seeded<'s>(&'s self, value: &'s T) -> Seeded<'s> {
+		Box::new(ByteLengthPrefixedSeeded(value, &self.0, &self.1))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ByteLengthPrefixedSeed<T, LengthSeeder, Inner>(LengthSeeder, Inner, PhantomData<T>);
+impl<'de, T, LengthSeeder: DeSeeder<'de, usize>, Inner: DeSeeder<'de, T>> de::DeserializeSeed<'de>
+	for ByteLengthPrefixedSeed<T, LengthSeeder, Inner>
+{
+	type Value = T;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let region = RawRegionSeed(self.0).deserialize(deserializer)?;
+		let mut limited = raw::Deserializer::new(region);
+		self.1
+			.seed()
+			.deserialize(&mut limited)
+			.and_then(|value| limited.end().map(|()| value))
+			.map_err(|error| {
+				de::Error::custom(format_args!(
+					"{} (in ByteLengthPrefixed of {} bytes)",
+					error,
+					region.len()
+				))
+			})
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct ByteLengthPrefixedSeeded<'a, T, LengthSeeder, Inner>(&'a T, &'a LengthSeeder, &'a Inner);
+impl<'a, T, LengthSeeder: SerSeeder<usize>, Inner: SerSeeder<T>> ser::Serialize
+	for ByteLengthPrefixedSeeded<'a, T, LengthSeeder, Inner>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let mut value = raw::Serializer::new();
+		self.2
+			.seeded(self.0)
+			.serialize(&mut value)
+			.map_err(ser::Error::custom)?;
+		RawRegionSeeded(&value.output(), self.1).serialize(serializer)
+	}
+}
+
+/// Lookahead: Peeks at the next `N` bytes without consuming them and passes them to a closure that picks the actual [`Seeder`],
+/// which then reads from the same position, so it sees the peeked bytes again.  
+/// Requires a [`raw::Deserializer`] to read. Writing isn't supported, since it's not known which [`Seeder`] applies to a value;
+/// use the one the closure would pick directly.  
+/// (Parameters: `FnOnce(&[u8; N]) -> impl Seeder`)
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Peek<const N: usize, Decide>(pub Decide);
+impl<'de, T, const N: usize, Decide: FnOnce(&[u8; N]) -> Inner, Inner: DeSeeder<'de, T>>
+	DeSeeder<'de, T> for Peek<N, Decide>
+{
+	type Seed = PeekSeed<T, N, Decide>;
+	fn seed(self) -> Self::Seed {
+		PeekSeed(self.0, PhantomData)
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct PeekSeed<T, const N: usize, Decide>(Decide, PhantomData<T>);
+impl<'de, T, const N: usize, Decide: FnOnce(&[u8; N]) -> Inner, Inner: DeSeeder<'de, T>>
+	de::DeserializeSeed<'de> for PeekSeed<T, N, Decide>
+{
+	type Value = T;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<T, const N: usize, Decide>(Decide, PhantomData<T>);
+		impl<
+				'de,
+				T,
+				const N: usize,
+				Decide: FnOnce(&[u8; N]) -> Inner,
+				Inner: DeSeeder<'de, T>,
+			> de::Visitor<'de> for Visitor<T, N, Decide>
+		{
+			type Value = T;
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "{} bytes to peek at", N)
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let Visitor(decide, _) = self;
+				let remaining = raw::next_remaining(&mut seq)?;
+				let peeked: &[u8; N] = remaining
+					.get(..N)
+					.and_then(|peeked| peeked.try_into().ok())
+					.ok_or_else(|| {
+						de::Error::invalid_length(
+							remaining.len(),
+							&format!("at least {} bytes to peek at", N).as_str(),
+						)
+					})?;
+				seq.next_element_seed(decide(peeked).seed())?
+					.ok_or_else(|| de::Error::invalid_length(1, &"peeked value"))
+			}
+		}
+
+		deserializer.deserialize_tuple(2, Visitor::<T, N, Decide>(self.0, PhantomData))
 	}
 }
@@ -0,0 +1,871 @@
+//! Flat in-memory byte format without any framing.
+//!
+//! Tuples, structs and primitives are concatenated without any length prefixes or padding,
+//! multi-byte primitives are stored little-endian and sequences, maps, strings and bytes
+//! extend to the end of the input.
+//! This is the buffered path some seeders in this crate rely on to learn about their position in the input
+//! or the size of what they write.
+
+use alloc::{
+	format,
+	string::{String, ToString as _},
+	vec::Vec,
+};
+use core::{
+	any::type_name,
+	fmt::{self, Display},
+};
+use log::trace;
+use serde::{
+	de::{self, IntoDeserializer as _},
+	ser,
+};
+
+/// Error of [`Deserializer`] and [`Serializer`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error(String);
+impl Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(&self.0)
+	}
+}
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+impl de::Error for Error {
+	fn custom<T: Display>(msg: T) -> Self {
+		Self(msg.to_string())
+	}
+}
+
+impl ser::Error for Error {
+	fn custom<T: Display>(msg: T) -> Self {
+		Self(msg.to_string())
+	}
+}
+
+/// Newtype struct name a [`Deserializer`] answers with its current offset via [`de::Visitor::visit_u64`], without consuming any input.
+pub(crate) const OFFSET_TOKEN: &str = "$raw_seeders::raw::Offset";
+
+/// Reads the current offset into a [`Deserializer`]'s input.
+#[derive(Debug, Copy, Clone, Default)]
+pub(crate) struct OffsetSeed;
+impl<'de> de::DeserializeSeed<'de> for OffsetSeed {
+	type Value = usize;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor;
+		impl<'de> de::Visitor<'de> for Visitor {
+			type Value = usize;
+			fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+				write!(f, "offset from a raw_seeders::raw::Deserializer")
+			}
+
+			fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+				Ok(v as usize)
+			}
+		}
+
+		deserializer.deserialize_newtype_struct(OFFSET_TOKEN, Visitor)
+	}
+}
+
+/// Newtype struct name a [`Deserializer`] answers with its remaining input via [`de::Visitor::visit_borrowed_bytes`], without consuming any of it.
+pub(crate) const REMAINING_TOKEN: &str = "$raw_seeders::raw::Remaining";
+
+/// Reads the remaining input of a [`Deserializer`] without consuming it.
+#[derive(Debug, Copy, Clone, Default)]
+pub(crate) struct RemainingSeed;
+impl<'de> de::DeserializeSeed<'de> for RemainingSeed {
+	type Value = &'de [u8];
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor;
+		impl<'de> de::Visitor<'de> for Visitor {
+			type Value = &'de [u8];
+			fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+				write!(f, "remaining input of a raw_seeders::raw::Deserializer")
+			}
+
+			fn visit_borrowed_bytes<E: de::Error>(self, v: &'de [u8]) -> Result<Self::Value, E> {
+				Ok(v)
+			}
+		}
+
+		deserializer.deserialize_newtype_struct(REMAINING_TOKEN, Visitor)
+	}
+}
+
+/// Newtype struct name a [`Deserializer`] answers with its entire input via [`de::Visitor::visit_borrowed_bytes`], without consuming any of it.
+pub(crate) const INPUT_TOKEN: &str = "$raw_seeders::raw::Input";
+
+/// Reads the entire input of a [`Deserializer`], including what was already consumed, without consuming anything.
+#[derive(Debug, Copy, Clone, Default)]
+pub(crate) struct InputSeed;
+impl<'de> de::DeserializeSeed<'de> for InputSeed {
+	type Value = &'de [u8];
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor;
+		impl<'de> de::Visitor<'de> for Visitor {
+			type Value = &'de [u8];
+			fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+				write!(f, "entire input of a raw_seeders::raw::Deserializer")
+			}
+
+			fn visit_borrowed_bytes<E: de::Error>(self, v: &'de [u8]) -> Result<Self::Value, E> {
+				Ok(v)
+			}
+		}
+
+		deserializer.deserialize_newtype_struct(INPUT_TOKEN, Visitor)
+	}
+}
+
+/// Consumes and discards a number of bytes.
+#[derive(Debug, Copy, Clone, Default)]
+pub(crate) struct SkipSeed(pub usize);
+impl<'de> de::DeserializeSeed<'de> for SkipSeed {
+	type Value = ();
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor(usize);
+		impl<'de> de::Visitor<'de> for Visitor {
+			type Value = ();
+			fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+				write!(f, "{} bytes", self.0)
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				for i in 0..self.0 {
+					seq.next_element::<u8>()?
+						.ok_or_else(|| de::Error::invalid_length(i, &self))?;
+				}
+				Ok(())
+			}
+		}
+
+		deserializer.deserialize_tuple(self.0, Visitor(self.0))
+	}
+}
+
+/// Tuple struct name a [`Serializer`] answers by writing zeros up to the next multiple of its length in output bytes.
+pub(crate) const ALIGN_TOKEN: &str = "$raw_seeders::raw::Align";
+
+/// Writes zeros up to the next multiple of `.0` bytes of a [`Serializer`]'s output.
+#[derive(Debug, Copy, Clone, Default)]
+pub(crate) struct AlignTo(pub usize);
+impl ser::Serialize for AlignTo {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		ser::SerializeTupleStruct::end(serializer.serialize_tuple_struct(ALIGN_TOKEN, self.0)?)
+	}
+}
+
+/// Reads the input remaining behind `seq` with [`RemainingSeed`], without consuming it.
+pub(crate) fn next_remaining<'de, A: de::SeqAccess<'de>>(
+	seq: &mut A,
+) -> Result<&'de [u8], A::Error> {
+	// An empty remainder ends a seq, so it may not be reported.
+	Ok(seq.next_element_seed(RemainingSeed)?.unwrap_or_default())
+}
+
+/// Runs `read` on a nested [`Deserializer`] over the part of the input remaining behind `seq` that `region` selects,
+/// then consumes as many bytes as it read with [`SkipSeed`].
+///
+/// Returns the value and the bytes it was read from.
+/// Errors of the nested [`Deserializer`] are passed through `context`.
+pub(crate) fn next_nested<'de, A: de::SeqAccess<'de>, T>(
+	seq: &mut A,
+	region: impl FnOnce(&'de [u8]) -> Result<&'de [u8], A::Error>,
+	read: impl FnOnce(&mut Deserializer<'de>) -> Result<T, Error>,
+	context: impl FnOnce(Error) -> A::Error,
+) -> Result<(T, &'de [u8]), A::Error> {
+	let remaining = next_remaining(seq)?;
+	let mut nested = Deserializer::new(region(remaining)?);
+	let value = read(&mut nested).map_err(context)?;
+	let consumed = &remaining[..nested.offset()];
+	if seq.next_element_seed(SkipSeed(consumed.len()))?.is_none() && !consumed.is_empty() {
+		return Err(de::Error::invalid_length(consumed.len(), &"consumed input"));
+	}
+	Ok((value, consumed))
+}
+
+/// Deserializes a value from all of `input` with `seed`, e.g. from [`DeSeeder::seed`](`serde_seeded::DeSeeder::seed`).
+///
+/// # Errors
+///
+/// Iff deserialization fails or any input remains.
+pub fn from_slice<'de, Seed: de::DeserializeSeed<'de>>(
+	seed: Seed,
+	input: &'de [u8],
+) -> Result<Seed::Value, Error> {
+	let mut deserializer = Deserializer::new(input);
+	let value = seed.deserialize(&mut deserializer)?;
+	deserializer.end()?;
+	Ok(value)
+}
+
+/// Serializes `value` into a new [`Vec<u8>`], e.g. from [`SerSeeder::seeded`](`serde_seeded::SerSeeder::seeded`).
+///
+/// # Errors
+///
+/// Iff serialization fails.
+pub fn to_vec<T: ?Sized + ser::Serialize>(value: &T) -> Result<Vec<u8>, Error> {
+	let mut serializer = Serializer::new();
+	value.serialize(&mut serializer)?;
+	Ok(serializer.into_inner())
+}
+
+/// Serializes `value` with `seeder` in this format, deserializes it back with the same `seeder` and asserts that the result equals `value`.  
+/// Returns the serialized bytes for further checks.
+///
+/// # Panics
+///
+/// Iff either direction fails, input remains or the round-tripped value differs.
+#[cfg(any(test, feature = "test-util"))]
+pub fn assert_round_trips<T, Seeder>(seeder: Seeder, value: &T) -> Vec<u8>
+where
+	T: PartialEq + fmt::Debug,
+	Seeder: Clone + serde_seeded::SerSeeder<T> + for<'de> serde_seeded::DeSeeder<'de, T>,
+{
+	let bytes = to_vec(&*seeder.seeded(value))
+		.unwrap_or_else(|error| panic!("Failed to serialize {:?}: {}", value, error));
+	let round_tripped = from_slice(seeder.seed(), &bytes).unwrap_or_else(|error| {
+		panic!(
+			"Failed to deserialize {:?} from {:02X?}: {}",
+			value, bytes, error
+		)
+	});
+	assert_eq!(
+		&round_tripped, value,
+		"Round trip through {:02X?} changed the value",
+		bytes
+	);
+	bytes
+}
+
+/// Reads from a borrowed byte slice.
+#[derive(Debug, Clone)]
+pub struct Deserializer<'de> {
+	whole: &'de [u8],
+	input: &'de [u8],
+	offset: usize,
+}
+impl<'de> Deserializer<'de> {
+	pub fn new(input: &'de [u8]) -> Self {
+		Self {
+			whole: input,
+			input,
+			offset: 0,
+		}
+	}
+
+	/// The number of bytes consumed so far.
+	pub fn offset(&self) -> usize {
+		self.offset
+	}
+
+	/// The input that has not been consumed yet.
+	pub fn remaining(&self) -> &'de [u8] {
+		self.input
+	}
+
+	/// Errors iff any input remains.
+	pub fn end(&self) -> Result<(), Error> {
+		if self.input.is_empty() {
+			Ok(())
+		} else {
+			Err(Error(format!(
+				"{} trailing bytes at offset {}",
+				self.input.len(),
+				self.offset
+			)))
+		}
+	}
+
+	fn take(&mut self, len: usize) -> Result<&'de [u8], Error> {
+		if len > self.input.len() {
+			return Err(Error(format!(
+				"Tried to read {} bytes at offset {}, but only {} remain",
+				len,
+				self.offset,
+				self.input.len()
+			)));
+		}
+		let (taken, rest) = self.input.split_at(len);
+		self.input = rest;
+		self.offset += len;
+		Ok(taken)
+	}
+
+	fn take_array<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+		let mut array = [0; N];
+		array.copy_from_slice(self.take(N)?);
+		Ok(array)
+	}
+
+	/// Logs the byte range `read` consumed at trace level, as e.g. "tuple(3) at offset 0x1A..0x26".
+	fn traced<T>(
+		&mut self,
+		what: impl Display,
+		read: impl FnOnce(&mut Self) -> Result<T, Error>,
+	) -> Result<T, Error> {
+		let start = self.offset;
+		let value = read(self);
+		match &value {
+			Ok(_) => trace!("{} at offset {:#X}..{:#X}", what, start, self.offset),
+			Err(error) => trace!(
+				"{} at offset {:#X} failed at {:#X}: {}",
+				what,
+				start,
+				self.offset,
+				error
+			),
+		}
+		value
+	}
+
+	fn take_rest(&mut self) -> &'de [u8] {
+		let rest = self.input;
+		self.input = &[];
+		self.offset += rest.len();
+		rest
+	}
+}
+
+macro_rules! deserialize_le {
+	($($deserialize:ident => $visit:ident($ty:ty)),*$(,)?) => {$(
+		fn $deserialize<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+			visitor.$visit(<$ty>::from_le_bytes(self.take_array()?))
+		}
+	)*};
+}
+
+impl<'de> de::Deserializer<'de> for &mut Deserializer<'de> {
+	type Error = Error;
+
+	fn deserialize_any<V: de::Visitor<'de>>(self, _: V) -> Result<V::Value, Self::Error> {
+		Err(Error(
+			"The raw format is not self-describing, so deserialize_any isn't supported".to_string(),
+		))
+	}
+
+	fn deserialize_bool<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+		match self.take_array::<1>()? {
+			[0] => visitor.visit_bool(false),
+			[1] => visitor.visit_bool(true),
+			[other] => Err(de::Error::invalid_value(
+				de::Unexpected::Unsigned(other as u64),
+				&"0 or 1",
+			)),
+		}
+	}
+
+	deserialize_le! {
+		deserialize_i8 => visit_i8(i8),
+		deserialize_i16 => visit_i16(i16),
+		deserialize_i32 => visit_i32(i32),
+		deserialize_i64 => visit_i64(i64),
+		deserialize_i128 => visit_i128(i128),
+		deserialize_u8 => visit_u8(u8),
+		deserialize_u16 => visit_u16(u16),
+		deserialize_u32 => visit_u32(u32),
+		deserialize_u64 => visit_u64(u64),
+		deserialize_u128 => visit_u128(u128),
+		deserialize_f32 => visit_f32(f32),
+		deserialize_f64 => visit_f64(f64),
+	}
+
+	fn deserialize_char<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+		let code = u32::from_le_bytes(self.take_array()?);
+		visitor.visit_char(core::char::from_u32(code).ok_or_else(|| {
+			de::Error::invalid_value(de::Unexpected::Unsigned(code as u64), &"char")
+		})?)
+	}
+
+	fn deserialize_str<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+		let offset = self.offset;
+		visitor.visit_borrowed_str(
+			core::str::from_utf8(self.take_rest())
+				.map_err(|error| Error(format!("{} (at offset {})", error, offset)))?,
+		)
+	}
+
+	fn deserialize_string<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+		self.deserialize_str(visitor)
+	}
+
+	fn deserialize_bytes<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+		visitor.visit_borrowed_bytes(self.take_rest())
+	}
+
+	fn deserialize_byte_buf<V: de::Visitor<'de>>(
+		self,
+		visitor: V,
+	) -> Result<V::Value, Self::Error> {
+		self.deserialize_bytes(visitor)
+	}
+
+	fn deserialize_option<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+		match self.take_array::<1>()? {
+			[0] => visitor.visit_none(),
+			[1] => visitor.visit_some(self),
+			[other] => Err(de::Error::invalid_value(
+				de::Unexpected::Unsigned(other as u64),
+				&"option flag 0 or 1",
+			)),
+		}
+	}
+
+	fn deserialize_unit<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+		visitor.visit_unit()
+	}
+
+	fn deserialize_unit_struct<V: de::Visitor<'de>>(
+		self,
+		_name: &'static str,
+		visitor: V,
+	) -> Result<V::Value, Self::Error> {
+		visitor.visit_unit()
+	}
+
+	fn deserialize_newtype_struct<V: de::Visitor<'de>>(
+		self,
+		name: &'static str,
+		visitor: V,
+	) -> Result<V::Value, Self::Error> {
+		match name {
+			OFFSET_TOKEN => visitor.visit_u64(self.offset as u64),
+			REMAINING_TOKEN => visitor.visit_borrowed_bytes(self.input),
+			INPUT_TOKEN => visitor.visit_borrowed_bytes(self.whole),
+			_ => visitor.visit_newtype_struct(self),
+		}
+	}
+
+	fn deserialize_seq<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+		self.traced("seq", |this| visitor.visit_seq(Rest(this)))
+	}
+
+	fn deserialize_tuple<V: de::Visitor<'de>>(
+		self,
+		len: usize,
+		visitor: V,
+	) -> Result<V::Value, Self::Error> {
+		self.traced(format_args!("tuple({})", len), |this| {
+			visitor.visit_seq(Counted(this, len))
+		})
+	}
+
+	fn deserialize_tuple_struct<V: de::Visitor<'de>>(
+		self,
+		_name: &'static str,
+		len: usize,
+		visitor: V,
+	) -> Result<V::Value, Self::Error> {
+		self.deserialize_tuple(len, visitor)
+	}
+
+	fn deserialize_map<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+		self.traced("map", |this| visitor.visit_map(Rest(this)))
+	}
+
+	fn deserialize_struct<V: de::Visitor<'de>>(
+		self,
+		_name: &'static str,
+		fields: &'static [&'static str],
+		visitor: V,
+	) -> Result<V::Value, Self::Error> {
+		self.deserialize_tuple(fields.len(), visitor)
+	}
+
+	fn deserialize_enum<V: de::Visitor<'de>>(
+		self,
+		_name: &'static str,
+		_variants: &'static [&'static str],
+		visitor: V,
+	) -> Result<V::Value, Self::Error> {
+		visitor.visit_enum(self)
+	}
+
+	fn deserialize_identifier<V: de::Visitor<'de>>(
+		self,
+		visitor: V,
+	) -> Result<V::Value, Self::Error> {
+		self.deserialize_u32(visitor)
+	}
+
+	fn deserialize_ignored_any<V: de::Visitor<'de>>(self, _: V) -> Result<V::Value, Self::Error> {
+		Err(Error(
+			"The raw format is not self-describing, so values can't be ignored".to_string(),
+		))
+	}
+
+	fn is_human_readable(&self) -> bool {
+		false
+	}
+}
+
+/// Elements until the end of input.
+struct Rest<'a, 'de>(&'a mut Deserializer<'de>);
+impl<'a, 'de> de::SeqAccess<'de> for Rest<'a, 'de> {
+	type Error = Error;
+	fn next_element_seed<T: de::DeserializeSeed<'de>>(
+		&mut self,
+		seed: T,
+	) -> Result<Option<T::Value>, Self::Error> {
+		if self.0.input.is_empty() {
+			return Ok(None);
+		}
+		self.0
+			.traced(type_name::<T::Value>(), |this| seed.deserialize(this))
+			.map(Some)
+	}
+}
+impl<'a, 'de> de::MapAccess<'de> for Rest<'a, 'de> {
+	type Error = Error;
+	fn next_key_seed<K: de::DeserializeSeed<'de>>(
+		&mut self,
+		seed: K,
+	) -> Result<Option<K::Value>, Self::Error> {
+		de::SeqAccess::next_element_seed(self, seed)
+	}
+	fn next_value_seed<V: de::DeserializeSeed<'de>>(
+		&mut self,
+		seed: V,
+	) -> Result<V::Value, Self::Error> {
+		seed.deserialize(&mut *self.0)
+	}
+}
+
+/// A fixed number of elements.
+struct Counted<'a, 'de>(&'a mut Deserializer<'de>, usize);
+impl<'a, 'de> de::SeqAccess<'de> for Counted<'a, 'de> {
+	type Error = Error;
+	fn next_element_seed<T: de::DeserializeSeed<'de>>(
+		&mut self,
+		seed: T,
+	) -> Result<Option<T::Value>, Self::Error> {
+		if self.1 == 0 {
+			return Ok(None);
+		}
+		self.1 -= 1;
+		self.0
+			.traced(type_name::<T::Value>(), |this| seed.deserialize(this))
+			.map(Some)
+	}
+
+	fn size_hint(&self) -> Option<usize> {
+		Some(self.1)
+	}
+}
+
+impl<'de> de::EnumAccess<'de> for &mut Deserializer<'de> {
+	type Error = Error;
+	type Variant = Self;
+	fn variant_seed<V: de::DeserializeSeed<'de>>(
+		self,
+		seed: V,
+	) -> Result<(V::Value, Self::Variant), Self::Error> {
+		let index = u32::from_le_bytes(self.take_array()?);
+		let variant = seed.deserialize(index.into_deserializer())?;
+		Ok((variant, self))
+	}
+}
+impl<'de> de::VariantAccess<'de> for &mut Deserializer<'de> {
+	type Error = Error;
+	fn unit_variant(self) -> Result<(), Self::Error> {
+		Ok(())
+	}
+	fn newtype_variant_seed<T: de::DeserializeSeed<'de>>(
+		self,
+		seed: T,
+	) -> Result<T::Value, Self::Error> {
+		seed.deserialize(self)
+	}
+	fn tuple_variant<V: de::Visitor<'de>>(
+		self,
+		len: usize,
+		visitor: V,
+	) -> Result<V::Value, Self::Error> {
+		de::Deserializer::deserialize_tuple(self, len, visitor)
+	}
+	fn struct_variant<V: de::Visitor<'de>>(
+		self,
+		fields: &'static [&'static str],
+		visitor: V,
+	) -> Result<V::Value, Self::Error> {
+		de::Deserializer::deserialize_tuple(self, fields.len(), visitor)
+	}
+}
+
+/// Writes into a [`Vec<u8>`].
+#[derive(Debug, Clone, Default)]
+pub struct Serializer {
+	output: Vec<u8>,
+}
+impl Serializer {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// The bytes written so far.
+	pub fn output(&self) -> &[u8] {
+		&self.output
+	}
+
+	pub fn into_inner(self) -> Vec<u8> {
+		self.output
+	}
+}
+
+macro_rules! serialize_le {
+	($($serialize:ident($ty:ty)),*$(,)?) => {$(
+		fn $serialize(self, v: $ty) -> Result<Self::Ok, Self::Error> {
+			self.output.extend_from_slice(&v.to_le_bytes());
+			Ok(())
+		}
+	)*};
+}
+
+impl ser::Serializer for &mut Serializer {
+	type Ok = ();
+	type Error = Error;
+	type SerializeSeq = Self;
+	type SerializeTuple = Self;
+	type SerializeTupleStruct = Self;
+	type SerializeTupleVariant = Self;
+	type SerializeMap = Self;
+	type SerializeStruct = Self;
+	type SerializeStructVariant = Self;
+
+	fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+		self.output.push(v as u8);
+		Ok(())
+	}
+
+	serialize_le! {
+		serialize_i8(i8),
+		serialize_i16(i16),
+		serialize_i32(i32),
+		serialize_i64(i64),
+		serialize_i128(i128),
+		serialize_u8(u8),
+		serialize_u16(u16),
+		serialize_u32(u32),
+		serialize_u64(u64),
+		serialize_u128(u128),
+		serialize_f32(f32),
+		serialize_f64(f64),
+	}
+
+	fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+		self.serialize_u32(v as u32)
+	}
+
+	fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+		self.serialize_bytes(v.as_bytes())
+	}
+
+	fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
+		self.output.extend_from_slice(v);
+		Ok(())
+	}
+
+	fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+		self.serialize_bool(false)
+	}
+
+	fn serialize_some<T: ?Sized + ser::Serialize>(
+		self,
+		value: &T,
+	) -> Result<Self::Ok, Self::Error> {
+		self.output.push(1);
+		value.serialize(self)
+	}
+
+	fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+		Ok(())
+	}
+
+	fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+		Ok(())
+	}
+
+	fn serialize_unit_variant(
+		self,
+		_name: &'static str,
+		variant_index: u32,
+		_variant: &'static str,
+	) -> Result<Self::Ok, Self::Error> {
+		self.serialize_u32(variant_index)
+	}
+
+	fn serialize_newtype_struct<T: ?Sized + ser::Serialize>(
+		self,
+		_name: &'static str,
+		value: &T,
+	) -> Result<Self::Ok, Self::Error> {
+		value.serialize(self)
+	}
+
+	fn serialize_newtype_variant<T: ?Sized + ser::Serialize>(
+		self,
+		_name: &'static str,
+		variant_index: u32,
+		_variant: &'static str,
+		value: &T,
+	) -> Result<Self::Ok, Self::Error> {
+		self.output.extend_from_slice(&variant_index.to_le_bytes());
+		value.serialize(self)
+	}
+
+	fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+		Ok(self)
+	}
+
+	fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+		Ok(self)
+	}
+
+	fn serialize_tuple_struct(
+		self,
+		name: &'static str,
+		len: usize,
+	) -> Result<Self::SerializeTupleStruct, Self::Error> {
+		if name == ALIGN_TOKEN && len != 0 {
+			let padding = (len - self.output.len() % len) % len;
+			self.output.resize(self.output.len() + padding, 0);
+		}
+		Ok(self)
+	}
+
+	fn serialize_tuple_variant(
+		self,
+		_name: &'static str,
+		variant_index: u32,
+		_variant: &'static str,
+		_len: usize,
+	) -> Result<Self::SerializeTupleVariant, Self::Error> {
+		self.output.extend_from_slice(&variant_index.to_le_bytes());
+		Ok(self)
+	}
+
+	fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+		Ok(self)
+	}
+
+	fn serialize_struct(
+		self,
+		_name: &'static str,
+		_len: usize,
+	) -> Result<Self::SerializeStruct, Self::Error> {
+		Ok(self)
+	}
+
+	fn serialize_struct_variant(
+		self,
+		_name: &'static str,
+		variant_index: u32,
+		_variant: &'static str,
+		_len: usize,
+	) -> Result<Self::SerializeStructVariant, Self::Error> {
+		self.output.extend_from_slice(&variant_index.to_le_bytes());
+		Ok(self)
+	}
+
+	fn is_human_readable(&self) -> bool {
+		false
+	}
+}
+
+impl ser::SerializeSeq for &mut Serializer {
+	type Ok = ();
+	type Error = Error;
+	fn serialize_element<T: ?Sized + ser::Serialize>(&mut self, value: &T) -> Result<(), Error> {
+		value.serialize(&mut **self)
+	}
+	fn end(self) -> Result<(), Error> {
+		Ok(())
+	}
+}
+impl ser::SerializeTuple for &mut Serializer {
+	type Ok = ();
+	type Error = Error;
+	fn serialize_element<T: ?Sized + ser::Serialize>(&mut self, value: &T) -> Result<(), Error> {
+		value.serialize(&mut **self)
+	}
+	fn end(self) -> Result<(), Error> {
+		Ok(())
+	}
+}
+impl ser::SerializeTupleStruct for &mut Serializer {
+	type Ok = ();
+	type Error = Error;
+	fn serialize_field<T: ?Sized + ser::Serialize>(&mut self, value: &T) -> Result<(), Error> {
+		value.serialize(&mut **self)
+	}
+	fn end(self) -> Result<(), Error> {
+		Ok(())
+	}
+}
+impl ser::SerializeTupleVariant for &mut Serializer {
+	type Ok = ();
+	type Error = Error;
+	fn serialize_field<T: ?Sized + ser::Serialize>(&mut self, value: &T) -> Result<(), Error> {
+		value.serialize(&mut **self)
+	}
+	fn end(self) -> Result<(), Error> {
+		Ok(())
+	}
+}
+impl ser::SerializeMap for &mut Serializer {
+	type Ok = ();
+	type Error = Error;
+	fn serialize_key<T: ?Sized + ser::Serialize>(&mut self, key: &T) -> Result<(), Error> {
+		key.serialize(&mut **self)
+	}
+	fn serialize_value<T: ?Sized + ser::Serialize>(&mut self, value: &T) -> Result<(), Error> {
+		value.serialize(&mut **self)
+	}
+	fn end(self) -> Result<(), Error> {
+		Ok(())
+	}
+}
+impl ser::SerializeStruct for &mut Serializer {
+	type Ok = ();
+	type Error = Error;
+	fn serialize_field<T: ?Sized + ser::Serialize>(
+		&mut self,
+		_key: &'static str,
+		value: &T,
+	) -> Result<(), Error> {
+		value.serialize(&mut **self)
+	}
+	fn end(self) -> Result<(), Error> {
+		Ok(())
+	}
+}
+impl ser::SerializeStructVariant for &mut Serializer {
+	type Ok = ();
+	type Error = Error;
+	fn serialize_field<T: ?Sized + ser::Serialize>(
+		&mut self,
+		_key: &'static str,
+		value: &T,
+	) -> Result<(), Error> {
+		value.serialize(&mut **self)
+	}
+	fn end(self) -> Result<(), Error> {
+		Ok(())
+	}
+}
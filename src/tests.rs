@@ -0,0 +1,2768 @@
+//! Round trips through the crate's own [`raw`] format, with known bytes.
+
+use super::*;
+use alloc::borrow::Cow;
+#[cfg(feature = "std")]
+use std::{
+	any::Any,
+	collections::HashMap,
+	time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Deserializes all of `input` as laid out by `seeder`.
+fn read<'de, T, Seeder: DeSeeder<'de, T>>(
+	seeder: Seeder,
+	input: &'de [u8],
+) -> Result<T, raw::Error> {
+	raw::from_slice(seeder.seed(), input)
+}
+
+/// Serializes `value` as laid out by `seeder`.
+fn write<T, Seeder: SerSeeder<T>>(seeder: &Seeder, value: &T) -> Result<Vec<u8>, raw::Error> {
+	raw::to_vec(&*seeder.seeded(value))
+}
+
+/// Asserts that `result` failed with a message containing `expected`.
+#[track_caller]
+fn assert_error<T: Debug>(result: Result<T, raw::Error>, expected: &str) {
+	let error = result.expect_err("expected an error").to_string();
+	assert!(
+		error.contains(expected),
+		"expected an error containing {:?}, but got {:?}",
+		expected,
+		error
+	);
+}
+
+type DateTime = (u16, u8, u8, u8, u8, u8);
+
+#[test]
+fn dos_date_time() {
+	let bytes = [0x2D, 0x6C, 0x21, 0x4A];
+	let value: DateTime = read(DosDateTime(LittleEndian), &bytes).unwrap();
+	assert_eq!(value, (2017, 1, 1, 13, 33, 26));
+	assert_eq!(write(&DosDateTime(LittleEndian), &value).unwrap(), bytes);
+}
+
+#[test]
+fn dos_date_time_epoch_and_granularity() {
+	let epoch: DateTime = read(DosDateTime(BigEndian), &[0x00, 0x21, 0x00, 0x00]).unwrap();
+	assert_eq!(epoch, (1980, 1, 1, 0, 0, 0));
+	// The stored seconds field counts 2-second steps.
+	let last: DateTime = read(DosDateTime(BigEndian), &[0x00, 0x21, 0x00, 0x1D]).unwrap();
+	assert_eq!(last, (1980, 1, 1, 0, 0, 58));
+	assert_error(
+		write(&DosDateTime(BigEndian), &(1980, 1, 1, 0, 0, 59)),
+		"2-second granularity, but second was 59",
+	);
+	assert_error(
+		write(&DosDateTime(BigEndian), &(1979, 12, 31, 0, 0, 0)),
+		"is not representable as DOS date/time",
+	);
+	assert_error(
+		read::<DateTime, _>(DosDateTime(BigEndian), &[0x00, 0x00, 0x00, 0x00]),
+		"DOS date/time",
+	);
+}
+
+#[test]
+fn six_bit() {
+	// 111111 000000 101010 010101
+	let bytes = [4, 0, 0b1111_1100, 0b0000_1010, 0b1001_0101];
+	let units: Vec<u8> = read(SixBit(TryAsU16(LittleEndian)), &bytes).unwrap();
+	assert_eq!(units, [0x3F, 0x00, 0x2A, 0x15]);
+	assert_eq!(
+		write(&SixBit(TryAsU16(LittleEndian)), &units).unwrap(),
+		bytes
+	);
+
+	// A partial trailing byte is padded with zero bits.
+	let bytes = [1, 0, 0b1010_1000];
+	assert_eq!(
+		read::<Vec<u8>, _>(SixBit(TryAsU16(LittleEndian)), &bytes).unwrap(),
+		[0x2A]
+	);
+	assert_eq!(
+		write(&SixBit(TryAsU16(LittleEndian)), &vec![0x2A]).unwrap(),
+		bytes
+	);
+}
+
+#[test]
+fn six_bit_errors() {
+	assert_error(
+		write(&SixBit(TryAsU16(LittleEndian)), &vec![0x40]),
+		"Tried to serialise 64 as SixBit unit",
+	);
+	assert_error(
+		read::<Vec<u8>, _>(
+			SixBit(TryAsU64(LittleEndian)),
+			&(usize::MAX as u64).to_le_bytes(),
+		),
+		"overflows",
+	);
+}
+
+#[test]
+fn big_endian() {
+	let value: u32 = read(BigEndian, &[0x12, 0x34, 0x56, 0x78]).unwrap();
+	assert_eq!(value, 0x1234_5678);
+	assert_eq!(write(&BigEndian, &value).unwrap(), [0x12, 0x34, 0x56, 0x78]);
+}
+
+#[test]
+fn bom_endian() {
+	/// Reads a byte order mark, then a `u32` in the byte order it declares.
+	fn read_marked(input: &[u8]) -> Result<(BomEndian, u32), raw::Error> {
+		let mut deserializer = raw::Deserializer::new(input);
+		let endian = Bom.deserialize(&mut deserializer)?;
+		let body = DeSeeder::<u32>::seed(endian).deserialize(&mut deserializer)?;
+		deserializer.end()?;
+		Ok((endian, body))
+	}
+
+	let little = read_marked(b"II\x78\x56\x34\x12").unwrap();
+	let big = read_marked(b"MM\x12\x34\x56\x78").unwrap();
+	assert_eq!(little, (BomEndian::Little, 0x1234_5678));
+	assert_eq!(big, (BomEndian::Big, 0x1234_5678));
+	for &(endian, bytes) in &[
+		(little.0, b"II\x78\x56\x34\x12"),
+		(big.0, b"MM\x12\x34\x56\x78"),
+	] {
+		let mut written = write(&Bom, &endian).unwrap();
+		written.extend(write(&endian, &0x1234_5678_u32).unwrap());
+		assert_eq!(&written, bytes);
+	}
+
+	assert_error(
+		read_marked(b"XX\x12\x34\x56\x78"),
+		"byte order mark II or MM",
+	);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn indexed_seq() {
+	let seeder = IndexedSeq(
+		|item: &u16| *item,
+		LengthPrefixed(TryAsU16(LittleEndian), LittleEndian),
+	);
+	let bytes = [3, 0, 30, 0, 10, 0, 20, 0];
+	let (items, index): (Vec<u16>, HashMap<u16, usize>) = read(seeder, &bytes).unwrap();
+	assert_eq!(items, [30, 10, 20]);
+	for (i, item) in items.iter().enumerate() {
+		assert_eq!(index[item], i);
+	}
+	assert_eq!(index.len(), 3);
+	assert_eq!(write(&seeder, &(items, index)).unwrap(), bytes);
+
+	assert_error(
+		read::<(Vec<u16>, HashMap<u16, usize>), _>(seeder, &[2, 0, 7, 0, 7, 0]),
+		"Duplicate key in IndexedSeq at index 1",
+	);
+}
+
+#[test]
+fn if_remaining() {
+	/// Reads a `u8` header, then an optional trailing `u16`.
+	fn read_record(input: &[u8]) -> Result<(u8, Option<u16>), raw::Error> {
+		let mut deserializer = raw::Deserializer::new(input);
+		let header = DeSeeder::<u8>::seed(SerdeLike).deserialize(&mut deserializer)?;
+		let trailer = IfRemaining(LittleEndian)
+			.seed()
+			.deserialize(&mut deserializer)?;
+		deserializer.end()?;
+		Ok((header, trailer))
+	}
+
+	assert_eq!(read_record(&[7, 0x34, 0x12]).unwrap(), (7, Some(0x1234)));
+	assert_eq!(read_record(&[7]).unwrap(), (7, None));
+	assert_eq!(
+		write(&IfRemaining(LittleEndian), &Some(0x1234_u16)).unwrap(),
+		[0x34, 0x12]
+	);
+	assert_eq!(write(&IfRemaining(LittleEndian), &None::<u16>).unwrap(), []);
+}
+
+#[test]
+fn utf16_length_prefixed() {
+	let seeder = Utf16LengthPrefixed(TryAsU16(LittleEndian), LittleEndian);
+	// Two characters, but three code units.
+	let bytes = [3, 0, 0x61, 0x00, 0x3D, 0xD8, 0x00, 0xDE];
+	let value: String = read(seeder, &bytes).unwrap();
+	assert_eq!(value, "a\u{1F600}");
+	assert_eq!(value.chars().count(), 2);
+	assert_eq!(write(&seeder, &value).unwrap(), bytes);
+
+	assert_error(
+		read::<String, _>(seeder, &[1, 0, 0x3D, 0xD8]),
+		"lone surrogate",
+	);
+}
+
+#[test]
+fn head_then_rest() {
+	let seeder = HeadThenRest(LittleEndian, SerdeLike);
+	let bytes = [0x78, 0x56, 0x34, 0x12, 1, 2, 3];
+	let value: (u32, Vec<u8>) = read(seeder, &bytes).unwrap();
+	assert_eq!(value, (0x1234_5678, vec![1, 2, 3]));
+	assert_eq!(write(&seeder, &value).unwrap(), bytes);
+
+	assert_eq!(
+		read::<(u32, Vec<u8>), _>(seeder, &bytes[..4]).unwrap(),
+		(0x1234_5678, vec![])
+	);
+	assert_error(read::<(u32, Vec<u8>), _>(seeder, &[]), "Seq with head");
+}
+
+#[test]
+fn packed_tag_value() {
+	let seeder = PackedTagValue::<2, _>(BigEndian);
+	let value: (u16, u16) = read(seeder, &[0x92, 0x34]).unwrap();
+	assert_eq!(value, (0b10, 0x1234));
+	assert_eq!(write(&seeder, &value).unwrap(), [0x92, 0x34]);
+
+	assert_error(
+		write(&seeder, &(4_u16, 0)),
+		"Tried to serialise PackedTagValue (4, 0) with 2 tag and 14 value bits",
+	);
+	assert_error(
+		write(&seeder, &(0_u16, 0x4000)),
+		"Tried to serialise PackedTagValue (0, 16384) with 2 tag and 14 value bits",
+	);
+}
+
+#[test]
+fn offset_tracked_seq() {
+	let seeder = OffsetTrackedSeq(LengthPrefixed(TryAsU16(LittleEndian), SerdeLike));
+	let bytes = [1, 0, 1, 3, 0, 2, 3, 4, 0, 0];
+	let items: Vec<(usize, Vec<u8>)> = read(seeder, &bytes).unwrap();
+	assert_eq!(items, [(0, vec![1]), (3, vec![2, 3, 4]), (8, vec![])]);
+	assert_eq!(write(&seeder, &items).unwrap(), bytes);
+
+	// Offsets count from the start of the input, not the sequence.
+	let mut deserializer = raw::Deserializer::new(&[0xFF, 1, 0, 1]);
+	DeSeeder::<u8>::seed(SerdeLike)
+		.deserialize(&mut deserializer)
+		.unwrap();
+	let items: Vec<(usize, Vec<u8>)> = seeder.seed().deserialize(&mut deserializer).unwrap();
+	assert_eq!(items, [(1, vec![1])]);
+
+	assert!(read::<Vec<(usize, Vec<u8>)>, _>(seeder, &[2, 0, 1]).is_err());
+}
+
+#[cfg(feature = "encoding")]
+#[test]
+fn fixed_string_array() {
+	let seeder = FixedStringArray::<3, 8>::new();
+	let bytes = b"abc\0\0\0\0\0defghijk\xE9\0\0\0\0\0\0\0";
+	let names: Vec<String> = read(seeder, bytes).unwrap();
+	assert_eq!(names, ["abc", "defghijk", "\u{E9}"]);
+	assert_eq!(write(&seeder, &names).unwrap(), bytes);
+
+	let utf8 = FixedStringArray::<2, 4, Utf8Encoding>::new();
+	let bytes = b"\xC3\xA9\0\0abcd";
+	let names: Vec<String> = read(utf8, bytes).unwrap();
+	assert_eq!(names, ["\u{E9}", "abcd"]);
+	assert_eq!(write(&utf8, &names).unwrap(), bytes);
+}
+
+#[cfg(feature = "encoding")]
+#[test]
+fn fixed_string_array_errors() {
+	let seeder = FixedStringArray::<2, 4>::new();
+	assert_error(
+		write(&seeder, &vec!["a".to_string()]),
+		"Tried to serialise FixedStringArray::<2, 4> from a .len = 1",
+	);
+	assert_error(
+		write(&seeder, &vec!["a".to_string(), "abcde".to_string()]),
+		"Encoded string of 5 bytes doesn't fit FixedStringArray slot of 4",
+	);
+	assert_error(
+		read::<Vec<String>, _>(FixedStringArray::<1, 2, Utf8Encoding>::new(), b"\xFF\0"),
+		"Invalid",
+	);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn registered() {
+	let mut registry = Registry::new();
+	registry
+		.register::<u32, _>(1_u8, LittleEndian)
+		.register::<u16, _>(2, BigEndian);
+	let seeder = Registered(SerdeLike, &registry);
+
+	let bytes = [1, 0x78, 0x56, 0x34, 0x12];
+	let value: Box<dyn Any> = read(seeder, &bytes).unwrap();
+	assert_eq!(value.downcast_ref::<u32>(), Some(&0x1234_5678));
+	assert_eq!(write(&seeder, &value).unwrap(), bytes);
+
+	let bytes = [2, 0x12, 0x34];
+	let value: Box<dyn Any> = read(seeder, &bytes).unwrap();
+	assert_eq!(value.downcast_ref::<u16>(), Some(&0x1234));
+	assert_eq!(write(&seeder, &value).unwrap(), bytes);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn registered_errors() {
+	let mut registry = Registry::new();
+	registry.register::<u16, _>(1_u8, LittleEndian);
+	let seeder = Registered(SerdeLike, &registry);
+	assert_error(
+		read::<Box<dyn Any>, _>(seeder, &[3, 0, 0]),
+		"Unregistered tag 3",
+	);
+	assert_error(
+		write(&seeder, &(Box::new(0_u32) as Box<dyn Any>)),
+		"Tried to serialise unregistered type",
+	);
+
+	// Re-registering a tag replaces its layout.
+	registry.register::<u16, _>(1, BigEndian);
+	let seeder = Registered(SerdeLike, &registry);
+	let value: Box<dyn Any> = read(seeder, &[1, 0x12, 0x34]).unwrap();
+	assert_eq!(value.downcast_ref::<u16>(), Some(&0x1234));
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[should_panic(expected = "already registered under tag 1")]
+fn registered_type_under_two_tags() {
+	Registry::new()
+		.register::<u16, _>(1_u8, LittleEndian)
+		.register::<u16, _>(2, BigEndian);
+}
+
+#[test]
+fn sparse_zero_rle() {
+	let seeder = SparseZeroRle(16, TryAsU16(LittleEndian), SerdeLike);
+	let bytes = [2, 0, 5, 1, 0, 7];
+	let dense: Vec<u8> = read(seeder, &bytes).unwrap();
+	assert_eq!(dense, [0, 0, 5, 0, 7]);
+	assert_eq!(write(&seeder, &dense).unwrap(), bytes);
+
+	// Trailing zeros end with a zero value.
+	let bytes = [0, 0, 5, 1, 0, 0];
+	assert_eq!(read::<Vec<u8>, _>(seeder, &bytes).unwrap(), [5, 0, 0]);
+	assert_eq!(write(&seeder, &vec![5_u8, 0, 0]).unwrap(), bytes);
+}
+
+#[test]
+fn sparse_zero_rle_errors() {
+	let seeder = SparseZeroRle(4, TryAsU64(LittleEndian), SerdeLike);
+	assert_error(
+		read::<Vec<u8>, _>(
+			seeder,
+			&[2, 0, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 0, 7],
+		),
+		"SparseZeroRle run of 1 zeros after 3 items exceeds maximum length 4",
+	);
+	let mut huge_run = (usize::MAX as u64).to_le_bytes().to_vec();
+	huge_run.push(1);
+	assert_error(
+		read::<Vec<u8>, _>(seeder, &huge_run),
+		"exceeds maximum length 4",
+	);
+	assert_error(
+		write(&seeder, &vec![0_u8; 5]),
+		"Tried to serialise 5 items as SparseZeroRle with maximum length 4",
+	);
+}
+
+#[test]
+fn versioned_magic() {
+	const MAGICS: &[(&[u8], u8)] = &[(b"FMT1", 1), (b"FMT2", 2)];
+	let seeder = VersionedMagic(MAGICS);
+	assert_eq!(read::<u8, _>(seeder, b"FMT1").unwrap(), 1);
+	assert_eq!(read::<u8, _>(seeder, b"FMT2").unwrap(), 2);
+	assert_eq!(write(&seeder, &1).unwrap(), b"FMT1");
+	assert_eq!(write(&seeder, &2).unwrap(), b"FMT2");
+}
+
+#[test]
+fn versioned_magic_errors() {
+	let seeder = VersionedMagic::<u8>(&[(b"FMT1", 1), (b"FMT2", 2)]);
+	assert_error(read::<u8, _>(seeder, b"FMT3"), "one of");
+	assert_error(write(&seeder, &3), "No magic for version 3");
+	assert_error(
+		read::<u8, _>(VersionedMagic::<u8>(&[(b"FMT1", 1), (b"FMT", 2)]), b"FMT1"),
+		"VersionedMagic magics must all have the same length",
+	);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn unix_nanos() {
+	let nanos: u64 = 1_600_000_000_123_456_789;
+	let instant = UNIX_EPOCH + Duration::new(1_600_000_000, 123_456_789);
+	assert_eq!(
+		read::<SystemTime, _>(UnixNanos(LittleEndian), &nanos.to_le_bytes()).unwrap(),
+		instant
+	);
+	assert_eq!(
+		write(&UnixNanos(LittleEndian), &instant).unwrap(),
+		nanos.to_le_bytes()
+	);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn unix_nanos_errors() {
+	let far_future = UNIX_EPOCH + Duration::from_secs(u64::MAX / 1_000_000_000 + 1);
+	assert_error(write(&UnixNanos(LittleEndian), &far_future), "out of range");
+	let before_epoch = UNIX_EPOCH - Duration::from_secs(1);
+	assert!(write(&UnixNanos(LittleEndian), &before_epoch).is_err());
+}
+
+#[test]
+fn capacity_prefixed() {
+	let seeder = CapacityPrefixed(TryAsU32(LittleEndian), LittleEndian, 4);
+	let bytes = [
+		4, 0, 0, 0, 2, 0, 0, 0, //
+		1, 0, 0, 0, 2, 0, 0, 0, //
+		0, 0, 0, 0, 0, 0, 0, 0,
+	];
+	let value: (usize, Vec<u32>) = read(seeder, &bytes).unwrap();
+	assert_eq!(value, (4, vec![1, 2]));
+	assert_eq!(write(&seeder, &value).unwrap(), bytes);
+}
+
+#[test]
+fn capacity_prefixed_errors() {
+	let seeder = CapacityPrefixed(TryAsU32(LittleEndian), LittleEndian, usize::MAX);
+	assert_error(write(&seeder, &(3, vec![1_u32])), "2 unused slots of");
+	let mut bytes = vec![3, 0, 0, 0, 1, 0, 0, 0];
+	bytes.extend_from_slice(&1_u32.to_le_bytes());
+	assert_error(
+		read::<(usize, Vec<u32>), _>(seeder, &bytes),
+		"2 unused slots of",
+	);
+	assert_error(
+		write(
+			&CapacityPrefixed(TryAsU32(LittleEndian), LittleEndian, 4),
+			&(1, vec![1_u32, 2]),
+		),
+		"Tried to serialise 2 items with capacity 1",
+	);
+	assert_error(
+		read::<(usize, Vec<u32>), _>(
+			CapacityPrefixed(TryAsU32(LittleEndian), LittleEndian, 4),
+			&[1, 0, 0, 0, 2, 0, 0, 0],
+		),
+		"Item count 2 exceeds capacity 1",
+	);
+}
+
+#[test]
+fn ascii_signed_int() {
+	let value: i32 = read(AsciiSignedInt(SerdeLike, false), b"-42").unwrap();
+	assert_eq!(value, -42);
+	assert_eq!(
+		write(&AsciiSignedInt(SerdeLike, false), &value).unwrap(),
+		b"-42"
+	);
+	assert_eq!(
+		read::<i32, _>(AsciiSignedInt(SerdeLike, false), b"+42").unwrap(),
+		42
+	);
+	assert_eq!(
+		write(&AsciiSignedInt(SerdeLike, true), &42).unwrap(),
+		b"+42"
+	);
+	assert_eq!(
+		write(&AsciiSignedInt(SerdeLike, false), &42).unwrap(),
+		b"42"
+	);
+}
+
+#[test]
+fn ascii_signed_int_errors() {
+	for &text in &[&b"4 2"[..], b"-", b"", b"0x2A"] {
+		assert_error(
+			read::<i32, _>(AsciiSignedInt(SerdeLike, false), text),
+			"ASCII decimal integer with optional sign",
+		);
+	}
+	assert_error(
+		read::<i8, _>(AsciiSignedInt(SerdeLike, false), b"128"),
+		"number too large",
+	);
+}
+
+#[test]
+fn count_from_size() {
+	let bytes = [1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0];
+	let seeder = CountFromSize::<4, _>(12, LittleEndian);
+	let items: Vec<u32> = read(seeder, &bytes).unwrap();
+	assert_eq!(items, vec![1, 2, 3]);
+	assert_eq!(write(&seeder, &items).unwrap(), bytes);
+}
+
+#[test]
+fn count_from_size_errors() {
+	assert_error(
+		read::<Vec<u32>, _>(CountFromSize::<4, _>(13, LittleEndian), &[0; 13]),
+		"byte size divisible by 4",
+	);
+	assert_error(
+		write(&CountFromSize::<4, _>(12, LittleEndian), &vec![1_u32, 2]),
+		"Tried to serialise CountFromSize::<4, _>(12, _) from a .len = 2",
+	);
+	assert_error(
+		write(
+			&CountFromSize::<{ usize::MAX }, _>(0, SerdeLike),
+			&vec![0_u8; 2],
+		),
+		&format!("Byte size of 2 {}-byte items overflows", usize::MAX),
+	);
+}
+
+#[cfg(feature = "num_enum")]
+#[derive(Debug, Copy, Clone, PartialEq, num_enum::TryFromPrimitive, num_enum::IntoPrimitive)]
+#[repr(u32)]
+enum Kind {
+	Directory = 1,
+	File = 2,
+}
+
+#[cfg(feature = "num_enum")]
+#[test]
+fn enum_code() {
+	let kind: Kind = read(EnumCode(BigEndian), &[0, 0, 0, 2]).unwrap();
+	assert_eq!(kind, Kind::File);
+	assert_eq!(
+		write(&EnumCode(BigEndian), &Kind::Directory).unwrap(),
+		[0, 0, 0, 1]
+	);
+	assert_error(
+		read::<Kind, _>(EnumCode(BigEndian), &[0, 0, 0, 3]),
+		"No discriminant in enum `Kind` matches the value `3`",
+	);
+}
+
+#[test]
+fn budget() {
+	let seeder = TupleN(
+		2,
+		Budget(4, LengthPrefixed(TryAsU16(LittleEndian), SerdeLike)),
+	);
+	let bytes = [2, 0, b'a', b'b', 1, 0, b'c'];
+	let items: Vec<Vec<u8>> = read(seeder, &bytes).unwrap();
+	assert_eq!(items, [b"ab".to_vec(), b"c".to_vec()]);
+	assert_eq!(write(&seeder, &items).unwrap(), bytes);
+}
+
+#[test]
+fn budget_errors() {
+	let seeder = TupleN(
+		2,
+		Budget(4, LengthPrefixed(TryAsU16(LittleEndian), SerdeLike)),
+	);
+	// The second element claims 3 bytes, which fit the input but not its budget.
+	assert_error(
+		read::<Vec<Vec<u8>>, _>(seeder, &[1, 0, b'a', 3, 0, b'x', b'y', b'z']),
+		"(within Budget of 4 bytes)",
+	);
+}
+
+#[test]
+fn header_then_aligned_array() {
+	let seeder = HeaderThenAlignedArray::<4, _, _, _>(
+		Tuple::of(LittleEndian),
+		TryAsU32(LittleEndian),
+		LittleEndian,
+	);
+	let bytes = [
+		1, 0, 2, 0, 3, 0, // header
+		3, 0, 0, 0, // count
+		0, 0, // padding
+		10, 0, 0, 0, 20, 0, 0, 0, 30, 0, 0, 0,
+	];
+	let value: ([u16; 3], Vec<u32>) = read(seeder, &bytes).unwrap();
+	assert_eq!(value, ([1, 2, 3], vec![10, 20, 30]));
+	assert_eq!(write(&seeder, &value).unwrap(), bytes);
+	assert!(read::<([u16; 3], Vec<u32>), _>(seeder, &bytes[..9]).is_err());
+}
+
+#[test]
+fn lut() {
+	let palette: Vec<[u8; 3]> = (0..=255_u8).map(|i| [i, !i, i / 2]).collect();
+	let seeder = Lut::<_, u8, _>::new(LittleEndian, &palette);
+	for &index in &[0_u8, 1, 128, 255] {
+		let colour: [u8; 3] = read(seeder.clone(), &[index]).unwrap();
+		assert_eq!(colour, [index, !index, index / 2]);
+		assert_eq!(write(&seeder, &colour).unwrap(), [index]);
+	}
+
+	let duplicates = Lut::<_, u8, _>::new(LittleEndian, &[5_u16, 7, 5]);
+	assert_eq!(write(&duplicates, &5).unwrap(), [0]);
+	assert_eq!(write(&duplicates, &7).unwrap(), [1]);
+}
+
+#[test]
+fn lut_errors() {
+	let table: Vec<u16> = (0..300).collect();
+	assert_error(
+		read::<u16, _>(Lut::<_, u8, _>::new(LittleEndian, &table[..16]), &[20]),
+		"Lut index 20 out of range for 16 entries",
+	);
+	assert_error(
+		write(&Lut::<_, u8, _>::new(LittleEndian, &table[..16]), &20),
+		"20 is not in the Lut",
+	);
+	assert_error(
+		write(&Lut::<_, u8, _>::new(LittleEndian, &table), &299),
+		"Lut index 299 doesn't fit the index type",
+	);
+}
+
+#[test]
+fn raw_region() {
+	let bytes = [3, 0, b'a', b'b', b'c', 0xFF];
+	let mut deserializer = raw::Deserializer::new(&bytes);
+	let region: &[u8] = RawRegion(TryAsU16(LittleEndian))
+		.seed()
+		.deserialize(&mut deserializer)
+		.unwrap();
+	assert_eq!(region, b"abc");
+	assert_eq!(region.as_ptr(), bytes[2..].as_ptr());
+	assert_eq!(deserializer.remaining(), [0xFF]);
+	assert_eq!(
+		write(&RawRegion(TryAsU16(LittleEndian)), &&b"abc"[..]).unwrap(),
+		bytes[..5]
+	);
+
+	assert_error(
+		read::<&[u8], _>(RawRegion(TryAsU16(LittleEndian)), &[4, 0, b'a']),
+		"invalid length 1, expected 4 bytes",
+	);
+}
+
+#[test]
+fn dense_indexed() {
+	let seeder = DenseIndexed(
+		LengthPrefixed(TryAsU16(LittleEndian), Tuple::of(SerdeLike)),
+		|&[index, _]: &[u8; 2]| index as usize,
+	);
+	let bytes = [3, 0, 0, b'a', 1, b'b', 2, b'c'];
+	let items: Vec<[u8; 2]> = read(seeder, &bytes).unwrap();
+	assert_eq!(items, [[0, b'a'], [1, b'b'], [2, b'c']]);
+	assert_eq!(write(&seeder, &items).unwrap(), bytes);
+
+	assert_error(
+		read::<Vec<[u8; 2]>, _>(seeder, &[3, 0, 0, b'a', 1, b'b', 3, b'c']),
+		"Expected index 2 in DenseIndexed but found 3",
+	);
+}
+
+#[test]
+fn binary_angle() {
+	let degrees = BinaryAngle::<_, u16>::degrees(LittleEndian);
+	let angle: f64 = read(degrees, &[0x00, 0x40]).unwrap();
+	assert_eq!(angle, 90.);
+	assert_eq!(write(&degrees, &angle).unwrap(), [0x00, 0x40]);
+	assert_eq!(write(&degrees, &-90.).unwrap(), [0x00, 0xC0]);
+	assert_eq!(write(&degrees, &360.).unwrap(), [0x00, 0x00]);
+	// Just below a full turn rounds up and wraps around.
+	assert_eq!(write(&degrees, &359.999).unwrap(), [0x00, 0x00]);
+
+	let radians = BinaryAngle::<_, u8>::radians(LittleEndian);
+	let angle: f64 = read(radians, &[0x80]).unwrap();
+	assert_eq!(angle, core::f64::consts::PI);
+	assert_eq!(write(&radians, &angle).unwrap(), [0x80]);
+}
+
+#[test]
+fn binary_angle_errors() {
+	assert_error(
+		write(&BinaryAngle::<_, u16>::degrees(LittleEndian), &f64::NAN),
+		"Can't serialize non-finite angle NaN as BinaryAngle",
+	);
+}
+
+#[test]
+fn opcode_stream() {
+	// Opcode n is followed by n - 1 operand bytes.
+	let seeder = OpcodeStream(
+		SerdeLike,
+		|&opcode: &u8| TupleN(opcode as usize - 1, SerdeLike),
+		0xFF,
+	);
+	let bytes = [1, 2, 0x10, 3, 0x20, 0x30, 0xFF];
+	let instructions: Vec<(u8, Vec<u8>)> = read(seeder, &bytes).unwrap();
+	assert_eq!(
+		instructions,
+		[(1, vec![]), (2, vec![0x10]), (3, vec![0x20, 0x30])]
+	);
+	assert_eq!(write(&seeder, &instructions).unwrap(), bytes);
+
+	assert_error(
+		read::<Vec<(u8, Vec<u8>)>, _>(seeder, &[1, 2, 0x10]),
+		"OpcodeStream ended without end opcode",
+	);
+	assert_error(
+		write(&seeder, &vec![(0xFF, Vec::<u8>::new())]),
+		"Can't serialize end opcode as OpcodeStream item",
+	);
+}
+
+#[test]
+fn length_prefixed() {
+	let seeder = LengthPrefixed(TryAsU32(LittleEndian), LittleEndian);
+	let bytes = [2, 0, 0, 0, 0x34, 0x12, 0x78, 0x56];
+	let items: Vec<u16> = read(seeder, &bytes).unwrap();
+	assert_eq!(items, [0x1234, 0x5678]);
+	assert_eq!(write(&seeder, &items).unwrap(), bytes);
+
+	assert!(read::<Vec<u16>, _>(seeder, &bytes[..6]).is_err());
+}
+
+#[test]
+fn checksummed_seq() {
+	let bytes = [0x34, 0x12, 0xFF, 0xFF, 0x01, 0x00];
+	let (items, checksum): (Vec<u16>, u32) = read(ChecksummedSeq(LittleEndian), &bytes).unwrap();
+	assert_eq!(items, [0x1234, 0xFFFF, 0x0001]);
+	let reference: u32 = bytes.iter().map(|&byte| byte as u32).sum();
+	assert_eq!(checksum, reference);
+	assert_eq!(
+		write(&ChecksummedSeq(LittleEndian), &(items, checksum)).unwrap(),
+		bytes
+	);
+
+	let (items, checksum): (Vec<u16>, u32) = read(ChecksummedSeq(LittleEndian), &[]).unwrap();
+	assert_eq!((items.len(), checksum), (0, 0));
+	// A trailing partial item fails to read.
+	assert!(read::<(Vec<u16>, u32), _>(ChecksummedSeq(LittleEndian), &bytes[..5]).is_err());
+}
+
+#[test]
+fn aligned_value() {
+	let seeder = AlignedValue::<8, _>(LittleEndian);
+	let value: u32 = read(seeder, &[16, 0, 0, 0]).unwrap();
+	assert_eq!(value, 16);
+	assert_eq!(write(&seeder, &value).unwrap(), [16, 0, 0, 0]);
+
+	assert_error(
+		read::<u32, _>(seeder, &[17, 0, 0, 0]),
+		"Expected a multiple of 8 but found 17",
+	);
+	assert_error(
+		write(&seeder, &17_u32),
+		"Can't serialize 17 as multiple of 8",
+	);
+	assert_error(
+		write(&seeder, &-8_i32),
+		"Can't serialize -8 as multiple of 8",
+	);
+}
+
+#[test]
+fn chunks() {
+	let seeder = Chunks(TryAsU32(LittleEndian), |chunk_type: &[u8; 4]| {
+		TupleN(if chunk_type == b"HEAD" { 2 } else { 3 }, SerdeLike)
+	});
+	let mut bytes = b"HEAD\x02\0\0\0".to_vec();
+	bytes.extend_from_slice(&[1, 2]);
+	bytes.extend_from_slice(b"DATA\x03\0\0\0abc");
+	let chunks: Vec<([u8; 4], Vec<u8>)> = read(seeder, &bytes).unwrap();
+	assert_eq!(
+		chunks,
+		[(*b"HEAD", vec![1, 2]), (*b"DATA", b"abc".to_vec())]
+	);
+	assert_eq!(write(&seeder, &chunks).unwrap(), bytes);
+}
+
+#[test]
+fn chunks_errors() {
+	let seeder = Chunks(TryAsU32(LittleEndian), |chunk_type: &[u8; 4]| {
+		TupleN(if chunk_type == b"HEAD" { 2 } else { 3 }, SerdeLike)
+	});
+	assert_error(
+		read::<Vec<([u8; 4], Vec<u8>)>, _>(seeder, b"DATA\x05\0\0\0abc"),
+		"Chunk \"DATA\" is 5 bytes long, but only 3 remain",
+	);
+	assert_error(
+		read::<Vec<([u8; 4], Vec<u8>)>, _>(seeder, b"HEAD\x03\0\0\0abc"),
+		"(in chunk \"HEAD\")",
+	);
+	assert!(write(&seeder, &vec![(*b"HEAD", vec![1_u8, 2, 3])]).is_err());
+}
+
+#[test]
+fn flag_and_value() {
+	let high = FlagAndValue::<7>(FlagBit::High);
+	let value: (bool, u8) = read(high, &[0x80 | 100]).unwrap();
+	assert_eq!(value, (true, 100));
+	assert_eq!(write(&high, &value).unwrap(), [0x80 | 100]);
+
+	let low = FlagAndValue::<3>(FlagBit::Low);
+	let value: (bool, u8) = read(low, &[0b1011]).unwrap();
+	assert_eq!(value, (true, 0b101));
+	assert_eq!(write(&low, &value).unwrap(), [0b1011]);
+}
+
+#[test]
+fn flag_and_value_errors() {
+	let seeder = FlagAndValue::<3>(FlagBit::High);
+	assert_error(
+		read::<(bool, u8), _>(seeder, &[0x88]),
+		"expected flag and 3-bit value",
+	);
+	assert_error(
+		write(&seeder, &(false, 8)),
+		"Can't fit 8 into 3 value bits of FlagAndValue",
+	);
+}
+
+#[test]
+fn compress_runs() {
+	let seeder = CompressRuns(LengthPrefixed(TryAsU16(LittleEndian), SerdeLike));
+	let bytes = [4, 0, 5, 5, 5, 7];
+	let runs: Vec<(usize, u8)> = read(seeder, &bytes).unwrap();
+	assert_eq!(runs, [(3, 5), (1, 7)]);
+	assert_eq!(write(&seeder, &runs).unwrap(), bytes);
+	assert_eq!(read::<Vec<(usize, u8)>, _>(seeder, &[0, 0]).unwrap(), []);
+}
+
+#[test]
+fn length_prefixed_utf8() {
+	let seeder = LengthPrefixedUtf8(TryAsU16(LittleEndian), 3);
+	let bytes = b"\x06\0\xC3\xA4\xC3\xB6\xC3\xBC";
+	let text: String = read(seeder, bytes).unwrap();
+	assert_eq!(text, "äöü");
+	assert_eq!(write(&seeder, &text).unwrap(), bytes);
+}
+
+#[test]
+fn length_prefixed_utf8_errors() {
+	// 4 chars in only 5 bytes.
+	let seeder = LengthPrefixedUtf8(TryAsU16(LittleEndian), 3);
+	assert_error(
+		read::<String, _>(seeder, b"\x05\0\xC3\xA4abc"),
+		"invalid length 4, expected at most 3 chars",
+	);
+	assert_error(
+		write(&seeder, &"\u{e4}abc".to_string()),
+		"Can't serialize 4 chars as LengthPrefixedUtf8 of at most 3",
+	);
+	assert_error(read::<String, _>(seeder, b"\x01\0\xFF"), "invalid utf-8");
+}
+
+#[test]
+fn finite() {
+	let seeder = Finite(IEEE754(LittleEndian));
+	let bytes = 1.5_f32.to_le_bytes();
+	let value: f32 = read(seeder, &bytes).unwrap();
+	assert_eq!(value, 1.5);
+	assert_eq!(write(&seeder, &value).unwrap(), bytes);
+
+	let value: Option<f32> = read(seeder, &bytes).unwrap();
+	assert_eq!(value, Some(1.5));
+	assert_eq!(write(&seeder, &value).unwrap(), bytes);
+	let value: Option<f32> = read(seeder, &f32::INFINITY.to_le_bytes()).unwrap();
+	assert_eq!(value, None);
+	let written = write(&seeder, &value).unwrap();
+	assert!(read::<f32, IEEE754<_>>(IEEE754(LittleEndian), &written)
+		.unwrap()
+		.is_nan());
+}
+
+#[test]
+fn finite_errors() {
+	let seeder = Finite(IEEE754(LittleEndian));
+	assert_error(
+		read::<f32, _>(seeder, &f32::NAN.to_le_bytes()),
+		"expected finite number",
+	);
+	assert_error(
+		read::<f64, _>(seeder, &f64::NEG_INFINITY.to_le_bytes()),
+		"expected finite number",
+	);
+	assert_error(
+		write(&seeder, &f32::INFINITY),
+		"Can't serialize inf as Finite",
+	);
+	assert_error(
+		write(&seeder, &Some(f64::NAN)),
+		"Can't serialize NaN as Finite",
+	);
+}
+
+#[test]
+fn offset_count() {
+	let seeder = OffsetCount::<1, _, _>(TryAsU16(LittleEndian), SerdeLike);
+	let bytes = [2, 0, 10, 20, 30];
+	let items: Vec<u8> = read(seeder, &bytes).unwrap();
+	assert_eq!(items, [10, 20, 30]);
+	assert_eq!(write(&seeder, &items).unwrap(), bytes);
+}
+
+#[test]
+fn offset_count_errors() {
+	assert_error(
+		read::<Vec<u8>, _>(
+			OffsetCount::<-1, _, _>(TryAsU16(LittleEndian), SerdeLike),
+			&[0, 0],
+		),
+		"Stored count 0 with bias -1 is out of range",
+	);
+	assert_error(
+		write(
+			&OffsetCount::<1, _, _>(TryAsU16(LittleEndian), SerdeLike),
+			&Vec::<u8>::new(),
+		),
+		"Can't store length 0 with bias 1",
+	);
+}
+
+#[test]
+fn parallel_arrays() {
+	let seeder = ParallelArrays(TryAsU16(LittleEndian), SerdeLike, LittleEndian);
+	let bytes = [3, 0, 1, 2, 3, 10, 0, 20, 0, 30, 0];
+	let value: (Vec<u8>, Vec<u16>) = read(seeder, &bytes).unwrap();
+	assert_eq!(value, (vec![1, 2, 3], vec![10, 20, 30]));
+	assert_eq!(write(&seeder, &value).unwrap(), bytes);
+}
+
+#[test]
+fn parallel_arrays_errors() {
+	let seeder = ParallelArrays(TryAsU16(LittleEndian), SerdeLike, LittleEndian);
+	assert_error(
+		write(&seeder, &(vec![1_u8, 2, 3], vec![10_u16, 20])),
+		"Can't serialize ParallelArrays of different lengths 3 and 2",
+	);
+	// The second array is cut short.
+	assert!(read::<(Vec<u8>, Vec<u16>), _>(seeder, &[3, 0, 1, 2, 3, 10, 0]).is_err());
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn property_bag() {
+	let seeder = PropertyBag(TryAsU16(LittleEndian), LittleEndian);
+	let first = uuid::Uuid::from_u128(0x0011_2233_4455_6677_8899_AABB_CCDD_EEFF);
+	let second = uuid::Uuid::from_u128(0x1011_2233_4455_6677_8899_AABB_CCDD_EEFF);
+	let mut bytes = vec![2, 0];
+	for &(first_byte, value) in &[(0x00, 1), (0x10, 2)] {
+		bytes.extend_from_slice(&[0x33, 0x22, 0x11, first_byte, 0x55, 0x44, 0x77, 0x66]);
+		bytes.extend_from_slice(&[0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF]);
+		bytes.extend_from_slice(&[value, 0]);
+	}
+	let properties: HashMap<uuid::Uuid, u16> = read(seeder, &bytes).unwrap();
+	assert_eq!(properties.len(), 2);
+	assert_eq!((properties[&first], properties[&second]), (1, 2));
+	assert_eq!(write(&seeder, &properties).unwrap(), bytes);
+
+	// The same key twice.
+	let mut duplicate = bytes[..20].to_vec();
+	duplicate.extend_from_slice(&bytes[2..20]);
+	assert_error(
+		read::<HashMap<uuid::Uuid, u16>, _>(seeder, &duplicate),
+		"Duplicate key 00112233-4455-6677-8899-aabbccddeeff in PropertyBag",
+	);
+}
+
+#[test]
+fn initialized() {
+	let seeder = Initialized::<4, _>(LittleEndian);
+	let value: u16 = read(seeder, &[0x34, 0x12, 0xFF, 0xFF]).unwrap();
+	assert_eq!(value, 0x1234);
+	assert_eq!(write(&seeder, &value).unwrap(), [0x34, 0x12, 0xFF, 0xFF]);
+}
+
+#[test]
+fn initialized_errors() {
+	let seeder = Initialized::<4, _>(LittleEndian);
+	assert_error(
+		read::<u16, _>(seeder, &[0xFF; 4]),
+		"Region of 4 bytes is uninitialized (all 0xFF)",
+	);
+	assert_error(
+		read::<u16, _>(seeder, &[0x34, 0x12, 0xFF]),
+		"invalid length 3, expected 4 bytes",
+	);
+	assert_error(
+		write(&seeder, &0xFFFF_u16),
+		"Can't serialize a value that reads as uninitialized (all 0xFF)",
+	);
+	assert_error(
+		write(&seeder, &0_u64),
+		"Can't fit 8 bytes into Initialized region of 4",
+	);
+}
+
+#[test]
+fn framed_stream() {
+	let seeder = FramedStream(
+		TryAsU16(LittleEndian),
+		LengthPrefixed(TryAsU16(LittleEndian), SerdeLike),
+	);
+	let bytes = [3, 0, 1, 0, b'a', 4, 0, 2, 0, b'b', b'c', 2, 0, 0, 0];
+	let items: Vec<Vec<u8>> = read(seeder, &bytes).unwrap();
+	assert_eq!(items, [b"a".to_vec(), b"bc".to_vec(), vec![]]);
+	assert_eq!(write(&seeder, &items).unwrap(), bytes);
+}
+
+#[test]
+fn framed_stream_errors() {
+	let seeder = FramedStream(
+		TryAsU16(LittleEndian),
+		LengthPrefixed(TryAsU16(LittleEndian), SerdeLike),
+	);
+	assert_error(
+		read::<Vec<Vec<u8>>, _>(seeder, &[3, 0, 1, 0, b'a', 5, 0, 2, 0]),
+		"Frame 1 is 5 bytes long, but only 2 remain",
+	);
+	// The item doesn't fill its frame.
+	assert_error(
+		read::<Vec<Vec<u8>>, _>(seeder, &[4, 0, 1, 0, b'a', b'?']),
+		"(in frame 0)",
+	);
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn days_since_epoch() {
+	let epoch = time::Date::from_calendar_date(1900, time::Month::January, 1).unwrap();
+	let seeder = DaysSinceEpoch::<_, u16>::new(LittleEndian, epoch);
+	// 100 years of 365 days, plus 24 leap days since 1900 isn't one.
+	let bytes = 36524_u16.to_le_bytes();
+	let date: time::Date = read(seeder, &bytes).unwrap();
+	assert_eq!(
+		date,
+		time::Date::from_calendar_date(2000, time::Month::January, 1).unwrap()
+	);
+	assert_eq!(write(&seeder, &date).unwrap(), bytes);
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn days_since_epoch_errors() {
+	let epoch = time::Date::from_calendar_date(1900, time::Month::January, 1).unwrap();
+	assert_error(
+		read::<time::Date, _>(
+			DaysSinceEpoch::<_, u32>::new(LittleEndian, epoch),
+			&u32::MAX.to_le_bytes(),
+		),
+		"4294967295 days since 1900-01-01 is out of range",
+	);
+	assert_error(
+		write(
+			&DaysSinceEpoch::<_, u16>::new(LittleEndian, epoch),
+			&time::Date::from_calendar_date(1899, time::Month::December, 31).unwrap(),
+		),
+		"Can't store 1899-12-31 (-1 days since 1900-01-01) in u16",
+	);
+}
+
+/// Reads pages of length-prefixed bytes, each followed by the next page's offset or 0.
+fn read_paged(input: &[u8]) -> Result<(Vec<u8>, &[u8]), raw::Error> {
+	let page = Tupled((LengthPrefixed(TryAsU16(LittleEndian), SerdeLike), SerdeLike));
+	let seeder = Paged::new(
+		page,
+		|&next: &u8| {
+			if next == 0 {
+				None
+			} else {
+				Some(next as usize)
+			}
+		},
+	);
+	let mut deserializer = raw::Deserializer::new(input);
+	let items = seeder.seed().deserialize(&mut deserializer)?;
+	Ok((items, deserializer.remaining()))
+}
+
+#[test]
+fn paged() {
+	let bytes = [2, 0, 1, 2, 5, 1, 0, 3, 0];
+	let (items, remaining) = read_paged(&bytes).unwrap();
+	assert_eq!(items, [1, 2, 3]);
+	// Only the first page is consumed.
+	assert_eq!(remaining, &bytes[5..]);
+}
+
+#[test]
+fn paged_errors() {
+	assert_error(
+		read_paged(&[2, 0, 1, 2, 5, 0, 0, 5]),
+		"Page at offset 0x5 visited twice",
+	);
+	assert_error(
+		read_paged(&[2, 0, 1, 2, 20]),
+		"Page offset 0x14 is out of bounds (0x5 bytes)",
+	);
+	assert_error(read_paged(&[2, 0, 1, 2, 5, 9, 0]), "(in page at 0x5)");
+}
+
+#[test]
+fn signed_repr() {
+	let twos = SignedRepr::<12, _, u16>::twos_complement(LittleEndian);
+	let offset = SignedRepr::<12, _, u16>::offset_binary(LittleEndian);
+	for &(seeder, value, stored) in &[
+		(twos, -2048, 0x0800_u16),
+		(twos, 2047, 0x07FF),
+		(twos, -1, 0x0FFF),
+		(offset, -2048, 0x0000),
+		(offset, 2047, 0x0FFF),
+		(offset, 0, 0x0800),
+	] {
+		let bytes = stored.to_le_bytes();
+		assert_eq!(read::<i64, _>(seeder, &bytes).unwrap(), value);
+		assert_eq!(write(&seeder, &value).unwrap(), bytes);
+	}
+
+	let full = SignedRepr::<64, _, u64>::twos_complement(LittleEndian);
+	assert_eq!(
+		read::<i64, _>(full, &i64::MIN.to_le_bytes()).unwrap(),
+		i64::MIN
+	);
+	assert_eq!(write(&full, &i64::MAX).unwrap(), i64::MAX.to_le_bytes());
+}
+
+#[test]
+fn signed_repr_errors() {
+	let seeder = SignedRepr::<12, _, u16>::twos_complement(LittleEndian);
+	assert_error(
+		read::<i64, _>(seeder, &0x1000_u16.to_le_bytes()),
+		"expected 12-bit value",
+	);
+	assert_error(
+		write(&seeder, &2048),
+		"Can't fit 2048 into 12-bit TwosComplement",
+	);
+}
+
+#[test]
+fn capture() {
+	let (a, b) = (RefCell::new(None), RefCell::new(None));
+	let seeder = Tupled((
+		Capture(&a, LittleEndian),
+		Capture(&b, LittleEndian),
+		LittleEndian,
+	));
+	let bytes = [3, 0, 4, 0, 7, 0];
+	let (_, _, total): (u16, u16, u16) = read(seeder, &bytes).unwrap();
+	assert_eq!((*a.borrow(), *b.borrow()), (Some(3), Some(4)));
+	assert_eq!(a.borrow().unwrap() + b.borrow().unwrap(), total);
+
+	a.replace(None);
+	assert_eq!(
+		write(&seeder, &(5, 6, 11_u16)).unwrap(),
+		[5, 0, 6, 0, 11, 0]
+	);
+	assert_eq!((*a.borrow(), *b.borrow()), (Some(5), Some(6)));
+}
+
+#[test]
+fn pad_to_len() {
+	let seeder = PadToLen::<4, _, _, _>(TryAsU16(LittleEndian), SerdeLike, 0_u8, false);
+	let bytes = [2, 0, 7, 8];
+	let items: Vec<u8> = read(seeder, &bytes).unwrap();
+	assert_eq!(items, [7, 8, 0, 0]);
+	assert_eq!(write(&seeder, &items).unwrap(), bytes);
+
+	let all = PadToLen::<4, _, _, _>(TryAsU16(LittleEndian), SerdeLike, 0_u8, true);
+	assert_eq!(write(&all, &items).unwrap(), [4, 0, 7, 8, 0, 0]);
+	assert_eq!(read::<Vec<u8>, _>(all, &[4, 0, 7, 8, 0, 0]).unwrap(), items);
+}
+
+#[test]
+fn pad_to_len_errors() {
+	let seeder = PadToLen::<4, _, _, _>(TryAsU16(LittleEndian), SerdeLike, 0_u8, false);
+	assert_error(
+		read::<Vec<u8>, _>(seeder, &[5, 0, 1, 2, 3, 4, 5]),
+		"invalid length 5, expected at most 4 items",
+	);
+	assert_error(
+		write(&seeder, &vec![1, 2, 3, 4, 5]),
+		"Can't serialize 5 items as PadToLen with capacity 4",
+	);
+}
+
+#[test]
+fn check_digit() {
+	let seeder = CheckDigit(LittleEndian, luhn);
+	let bytes = 79_927_398_713_u64.to_le_bytes();
+	let value: u64 = read(seeder, &bytes).unwrap();
+	assert_eq!(value, 79_927_398_713);
+	assert_eq!(write(&seeder, &value).unwrap(), bytes);
+	// The check digit is recomputed when writing.
+	assert_eq!(write(&seeder, &79_927_398_710).unwrap(), bytes);
+
+	assert_error(
+		read::<u64, _>(seeder, &79_927_398_710_u64.to_le_bytes()),
+		"Check digit mismatch: Read 79927398710 but expected 79927398713",
+	);
+}
+
+#[test]
+fn byte_count_fixed() {
+	let seeder = ByteCountFixed::<4, _, _>(TryAsU16(LittleEndian), LittleEndian);
+	let bytes = [12, 0, 1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0];
+	let items: Vec<u32> = read(seeder, &bytes).unwrap();
+	assert_eq!(items, [1, 2, 3]);
+	assert_eq!(write(&seeder, &items).unwrap(), bytes);
+}
+
+#[test]
+fn byte_count_fixed_errors() {
+	let mut bytes = vec![13, 0];
+	bytes.extend_from_slice(&[0; 13]);
+	assert_error(
+		read::<Vec<u32>, _>(
+			ByteCountFixed::<4, _, _>(TryAsU16(LittleEndian), LittleEndian),
+			&bytes,
+		),
+		"invalid value: integer `13`, expected byte size divisible by 4",
+	);
+	assert_error(
+		write(
+			&ByteCountFixed::<{ usize::MAX }, _, _>(TryAsU16(LittleEndian), SerdeLike),
+			&vec![(), ()],
+		),
+		&format!("Byte size of 2 {}-byte items overflows", usize::MAX),
+	);
+}
+
+#[test]
+fn for_each() {
+	let mut sum = 0_u32;
+	let mut count = 0;
+	let seeder = ForEach::new(LittleEndian, |item: u16| {
+		sum += item as u32;
+		count += 1;
+	});
+	read::<(), _>(seeder, &[1, 0, 2, 0, 0xFF, 0xFF]).unwrap();
+	assert_eq!((sum, count), (0x10002, 3));
+
+	// A trailing partial item fails after the complete ones were passed on.
+	let mut seen = Vec::new();
+	let seeder = ForEach::new(LittleEndian, |item: u16| seen.push(item));
+	assert!(read::<(), _>(seeder, &[1, 0, 2]).is_err());
+	assert_eq!(seen, [1]);
+}
+
+#[test]
+fn dyn_endian() {
+	let bytes = [0x12, 0x34, 0x56, 0x78];
+	for &(little, expected) in &[(true, 0x7856_3412_u32), (false, 0x1234_5678)] {
+		let value: u32 = read(DynEndian(little), &bytes).unwrap();
+		assert_eq!(value, expected);
+		assert_eq!(write(&DynEndian(little), &value).unwrap(), bytes);
+	}
+}
+
+#[test]
+fn float_array_le() {
+	let mut floats = [0_f32; 1000];
+	for (i, float) in floats.iter_mut().enumerate() {
+		*float = (i as f32 - 500.) / 3.;
+	}
+	floats[1] = f32::INFINITY;
+	floats[2] = f32::MIN_POSITIVE / 2.;
+	let bytes = write(&Tuple::of(IEEE754(LittleEndian)), &floats).unwrap();
+	assert_eq!(bytes.len(), 4000);
+
+	let read_floats: [f32; 1000] = read(FloatArrayLe::<1000>, &bytes).unwrap();
+	assert_eq!(read_floats, floats);
+	assert_eq!(write(&FloatArrayLe::<1000>, &floats).unwrap(), bytes);
+
+	// Unaligned, and in double precision.
+	let mut unaligned = vec![0xAA];
+	unaligned.extend_from_slice(&1.5_f64.to_le_bytes());
+	unaligned.extend_from_slice(&(-0.25_f64).to_le_bytes());
+	let mut deserializer = raw::Deserializer::new(&unaligned);
+	DeSeeder::<u8>::seed(SerdeLike)
+		.deserialize(&mut deserializer)
+		.unwrap();
+	let doubles: [f64; 2] = FloatArrayLe::<2>
+		.seed()
+		.deserialize(&mut deserializer)
+		.unwrap();
+	deserializer.end().unwrap();
+	assert_eq!(doubles, [1.5, -0.25]);
+}
+
+#[test]
+fn float_array_le_errors() {
+	assert_error(
+		read::<[f32; 2], _>(FloatArrayLe::<2>, &[0; 7]),
+		"invalid length 7, expected 8 bytes of little-endian floats",
+	);
+}
+
+#[test]
+fn restrict_tags() {
+	let seeder = RestrictTags(
+		LengthPrefixed(TryAsU16(LittleEndian), SerdeLike),
+		|&opcode: &u8| opcode as u32,
+		&[1, 2],
+	);
+	let bytes = [3, 0, 1, 2, 1];
+	let opcodes: Vec<u8> = read(seeder, &bytes).unwrap();
+	assert_eq!(opcodes, [1, 2, 1]);
+	assert_eq!(write(&seeder, &opcodes).unwrap(), bytes);
+
+	assert_error(
+		read::<Vec<u8>, _>(seeder, &[3, 0, 1, 3, 2]),
+		"Tag 3 at index 1 is not one of [1, 2]",
+	);
+	assert_error(
+		write(&seeder, &vec![2, 1, 7]),
+		"Can't serialize tag 7 at index 2, which is not one of [1, 2]",
+	);
+}
+
+#[cfg(feature = "flate2")]
+fn zlib(data: &[u8]) -> Vec<u8> {
+	use std::io::Write as _;
+
+	let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+	encoder.write_all(data).unwrap();
+	encoder.finish().unwrap()
+}
+
+#[cfg(feature = "flate2")]
+#[test]
+fn deflated() {
+	let seeder = Deflated(
+		TryAsU16(LittleEndian),
+		LengthPrefixedUtf8(TryAsU16(LittleEndian), 100),
+	);
+	let compressed = zlib(b"\x0B\0hello world");
+	let mut bytes = vec![13, 0, compressed.len() as u8, 0];
+	bytes.extend_from_slice(&compressed);
+	let text: String = read(seeder, &bytes).unwrap();
+	assert_eq!(text, "hello world");
+
+	let written = write(&seeder, &text).unwrap();
+	assert_eq!(written[..2], [13, 0]);
+	assert_eq!(written[2] as usize, written.len() - 4);
+	assert_eq!(read::<String, _>(seeder, &written).unwrap(), text);
+}
+
+#[cfg(feature = "flate2")]
+#[test]
+fn deflated_errors() {
+	let seeder = Deflated(TryAsU16(LittleEndian), SerdeLike);
+	let compressed = zlib(&[1, 2, 3]);
+	let with_sizes = |uncompressed: u8| {
+		let mut bytes = vec![uncompressed, 0, compressed.len() as u8, 0];
+		bytes.extend_from_slice(&compressed);
+		bytes
+	};
+	assert_eq!(
+		read::<Vec<u8>, _>(seeder, &with_sizes(3)).unwrap(),
+		[1, 2, 3]
+	);
+	assert_error(
+		read::<Vec<u8>, _>(seeder, &with_sizes(4)),
+		"Decompressed 3 bytes, but 4 were declared",
+	);
+	assert_error(
+		read::<Vec<u8>, _>(seeder, &with_sizes(2)),
+		"Decompressed 3+ bytes, but 2 were declared",
+	);
+	assert!(read::<Vec<u8>, _>(seeder, &[3, 0, 3, 0, 1, 2, 3]).is_err());
+	assert_error(read::<u8, _>(seeder, &with_sizes(3)), "(in Deflated)");
+}
+
+#[test]
+fn leb128() {
+	let bytes = [0xE5, 0x8E, 0x26];
+	let value: u32 = read(Leb128::unsigned(), &bytes).unwrap();
+	assert_eq!(value, 624_485);
+	assert_eq!(write(&Leb128::unsigned(), &value).unwrap(), bytes);
+
+	let bytes = [0xC0, 0xBB, 0x78];
+	let value: i32 = read(Leb128::signed(), &bytes).unwrap();
+	assert_eq!(value, -123_456);
+	assert_eq!(write(&Leb128::signed(), &value).unwrap(), bytes);
+
+	let value: i8 = read(Leb128::signed(), &[0x7F]).unwrap();
+	assert_eq!(value, -1);
+	assert_eq!(write(&Leb128::signed(), &0_u128).unwrap(), [0]);
+}
+
+#[test]
+fn leb128_errors() {
+	assert_error(
+		read::<u32, _>(Leb128::unsigned(), &[0x80]),
+		"invalid length 1, expected unsigned LEB128 fitting u32",
+	);
+	assert_error(
+		read::<u8, _>(Leb128::unsigned(), &[0x80, 0x02]),
+		"invalid value: out-of-range LEB128, expected unsigned LEB128 fitting u8",
+	);
+	assert_error(
+		read::<i8, _>(Leb128::signed(), &[0x80, 0x01]),
+		"invalid value: out-of-range LEB128, expected signed LEB128 fitting i8",
+	);
+	let mut overlong = [0xFF; 19];
+	overlong[18] = 0x7F;
+	assert_error(
+		read::<u128, _>(Leb128::unsigned(), &overlong),
+		"LEB128 overflows 128 bits after 19 bytes",
+	);
+	assert_error(
+		write(&Leb128::unsigned(), &-1_i32),
+		"Can't serialize negative value as unsigned LEB128",
+	);
+	assert_error(
+		write(&Leb128::signed(), &u128::MAX),
+		"Can't serialize value out of i128 range as signed LEB128",
+	);
+}
+
+#[test]
+fn nul_terminated() {
+	let seeder = Tupled((NulTerminated, SerdeLike));
+	let bytes = b"abc\0\x07";
+	let value: (Vec<u8>, u8) = read(seeder, bytes).unwrap();
+	assert_eq!(value, (b"abc".to_vec(), 7));
+	assert_eq!(write(&seeder, &value).unwrap(), bytes);
+}
+
+#[test]
+fn nul_terminated_errors() {
+	assert_error(
+		read::<Vec<u8>, _>(NulTerminated, b"abc"),
+		"Missing NUL terminator",
+	);
+	assert_error(
+		write(&NulTerminated, &b"a\0c".to_vec()),
+		"Can't serialize interior NUL at index 1 as NulTerminated",
+	);
+}
+
+#[test]
+fn fixed_string() {
+	let seeder = FixedString(6, b' ');
+	let bytes = b"ab c  ";
+	let value: Vec<u8> = read(seeder, bytes).unwrap();
+	assert_eq!(value, b"ab c");
+	assert_eq!(write(&seeder, &value).unwrap(), bytes);
+
+	let value: Vec<u8> = read(seeder, b"      ").unwrap();
+	assert!(value.is_empty());
+}
+
+#[test]
+fn fixed_string_errors() {
+	assert_error(
+		read::<Vec<u8>, _>(FixedString(6, 0), b"abc"),
+		"Tried to read 1 bytes at offset 3, but only 0 remain",
+	);
+	assert_error(
+		write(&FixedString(2, 0), &b"abc".to_vec()),
+		"Can't fit 3 bytes into FixedString of 2",
+	);
+	assert_error(
+		write(&FixedString(6, b' '), &b"abc ".to_vec()),
+		"Can't serialise [97, 98, 99, 32] as FixedString padded with 0x20, since it ends with the padding byte",
+	);
+}
+
+#[cfg(feature = "encoding")]
+#[test]
+fn encoding() {
+	let seeder = Windows1252(NulTerminated);
+	let bytes = b"caf\xE9 \x80\0";
+	let text: String = read(seeder, bytes).unwrap();
+	assert_eq!(text, "caf\u{E9} \u{20AC}");
+	assert_eq!(write(&seeder, &text).unwrap(), bytes);
+
+	let seeder = Encoding::<ShiftJisEncoding, _>::new(FixedString(4, 0));
+	let bytes = b"\x82\xA0a\0";
+	let text: Box<str> = read(seeder, bytes).unwrap();
+	assert_eq!(&*text, "\u{3042}a");
+	assert_eq!(write(&seeder, &text).unwrap(), bytes);
+}
+
+#[cfg(feature = "encoding")]
+#[test]
+fn encoding_errors() {
+	let seeder = Ascii(NulTerminated);
+	assert_error(
+		read::<String, _>(seeder, b"ab\xE9\0"),
+		"Invalid ascii at byte offset 2",
+	);
+	assert_error(
+		write(&seeder, &"ab\u{E9}".to_string()),
+		"Can't encode '\u{E9}' at byte offset 2 as ascii",
+	);
+}
+
+#[test]
+fn bool() {
+	let lenient = Bool::<_, u16>::lenient(LittleEndian);
+	let value: bool = read(lenient, &[0, 0]).unwrap();
+	assert!(!value);
+	assert_eq!(write(&lenient, &value).unwrap(), [0, 0]);
+	let value: bool = read(lenient, &[7, 0]).unwrap();
+	assert!(value);
+	assert_eq!(write(&lenient, &value).unwrap(), [1, 0]);
+
+	let strict = Bool::<_, u8>::strict(SerdeLike);
+	let value: bool = read(strict, &[1]).unwrap();
+	assert!(value);
+	assert_eq!(write(&strict, &value).unwrap(), [1]);
+}
+
+#[test]
+fn bool_errors() {
+	assert_error(
+		read::<bool, _>(Bool::<_, u8>::strict(SerdeLike), &[2]),
+		"Expected 0 or 1 for strict Bool, found 2",
+	);
+}
+
+#[test]
+fn optional() {
+	let seeder = Optional(Bool::<_, u8>::strict(SerdeLike), TryAsU16(LittleEndian));
+	let value: Option<usize> = read(seeder, &[1, 0x34, 0x12]).unwrap();
+	assert_eq!(value, Some(0x1234));
+	assert_eq!(write(&seeder, &value).unwrap(), [1, 0x34, 0x12]);
+
+	let value: Option<usize> = read(seeder, &[0]).unwrap();
+	assert_eq!(value, None);
+	assert_eq!(write(&seeder, &value).unwrap(), [0]);
+}
+
+#[test]
+fn optional_errors() {
+	let seeder = Optional(Bool::<_, u8>::strict(SerdeLike), TryAsU16(LittleEndian));
+	assert_error(
+		read::<Option<usize>, _>(seeder, &[2, 0x34, 0x12]),
+		"Expected 0 or 1 for strict Bool, found 2",
+	);
+	assert_error(
+		read::<Option<usize>, _>(seeder, &[1, 0x34]),
+		"Tried to read 1 bytes at offset 2, but only 0 remain",
+	);
+	assert_error(read::<Option<usize>, _>(seeder, &[0, 0x34, 0x12]), "");
+}
+
+#[test]
+fn tuple_array() {
+	let seeder = Tuple::of(LengthPrefixed(TryAsU16(LittleEndian), SerdeLike));
+	let bytes = [1, 0, 7, 2, 0, 8, 9];
+	let value: [Vec<u8>; 2] = read(seeder.clone(), &bytes).unwrap();
+	assert_eq!(value, [vec![7], vec![8, 9]]);
+	assert_eq!(write(&seeder, &value).unwrap(), bytes);
+
+	// The already read first element is dropped.
+	assert_error(
+		read::<[Vec<u8>; 2], _>(seeder, &[1, 0, 7, 2, 0, 8]),
+		"(in Tuple element 1 of 2)",
+	);
+}
+
+#[test]
+fn crc32() {
+	assert_eq!(super::crc32(b"123456789"), 0xCBF4_3926);
+
+	let seeder = Crc32(FixedString(9, 0), LittleEndian);
+	let bytes = b"123456789\x26\x39\xF4\xCB";
+	let value: Vec<u8> = read(seeder, bytes).unwrap();
+	assert_eq!(value, b"123456789");
+	assert_eq!(write(&seeder, &value).unwrap(), bytes);
+}
+
+#[test]
+fn crc32_errors() {
+	let seeder = Crc32(FixedString(9, 0), LittleEndian);
+	assert_error(
+		read::<Vec<u8>, _>(seeder, b"123456780\x26\x39\xF4\xCB"),
+		"CRC-32 mismatch: stored CBF43926, computed",
+	);
+	assert_error(read::<Vec<u8>, _>(seeder, b"1234"), "(in CRC-32)");
+	assert_error(
+		read::<Vec<u8>, _>(seeder, b"123456789\x26\x39"),
+		"Tried to read 1 bytes at offset 11, but only 0 remain",
+	);
+}
+
+#[test]
+fn magic() {
+	let seeder = Magic(b"PK\x03\x04");
+	read::<(), _>(seeder, b"PK\x03\x04").unwrap();
+	assert_eq!(write(&seeder, &()).unwrap(), b"PK\x03\x04");
+}
+
+#[test]
+fn magic_errors() {
+	let seeder = Magic(b"PK\x03\x04");
+	assert_error(
+		read::<(), _>(seeder, b"PK\x05\x06"),
+		"Expected magic [50, 4B, 03, 04], found [50, 4B, 05, 06]",
+	);
+	assert_error(
+		read::<(), _>(seeder, b"PK"),
+		"Expected magic [50, 4B, 03, 04], found [50, 4B] and then: Tried to read",
+	);
+}
+
+#[test]
+fn padding() {
+	let seeder = Padding(3, 0xCC, true);
+	read::<(), _>(seeder, &[0xCC; 3]).unwrap();
+	assert_eq!(write(&seeder, &()).unwrap(), [0xCC; 3]);
+
+	// Unchecked padding accepts any filler.
+	read::<(), _>(Padding(3, 0xCC, false), &[1, 2, 3]).unwrap();
+}
+
+#[test]
+fn padding_errors() {
+	assert_error(
+		read::<(), _>(Padding(3, 0xCC, true), &[0xCC, 0, 0xCC]),
+		"Expected padding byte CC at 1 but found 00",
+	);
+	assert_error(
+		read::<(), _>(Padding(3, 0xCC, true), &[0xCC]),
+		"Tried to read 1 bytes at offset 1, but only 0 remain",
+	);
+}
+
+#[test]
+fn align() {
+	let seeder = Tupled((
+		Align::<4, _>(LengthPrefixed(TryAsU16(LittleEndian), SerdeLike)),
+		SerdeLike,
+	));
+	let bytes = [1, 0, 7, 0, 9];
+	let value: (Vec<u8>, u8) = read(seeder, &bytes).unwrap();
+	assert_eq!(value, (vec![7], 9));
+	assert_eq!(write(&seeder, &value).unwrap(), bytes);
+
+	let value: (Vec<u8>, u8) = read(seeder, &[2, 0, 7, 8, 9]).unwrap();
+	assert_eq!(value, (vec![7, 8], 9));
+
+	// Padding is counted from the start of the input, not of the value.
+	let seeder = Tupled((SerdeLike, Align::<4, _>(SerdeLike), SerdeLike));
+	let bytes = [1, 2, 0, 0, 3];
+	let value: (u8, u8, u8) = read(seeder, &bytes).unwrap();
+	assert_eq!(value, (1, 2, 3));
+	assert_eq!(write(&seeder, &value).unwrap(), bytes);
+}
+
+#[test]
+fn align_errors() {
+	let seeder = Align::<4, _>(LengthPrefixed(TryAsU16(LittleEndian), SerdeLike));
+	// The padding is missing.
+	assert_error(
+		read::<Vec<u8>, _>(seeder, &[1, 0, 7]),
+		"Tried to read 1 bytes at offset 3, but only 0 remain",
+	);
+	assert_error(read::<Vec<u8>, _>(seeder, &[2, 0, 7]), "(in Align::<4, _>)");
+}
+
+#[test]
+fn skip() {
+	read::<(), _>(Skip(3), &[1, 2, 3]).unwrap();
+	assert_eq!(write(&Skip(3), &()).unwrap(), [0; 3]);
+	assert_eq!(write(&Skip::filled(2, 0xFF), &()).unwrap(), [0xFF; 2]);
+	read::<(), _>(Skip::filled(2, 0xFF), &[0, 0]).unwrap();
+}
+
+#[test]
+fn skip_errors() {
+	assert_error(
+		read::<(), _>(Skip(3), &[1, 2]),
+		"Tried to read 1 bytes at offset 2, but only 0 remain",
+	);
+}
+
+#[test]
+fn zig_zag() {
+	let seeder = ZigZag(Leb128::unsigned());
+	for (value, bytes) in [
+		(0_i32, &[0][..]),
+		(-1, &[1]),
+		(1, &[2]),
+		(-64, &[0x7F]),
+		(64, &[0x80, 0x01]),
+	] {
+		assert_eq!(read::<i32, _>(seeder, bytes).unwrap(), value);
+		assert_eq!(write(&seeder, &value).unwrap(), bytes);
+	}
+	assert_eq!(read::<i8, _>(ZigZag(SerdeLike), &[0xFF]).unwrap(), i8::MIN);
+	assert_eq!(write(&ZigZag(SerdeLike), &i8::MAX).unwrap(), [0xFE]);
+}
+
+#[test]
+fn zig_zag_errors() {
+	// Errors come from the unsigned storage.
+	assert_error(
+		read::<i8, _>(ZigZag(Leb128::unsigned()), &[0x80, 0x02]),
+		"out-of-range LEB128, expected unsigned LEB128 fitting u8",
+	);
+}
+
+#[test]
+fn max_length() {
+	let seeder = MaxLength(300, TryAsU16(LittleEndian));
+	let value: usize = read(seeder, &[0x2C, 0x01]).unwrap();
+	assert_eq!(value, 300);
+	assert_eq!(write(&seeder, &value).unwrap(), [0x2C, 0x01]);
+
+	let seeder = LengthPrefixed::bounded(TryAsU16(LittleEndian), SerdeLike, 2);
+	let items: Vec<u8> = read(seeder, &[2, 0, 7, 8]).unwrap();
+	assert_eq!(items, [7, 8]);
+	assert_eq!(write(&seeder, &items).unwrap(), [2, 0, 7, 8]);
+}
+
+#[test]
+fn max_length_errors() {
+	let seeder = MaxLength(300, TryAsU16(LittleEndian));
+	assert_error(
+		read::<usize, _>(seeder, &[0x2D, 0x01]),
+		"invalid length 301, expected at most 300",
+	);
+	assert_error(
+		write(&seeder, &301),
+		"Tried to serialise length 301 over maximum 300",
+	);
+
+	// The items aren't read at all.
+	let seeder = LengthPrefixed::bounded(TryAsU16(LittleEndian), SerdeLike, 2);
+	assert_error(
+		read::<Vec<u8>, _>(seeder, &[0xFF, 0xFF]),
+		"invalid length 65535, expected at most 2",
+	);
+	assert_error(
+		write(&seeder, &vec![1, 2, 3]),
+		"Tried to serialise length 3 over maximum 2",
+	);
+}
+
+#[test]
+fn try_as_u16_and_u64() {
+	let value: usize = read(TryAsU16(BigEndian), &[0x12, 0x34]).unwrap();
+	assert_eq!(value, 0x1234);
+	assert_eq!(write(&TryAsU16(BigEndian), &value).unwrap(), [0x12, 0x34]);
+
+	let bytes = [0x78, 0x56, 0x34, 0x12, 0, 0, 0, 0];
+	let value: usize = read(TryAsU64(LittleEndian), &bytes).unwrap();
+	assert_eq!(value, 0x1234_5678);
+	assert_eq!(write(&TryAsU64(LittleEndian), &value).unwrap(), bytes);
+}
+
+#[test]
+fn try_as_u16_and_u64_errors() {
+	assert_error(
+		write(&TryAsU16(BigEndian), &0x1_0000),
+		"out of range for u16",
+	);
+	assert_error(
+		read::<usize, _>(TryAsU64(LittleEndian), &[0; 7]),
+		"Tried to read 1 bytes at offset 7, but only 0 remain",
+	);
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct Permissions(u8);
+impl Flagsable for Permissions {
+	type Int = u8;
+	fn from_bits(bits: u8) -> Option<Self> {
+		(bits & !0b111 == 0).then_some(Self(bits))
+	}
+	fn from_bits_truncate(bits: u8) -> Self {
+		Self(bits & 0b111)
+	}
+	fn to_bits(&self) -> u8 {
+		self.0
+	}
+}
+
+#[test]
+fn flags() {
+	let value: Permissions = read(Flags::strict(SerdeLike), &[0b101]).unwrap();
+	assert_eq!(value, Permissions(0b101));
+	assert_eq!(write(&Flags::strict(SerdeLike), &value).unwrap(), [0b101]);
+
+	let value: Permissions = read(Flags::lenient(SerdeLike), &[0b1101]).unwrap();
+	assert_eq!(value, Permissions(0b101));
+	assert_eq!(write(&Flags::lenient(SerdeLike), &value).unwrap(), [0b101]);
+}
+
+#[test]
+fn flags_errors() {
+	assert_error(
+		read::<Permissions, _>(Flags::strict(SerdeLike), &[0b1101]),
+		"Unknown bits in 0xD for strict Flags",
+	);
+}
+
+#[derive(Debug, PartialEq)]
+enum Shape {
+	Point,
+	Circle(u16),
+	Rect(u8, u8),
+}
+impl<'de> DeTaggable<'de> for Shape {
+	type Tag = u8;
+	const VARIANTS: &'static [&'static str] = &["0", "1", "2"];
+	fn field_count(tag: &u8) -> Option<usize> {
+		match tag {
+			0 => Some(0),
+			1 | 2 => Some(2),
+			_ => None,
+		}
+	}
+	fn from<A: de::SeqAccess<'de>>(tag: u8, fields: &mut A) -> Result<Option<Self>, A::Error> {
+		let mut field = |i| {
+			fields
+				.next_element::<u8>()?
+				.ok_or_else(|| de::Error::invalid_length(i, &"Shape fields"))
+		};
+		Ok(Some(match tag {
+			0 => Shape::Point,
+			1 => Shape::Circle(u16::from_le_bytes([field(0)?, field(1)?])),
+			2 => Shape::Rect(field(0)?, field(1)?),
+			_ => return Ok(None),
+		}))
+	}
+}
+impl SerTaggable for Shape {
+	type Tag = u8;
+	fn tag(&self) -> u8 {
+		match self {
+			Shape::Point => 0,
+			Shape::Circle(_) => 1,
+			Shape::Rect(..) => 2,
+		}
+	}
+	fn len(&self) -> usize {
+		match self {
+			Shape::Point => 0,
+			Shape::Circle(_) | Shape::Rect(..) => 1,
+		}
+	}
+	fn to<SerializeTuple: ser::SerializeTuple>(
+		&self,
+		serialize_tuple: &mut SerializeTuple,
+	) -> Result<(), SerializeTuple::Error> {
+		match self {
+			Shape::Point => Ok(()),
+			Shape::Circle(radius) => {
+				serialize_tuple.serialize_element(&LittleEndian.seeded(radius))
+			}
+			Shape::Rect(width, height) => serialize_tuple.serialize_element(&(width, height)),
+		}
+	}
+}
+
+#[test]
+fn tagged() {
+	for (value, bytes) in [
+		(Shape::Point, &[0][..]),
+		(Shape::Circle(0x1234), &[1, 0x34, 0x12]),
+		(Shape::Rect(3, 4), &[2, 3, 4]),
+	] {
+		assert_eq!(read::<Shape, _>(Tagged(SerdeLike), bytes).unwrap(), value);
+		assert_eq!(write(&Tagged(SerdeLike), &value).unwrap(), bytes);
+	}
+}
+
+#[test]
+fn tagged_errors() {
+	assert_error(
+		read::<Shape, _>(Tagged(SerdeLike), &[3]),
+		"unknown variant `3`, expected one of `0`, `1`, `2`",
+	);
+	assert_error(
+		read::<Shape, _>(Tagged(SerdeLike), &[2, 3]),
+		"Tried to read 1 bytes at offset 2, but only 0 remain",
+	);
+}
+
+#[test]
+fn length_prefixed_boxed_slice() {
+	let seeder = LengthPrefixed(TryAsU16(LittleEndian), LittleEndian);
+	let bytes = [2, 0, 0x34, 0x12, 0x78, 0x56];
+	let value: Box<[u16]> = read(seeder, &bytes).unwrap();
+	assert_eq!(*value, [0x1234, 0x5678]);
+	assert_eq!(write(&seeder, &value).unwrap(), bytes);
+}
+
+#[test]
+fn const_() {
+	let seeder = Const(3_u16, BigEndian);
+	read::<(), _>(seeder, &[0, 3]).unwrap();
+	assert_eq!(write(&seeder, &()).unwrap(), [0, 3]);
+}
+
+#[test]
+fn const_errors() {
+	assert_error(
+		read::<(), _>(Const(3_u16, BigEndian), &[0, 4]),
+		"Expected constant 3 but found 4",
+	);
+}
+
+#[test]
+fn map() {
+	let seeder = Map(
+		TryAsU16(LittleEndian),
+		SerdeLike,
+		LittleEndian,
+		DuplicateKeys::Error,
+	);
+	let bytes = [2, 0, 1, 0x34, 0x12, 2, 0x78, 0x56];
+	let value: BTreeMap<u8, u16> = read(seeder, &bytes).unwrap();
+	assert_eq!(value, BTreeMap::from([(1, 0x1234), (2, 0x5678)]));
+	assert_eq!(write(&seeder, &value).unwrap(), bytes);
+
+	// The later value wins.
+	let seeder = Map(
+		TryAsU16(LittleEndian),
+		SerdeLike,
+		LittleEndian,
+		DuplicateKeys::Warn,
+	);
+	let value: BTreeMap<u8, u16> = read(seeder, &[2, 0, 1, 0x34, 0x12, 1, 0x78, 0x56]).unwrap();
+	assert_eq!(value, BTreeMap::from([(1, 0x5678)]));
+}
+
+#[test]
+fn map_errors() {
+	let seeder = Map(
+		TryAsU16(LittleEndian),
+		SerdeLike,
+		LittleEndian,
+		DuplicateKeys::Error,
+	);
+	assert_error(
+		read::<BTreeMap<u8, u16>, _>(seeder, &[2, 0, 1, 0x34, 0x12, 1, 0x78, 0x56]),
+		"Duplicate map key 1",
+	);
+	assert_error(
+		read::<BTreeMap<u8, u16>, _>(seeder, &[2, 0, 1, 0x34, 0x12]),
+		"Tried to read 1 bytes at offset 5, but only 0 remain",
+	);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hash_map_key_order() {
+	let seeder = Map(
+		TryAsU16(LittleEndian),
+		SerdeLike,
+		SerdeLike,
+		DuplicateKeys::Error,
+	);
+	let value: HashMap<u8, u8> = (0..=255).map(|key| (key, !key)).collect();
+	let bytes = write(&seeder, &value).unwrap();
+	let expected: Vec<u8> = vec![0, 1]
+		.into_iter()
+		.chain((0..=255).flat_map(|key| vec![key, !key]))
+		.collect();
+	assert_eq!(bytes, expected);
+	assert_eq!(read::<HashMap<u8, u8>, _>(seeder, &bytes).unwrap(), value);
+}
+
+#[test]
+fn pair_and_triple() {
+	let seeder = Pair(BigEndian, SerdeLike);
+	let value: (u16, u8) = read(seeder, &[0x12, 0x34, 5]).unwrap();
+	assert_eq!(value, (0x1234, 5));
+	assert_eq!(write(&seeder, &value).unwrap(), [0x12, 0x34, 5]);
+
+	let seeder = Triple(SerdeLike, LittleEndian, NulTerminated);
+	let bytes = b"\x01\x34\x12ab\0";
+	let value: (u8, u16, Vec<u8>) = read(seeder, bytes).unwrap();
+	assert_eq!(value, (1, 0x1234, b"ab".to_vec()));
+	assert_eq!(write(&seeder, &value).unwrap(), bytes);
+}
+
+#[test]
+fn pair_and_triple_errors() {
+	assert_error(
+		read::<(u16, u8), _>(Pair(BigEndian, SerdeLike), &[0x12, 0x34]),
+		"Tried to read 1 bytes at offset 2, but only 0 remain",
+	);
+	assert_error(
+		write(
+			&Triple(SerdeLike, LittleEndian, NulTerminated),
+			&(1_u8, 2_u16, b"\0".to_vec()),
+		),
+		"Can't serialize interior NUL at index 0 as NulTerminated",
+	);
+}
+
+#[test]
+fn rest() {
+	let seeder = Pair(SerdeLike, Rest);
+	let bytes = [1, 2, 3, 4];
+	let value: (u8, Vec<u8>) = read(seeder, &bytes).unwrap();
+	assert_eq!(value, (1, vec![2, 3, 4]));
+	assert_eq!(write(&seeder, &value).unwrap(), bytes);
+
+	let value: (u8, &[u8]) = read(seeder, &bytes).unwrap();
+	assert_eq!(value, (1, &bytes[1..]));
+	assert_eq!(write(&seeder, &value).unwrap(), bytes);
+
+	let value: (u8, &[u8]) = read(seeder, &bytes[..1]).unwrap();
+	assert!(value.1.is_empty());
+}
+
+#[test]
+fn rest_errors() {
+	// Nothing is left for a field after `Rest`.
+	assert_error(
+		read::<(Vec<u8>, u8), _>(Pair(Rest, SerdeLike), &[1, 2, 3, 4]),
+		"Tried to read 1 bytes at offset 4, but only 0 remain",
+	);
+}
+
+#[test]
+fn offset() {
+	let bytes = [0xAA, 3, 0, 0xBB];
+	let seeder = Triple(
+		SerdeLike,
+		Offset(TryAsU16(LittleEndian), SerdeLike, OffsetBase::Input),
+		Skip(1),
+	);
+	let value: (u8, u8, ()) = read(seeder, &bytes).unwrap();
+	assert_eq!(value, (0xAA, 0xBB, ()));
+
+	let seeder = Triple(
+		Skip(1),
+		Offset(TryAsU16(LittleEndian), SerdeLike, OffsetBase::Field),
+		Skip(1),
+	);
+	let value: ((), u8, ()) = read(seeder, &[0xAA, 2, 0, 0xBB]).unwrap();
+	assert_eq!(value, ((), 0xBB, ()));
+}
+
+#[test]
+fn offset_errors() {
+	let seeder = Pair(
+		Offset(TryAsU16(LittleEndian), SerdeLike, OffsetBase::Field),
+		Skip(1),
+	);
+	assert_error(
+		read::<(u8, ()), _>(seeder, &[4, 0, 0]),
+		"Address 4 (Field-relative) is outside the input of 3 bytes",
+	);
+	assert_error(read::<(u8, ()), _>(seeder, &[3, 0, 0]), "(at Offset 3)");
+}
+
+#[test]
+fn assert_round_trips() {
+	let bytes = raw::assert_round_trips(FixedString(4, b' '), &b"ab".to_vec());
+	assert_eq!(bytes, b"ab  ");
+	raw::assert_round_trips(
+		Optional(Bool::<_, u8>::strict(SerdeLike), BigEndian),
+		&Some(7_u16),
+	);
+}
+
+#[test]
+#[should_panic(expected = "Round trip through [01, 02] changed the value")]
+fn assert_round_trips_changed() {
+	// Only the first two items are written.
+	raw::assert_round_trips(TupleN::truncating(2, SerdeLike), &vec![1_u8, 2, 3]);
+}
+
+#[test]
+#[should_panic(expected = "Failed to serialize [1, 2, 3]: Can't fit 3 bytes into FixedString of 2")]
+fn assert_round_trips_unserializable() {
+	raw::assert_round_trips(FixedString(2, 0), &vec![1, 2, 3]);
+}
+
+#[test]
+fn tuple_n() {
+	let seeder = TupleN(2, BigEndian);
+	let value: Vec<u16> = read(seeder, &[0x12, 0x34, 0x56, 0x78]).unwrap();
+	assert_eq!(value, [0x1234, 0x5678]);
+	assert_eq!(write(&seeder, &value).unwrap(), [0x12, 0x34, 0x56, 0x78]);
+
+	let seeder = TupleN::truncating(2, BigEndian);
+	assert_eq!(
+		write(&seeder, &vec![0x1234_u16, 0x5678, 0x9ABC]).unwrap(),
+		[0x12, 0x34, 0x56, 0x78]
+	);
+	let value: Vec<u16> = read(seeder, &[0x12, 0x34, 0x56, 0x78]).unwrap();
+	assert_eq!(value, [0x1234, 0x5678]);
+	assert_eq!(write(&seeder, &value).unwrap(), [0x12, 0x34, 0x56, 0x78]);
+}
+
+#[test]
+fn tuple_n_errors() {
+	assert_error(
+		write(&TupleN(2, BigEndian), &vec![1_u16, 2, 3]),
+		"Tried to serialise TupleN(2, _) from a .len = 3 (the length must match exactly)",
+	);
+	assert_error(
+		write(&TupleN(2, BigEndian), &vec![1_u16]),
+		"Tried to serialise TupleN(2, _) from a .len = 1 (the length must match exactly)",
+	);
+	assert_error(
+		write(&TupleN::truncating(2, BigEndian), &vec![1_u16]),
+		"Tried to serialise TupleN::truncating(2, _) from a .len = 1 (the length must be at least that)",
+	);
+	assert_error(
+		read::<Vec<u16>, _>(TupleN(2, BigEndian), &[0x12, 0x34, 0x56]),
+		"Tried to read 1 bytes at offset 3, but only 0 remain",
+	);
+}
+
+#[test]
+fn or_default() {
+	let seeder = Pair(SerdeLike, OrDefault(BigEndian));
+	let value: (u8, u16) = read(seeder, &[1, 0x12, 0x34]).unwrap();
+	assert_eq!(value, (1, 0x1234));
+	assert_eq!(write(&seeder, &value).unwrap(), [1, 0x12, 0x34]);
+
+	let value: (u8, u16) = read(seeder, &[1]).unwrap();
+	assert_eq!(value, (1, 0));
+	// The default is still written.
+	assert_eq!(write(&seeder, &value).unwrap(), [1, 0, 0]);
+}
+
+#[test]
+fn or_default_errors() {
+	assert_error(
+		read::<(u8, u16), _>(Pair(SerdeLike, OrDefault(BigEndian)), &[1, 0x12]),
+		"Tried to read 1 bytes at offset 2, but only 0 remain",
+	);
+}
+
+#[test]
+fn utf16() {
+	let seeder = Utf16(LittleEndian, TryAsU16(LittleEndian), false);
+	let bytes = [2, 0, b'h', 0, b'i', 0];
+	let text: String = read(seeder, &bytes).unwrap();
+	assert_eq!(text, "hi");
+	assert_eq!(write(&seeder, &text).unwrap(), bytes);
+
+	let seeder = Utf16(LittleEndian, TryAsU16(LittleEndian), true);
+	let bytes = [3, 0, 0xFF, 0xFE, b'h', 0, b'i', 0];
+	let text: String = read(seeder, &bytes).unwrap();
+	assert_eq!(text, "hi");
+	assert_eq!(write(&seeder, &text).unwrap(), bytes);
+
+	// A byte-swapped BOM overrides the byte order.
+	let text: String = read(seeder, &[3, 0, 0xFE, 0xFF, 0, b'h', 0, b'i']).unwrap();
+	assert_eq!(text, "hi");
+}
+
+#[test]
+fn utf16_errors() {
+	let seeder = Utf16(LittleEndian, TryAsU16(LittleEndian), false);
+	assert_error(
+		read::<String, _>(seeder, &[1, 0, 0x00, 0xD8]),
+		"invalid utf-16",
+	);
+	assert_error(
+		read::<String, _>(seeder, &[2, 0, b'h', 0]),
+		"Tried to read 1 bytes at offset 4, but only 0 remain",
+	);
+}
+
+#[test]
+fn byte_length_prefixed() {
+	let seeder = ByteLengthPrefixed(TryAsU16(LittleEndian), Pair(BigEndian, NulTerminated));
+	let bytes = b"\x05\0\x12\x34ab\0";
+	let value: (u16, Vec<u8>) = read(seeder, bytes).unwrap();
+	assert_eq!(value, (0x1234, b"ab".to_vec()));
+	assert_eq!(write(&seeder, &value).unwrap(), bytes);
+}
+
+#[test]
+fn byte_length_prefixed_errors() {
+	let seeder = ByteLengthPrefixed(TryAsU16(LittleEndian), BigEndian);
+	assert_error(
+		read::<u16, _>(seeder, &[3, 0, 0x12, 0x34, 0x56]),
+		"1 trailing bytes at offset 2 (in ByteLengthPrefixed of 3 bytes)",
+	);
+	assert_error(
+		read::<u16, _>(seeder, &[1, 0, 0x12]),
+		"(in ByteLengthPrefixed of 1 bytes)",
+	);
+}
+
+#[test]
+fn borrowed_buffer() {
+	let seeder = Pair(SerdeLike, BorrowedBuffer);
+	let bytes = [1, 2, 3];
+	let value: (u8, Cow<[u8]>) = read(seeder, &bytes).unwrap();
+	assert!(matches!(value.1, Cow::Borrowed(&[2, 3])));
+	assert_eq!(write(&seeder, &value).unwrap(), bytes);
+}
+
+#[test]
+fn borrowed_buffer_errors() {
+	// Bytes that aren't borrowed from the input are rejected.
+	let bytes = [2, 3];
+	let error = DeSeeder::<Cow<[u8]>>::seed(BorrowedBuffer)
+		.deserialize(de::value::BytesDeserializer::<de::value::Error>::new(
+			&bytes,
+		))
+		.unwrap_err();
+	assert!(error
+		.to_string()
+		.contains("invalid type: byte array, expected remaining borrowed bytes"));
+}
+
+#[test]
+fn try_as_range_errors() {
+	assert_error(
+		write(&TryAsU16(LittleEndian), &70_000),
+		"70000 (usize) is out of range for u16 (0..=65535)",
+	);
+	assert_error(
+		write(&TryAsU32(LittleEndian), &usize::MAX),
+		&format!(
+			"{} (usize) is out of range for u32 (0..=4294967295)",
+			usize::MAX
+		),
+	);
+	assert_error(
+		read::<usize, _>(TryAsI32(LittleEndian), &(-2_i32).to_le_bytes()),
+		&format!("-2 (i32) is out of range for usize (0..={})", usize::MAX),
+	);
+	assert_error(
+		write(&TryAsI32(LittleEndian), &(i32::MAX as usize + 1)),
+		"2147483648 (usize) is out of range for i32 (-2147483648..=2147483647)",
+	);
+}
+
+// `usize` and u64 only overflow each other on targets with pointers that aren't 64 bits wide.
+#[cfg(not(target_pointer_width = "64"))]
+#[test]
+fn try_as_u64_range_errors() {
+	assert_error(
+		read::<usize, _>(TryAsU64(LittleEndian), &u64::MAX.to_le_bytes()),
+		&format!(
+			"18446744073709551615 (u64) is out of range for usize (0..={})",
+			usize::MAX
+		),
+	);
+}
+
+#[test]
+fn peek() {
+	// The chosen seeder sees the peeked count byte again.
+	let seeder = Peek::<1, _>(|&[count]: &[u8; 1]| TupleN(1 + count as usize, SerdeLike));
+	let value: Vec<u8> = read(seeder, &[2, 7, 8]).unwrap();
+	assert_eq!(value, [2, 7, 8]);
+
+	let seeder = Peek::<2, _>(|magic: &[u8; 2]| DynEndian(magic != b"BE"));
+	let value: u32 = read(seeder, b"BE\x12\x34").unwrap();
+	assert_eq!(value, 0x4245_1234);
+	let value: u32 = read(seeder, b"LE\x12\x34").unwrap();
+	assert_eq!(value, 0x3412_454C);
+}
+
+#[test]
+fn peek_errors() {
+	let seeder = Peek::<2, _>(|_: &[u8; 2]| SerdeLike);
+	assert_error(
+		read::<u8, _>(seeder, &[1]),
+		"invalid length 1, expected at least 2 bytes to peek at",
+	);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn timestamp() {
+	let seeder = Timestamp::<_, i32>::unix(LittleEndian, TimeUnit::Seconds);
+	let bytes = 1_600_000_000_i32.to_le_bytes();
+	let value: SystemTime = read(seeder, &bytes).unwrap();
+	assert_eq!(value, UNIX_EPOCH + Duration::from_secs(1_600_000_000));
+	assert_eq!(write(&seeder, &value).unwrap(), bytes);
+
+	// Before the epoch, rounded down when writing.
+	let epoch = UNIX_EPOCH + Duration::from_secs(1000);
+	let seeder = Timestamp::<_, i16>::since(epoch, LittleEndian, TimeUnit::Milliseconds);
+	let value: SystemTime = read(seeder, &(-1500_i16).to_le_bytes()).unwrap();
+	assert_eq!(value, epoch - Duration::from_millis(1500));
+	assert_eq!(
+		write(&seeder, &(value - Duration::from_micros(1))).unwrap(),
+		(-1501_i16).to_le_bytes()
+	);
+
+	let seeder = Timestamp::<_, u16>::unix(LittleEndian, TimeUnit::Microseconds);
+	let value: Duration = read(seeder, &[0xE8, 0x03]).unwrap();
+	assert_eq!(value, Duration::from_millis(1));
+	assert_eq!(write(&seeder, &value).unwrap(), [0xE8, 0x03]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn timestamp_errors() {
+	let seeder = Timestamp::<_, u16>::unix(LittleEndian, TimeUnit::Seconds);
+	assert_error(
+		write(&seeder, &Duration::from_secs(65_536)),
+		&format!(
+			"Timestamp since {:?} is out of range for the Seconds field",
+			UNIX_EPOCH
+		),
+	);
+	let seeder = Timestamp::<_, i8>::unix(SerdeLike, TimeUnit::Seconds);
+	assert_error(
+		read::<Duration, _>(seeder, &[0xFF]),
+		&format!(
+			"Timestamp of -1 Seconds since {:?} is out of range",
+			UNIX_EPOCH
+		),
+	);
+}
+
+#[test]
+fn seq_size_hint() {
+	// Raw seqs run to the end of the input, so a serde value deserializer provides the hint here.
+	let deserializer =
+		de::value::SeqDeserializer::<_, de::value::Error>::new(vec![1_u8, 2, 3].into_iter());
+	let value: Vec<u8> = Seq(SerdeLike).seed().deserialize(deserializer).unwrap();
+	assert_eq!(value, [1, 2, 3]);
+	assert_eq!(value.capacity(), 3);
+
+	// Bogus hints don't allocate more than about 1 MiB up front.
+	let value = <Vec<u64> as DeSeqable>::from_hinted::<_, de::value::Error>(
+		iter::empty(),
+		Some(usize::MAX),
+	)
+	.unwrap();
+	assert_eq!(value.capacity(), 1024 * 1024 / 8);
+}
+
+#[test]
+fn bits() {
+	let seeder = Bits::<2, _, u8>::new([4, 4], SerdeLike);
+	let value: (u8, u8) = read(seeder, &[0xAB]).unwrap();
+	assert_eq!(value, (0xA, 0xB));
+	assert_eq!(write(&seeder, &value).unwrap(), [0xAB]);
+
+	// RGB565
+	let seeder = Bits::<3, _, u16>::new([5, 6, 5], BigEndian);
+	let value: [u8; 3] = read(seeder, &[0xF8, 0x1F]).unwrap();
+	assert_eq!(value, [0x1F, 0, 0x1F]);
+	assert_eq!(write(&seeder, &value).unwrap(), [0xF8, 0x1F]);
+}
+
+#[test]
+fn bits_errors() {
+	let seeder = Bits::<2, _, u8>::new([4, 3], SerdeLike);
+	assert_error(
+		read::<(u8, u8), _>(seeder, &[0]),
+		"Bits field widths [4, 3] add up to 7 instead of the container's 8 bits",
+	);
+	assert_error(
+		write(&seeder, &(0_u8, 0_u8)),
+		"Bits field widths [4, 3] add up to 7 instead of the container's 8 bits",
+	);
+
+	let seeder = Bits::<2, _, u8>::new([4, 4], SerdeLike);
+	assert_error(
+		write(&seeder, &(0x10_u8, 0_u8)),
+		"Bits field 0 value 16 doesn't fit into 4 bits",
+	);
+	let seeder = Bits::<2, _, u16>::new([12, 4], BigEndian);
+	assert_error(
+		read::<(u8, u8), _>(seeder, &[0xFF, 0xF0]),
+		"Bits fields [4095, 0] don't fit the target integer types",
+	);
+}
+
+#[test]
+fn seed_deserialize_and_serialize() {
+	let mut deserializer = raw::Deserializer::new(&[0x12, 0x34]);
+	let value: u16 = seed_deserialize(BigEndian, &mut deserializer).unwrap();
+	assert_eq!(value, 0x1234);
+	deserializer.end().unwrap();
+
+	let mut serializer = raw::Serializer::new();
+	seed_serialize(&BigEndian, &value, &mut serializer).unwrap();
+	assert_eq!(serializer.into_inner(), [0x12, 0x34]);
+}
+
+#[test]
+fn if_() {
+	let value: Option<u16> = read(If(true, BigEndian), &[0x12, 0x34]).unwrap();
+	assert_eq!(value, Some(0x1234));
+	assert_eq!(write(&If(true, BigEndian), &value).unwrap(), [0x12, 0x34]);
+
+	let value: Option<u16> = read(If(false, BigEndian), &[]).unwrap();
+	assert_eq!(value, None);
+	assert!(write(&If(false, BigEndian), &value).unwrap().is_empty());
+}
+
+#[test]
+fn if_errors() {
+	assert_error(
+		write(&If(true, BigEndian), &None::<u16>),
+		"Tried to serialise If(true, _) from None",
+	);
+	assert_error(
+		write(&If(false, BigEndian), &Some(1_u16)),
+		"Tried to serialise If(false, _) from Some(_)",
+	);
+	assert_error(
+		read::<Option<u16>, _>(If(true, BigEndian), &[0x12]),
+		"Tried to read 1 bytes at offset 1, but only 0 remain",
+	);
+}
+
+#[cfg(feature = "encoding")]
+#[test]
+fn ascii_and_utf8() {
+	let seeder = Ascii(FixedString(4, 0));
+	let text: String = read(seeder, b"ab\0\0").unwrap();
+	assert_eq!(text, "ab");
+	assert_eq!(write(&seeder, &text).unwrap(), b"ab\0\0");
+
+	let seeder = Utf8(NulTerminated);
+	let text: String = read(seeder, b"a\xC3\xA4\0").unwrap();
+	assert_eq!(text, "a\u{E4}");
+	assert_eq!(write(&seeder, &text).unwrap(), b"a\xC3\xA4\0");
+}
+
+#[cfg(feature = "encoding")]
+#[test]
+fn ascii_and_utf8_errors() {
+	assert_error(
+		read::<String, _>(Ascii(FixedString(4, 0)), b"a\x80\0\0"),
+		"Invalid ascii at byte offset 1",
+	);
+	assert_error(
+		read::<String, _>(Utf8(NulTerminated), b"ab\xC3\0"),
+		"Invalid utf-8 at byte offset 2",
+	);
+}
+
+#[test]
+fn reserved() {
+	read::<(), _>(Reserved(2, 0xFF), &[0xFF, 0xFF]).unwrap();
+	assert_eq!(write(&Reserved(2, 0xFF), &()).unwrap(), [0xFF, 0xFF]);
+}
+
+#[test]
+fn reserved_errors() {
+	assert_error(
+		read::<(), _>(Reserved(2, 0xFF), &[0xFF, 0]),
+		"Expected reserved byte FF at offset 1 but found 00",
+	);
+	// The offset is counted from the start of the input.
+	assert_error(
+		read::<(u8, ()), _>(Tupled((SerdeLike, Reserved(2, 0))), &[7, 0, 1]),
+		"Expected reserved byte 00 at offset 2 but found 01",
+	);
+}
+
+/// Sum of all bytes, truncated to 8 bits.
+#[derive(Debug, Copy, Clone, Default)]
+struct Sum8;
+impl Checksum for Sum8 {
+	type Digest = u8;
+	const NAME: &'static str = "Sum8";
+	fn checksum(bytes: &[u8]) -> u8 {
+		bytes.iter().fold(0, |sum, byte| sum.wrapping_add(*byte))
+	}
+}
+
+#[test]
+fn checksummed() {
+	assert_eq!(adler32(b"Wikipedia"), 0x11E6_0398);
+
+	let seeder = Adler32(FixedString(9, 0), BigEndian);
+	let bytes = b"Wikipedia\x11\xE6\x03\x98";
+	let value: Vec<u8> = read(seeder, bytes).unwrap();
+	assert_eq!(value, b"Wikipedia");
+	assert_eq!(write(&seeder, &value).unwrap(), bytes);
+
+	let seeder = Checksummed::<_, _, Sum8>::new(TupleN(2, SerdeLike), SerdeLike);
+	let value: Vec<u8> = read(seeder, &[0xF0, 0x20, 0x10]).unwrap();
+	assert_eq!(value, [0xF0, 0x20]);
+	assert_eq!(write(&seeder, &value).unwrap(), [0xF0, 0x20, 0x10]);
+}
+
+#[test]
+fn checksummed_errors() {
+	assert_error(
+		read::<Vec<u8>, _>(
+			Adler32(FixedString(9, 0), BigEndian),
+			b"Wikipedib\x11\xE6\x03\x98",
+		),
+		"Adler-32 mismatch: stored 11E60398, computed",
+	);
+	let seeder = Checksummed::<_, _, Sum8>::new(TupleN(2, SerdeLike), SerdeLike);
+	assert_error(
+		read::<Vec<u8>, _>(seeder, &[0xF0, 0x20, 0x11]),
+		"Sum8 mismatch: stored 00000011, computed 00000010",
+	);
+	assert_error(read::<Vec<u8>, _>(seeder, &[0xF0]), "(in Sum8)");
+}
+
+#[cfg(feature = "half")]
+#[test]
+fn ieee754_half() {
+	use half::{bf16, f16};
+
+	for (value, bytes) in [
+		(f16::ONE, [0x00, 0x3C]),
+		(f16::INFINITY, [0x00, 0x7C]),
+		(f16::NEG_INFINITY, [0x00, 0xFC]),
+		(f16::MIN_POSITIVE_SUBNORMAL, [0x01, 0x00]),
+	] {
+		assert_eq!(
+			read::<f16, _>(IEEE754(LittleEndian), &bytes).unwrap(),
+			value
+		);
+		assert_eq!(write(&IEEE754(LittleEndian), &value).unwrap(), bytes);
+	}
+	for (value, bytes) in [
+		(bf16::ONE, [0x3F, 0x80]),
+		(bf16::INFINITY, [0x7F, 0x80]),
+		(bf16::NEG_INFINITY, [0xFF, 0x80]),
+		(bf16::MIN_POSITIVE_SUBNORMAL, [0x00, 0x01]),
+	] {
+		assert_eq!(read::<bf16, _>(IEEE754(BigEndian), &bytes).unwrap(), value);
+		assert_eq!(write(&IEEE754(BigEndian), &value).unwrap(), bytes);
+	}
+
+	// NaN payloads survive the round trip bit for bit.
+	let value: f16 = read(IEEE754(LittleEndian), &[0x01, 0x7E]).unwrap();
+	assert!(value.is_nan());
+	assert_eq!(value.to_bits(), 0x7E01);
+	assert_eq!(write(&IEEE754(LittleEndian), &value).unwrap(), [0x01, 0x7E]);
+	let value: bf16 = read(IEEE754(BigEndian), &[0xFF, 0xC1]).unwrap();
+	assert!(value.is_nan());
+	assert_eq!(write(&IEEE754(BigEndian), &value).unwrap(), [0xFF, 0xC1]);
+}
+
+#[test]
+fn padded_tuple() {
+	let seeder = PaddedTuple(4, 0_u16, BigEndian, true);
+	let bytes = [0, 1, 0, 2, 0, 0, 0, 0];
+	let value: Vec<u16> = read(seeder, &bytes).unwrap();
+	assert_eq!(value, [1, 2]);
+	assert_eq!(write(&seeder, &value).unwrap(), bytes);
+
+	// Untrimmed, the fill items are kept.
+	let value: Vec<u16> = read(PaddedTuple(4, 0_u16, BigEndian, false), &bytes).unwrap();
+	assert_eq!(value, [1, 2, 0, 0]);
+}
+
+#[test]
+fn padded_tuple_errors() {
+	let seeder = PaddedTuple(2, 0_u16, BigEndian, true);
+	assert_error(
+		write(&seeder, &vec![1, 2, 3]),
+		"Can't serialize 3 items as PaddedTuple of 2",
+	);
+	assert_error(
+		read::<Vec<u16>, _>(seeder, &[0, 1, 0]),
+		"Tried to read 1 bytes at offset 3, but only 0 remain",
+	);
+}
+
+#[cfg(feature = "encoding")]
+#[test]
+fn encoding_str_types() {
+	let seeder = Windows1252(NulTerminated);
+	let value: Cow<str> = read(seeder, b"\x80\0").unwrap();
+	assert_eq!(value, "\u{20AC}");
+	assert_eq!(write(&seeder, &value).unwrap(), b"\x80\0");
+	assert_eq!(write(&seeder, &"\u{20AC}").unwrap(), b"\x80\0");
+	assert_error(
+		write(&seeder, &"\u{3042}"),
+		"Can't encode '\u{3042}' at byte offset 0 as windows-1252",
+	);
+}
+
+#[test]
+fn validated() {
+	let seeder = Validated(BigEndian, in_range(1_u16..=10), true);
+	let value: u16 = read(seeder.clone(), &[0, 10]).unwrap();
+	assert_eq!(value, 10);
+	assert_eq!(write(&seeder, &value).unwrap(), [0, 10]);
+
+	// Unchecked when writing.
+	let seeder = Validated(BigEndian, in_range(1_u16..=10), false);
+	assert_eq!(write(&seeder, &11).unwrap(), [0, 11]);
+}
+
+#[test]
+fn validated_errors() {
+	let seeder = Validated(BigEndian, in_range(1_u16..=10), true);
+	assert_error(
+		read::<u16, _>(seeder.clone(), &[0, 11]),
+		"11 is out of range 1..=10",
+	);
+	assert_error(write(&seeder, &0), "0 is out of range 1..=10");
+
+	let even = |value: &u8| {
+		if value % 2 == 0 {
+			Ok(())
+		} else {
+			Err(format!("{} is odd", value))
+		}
+	};
+	assert_error(
+		read::<u8, _>(Validated(SerdeLike, even, true), &[3]),
+		"3 is odd",
+	);
+}
+
+#[test]
+fn tupled() {
+	let seeder = Tupled((SerdeLike, BigEndian, LittleEndian, NulTerminated));
+	let bytes = b"\x01\x12\x34\x78\x56ab\0";
+	let value: (u8, u16, u16, Vec<u8>) = read(seeder, bytes).unwrap();
+	assert_eq!(value, (1, 0x1234, 0x5678, b"ab".to_vec()));
+	assert_eq!(write(&seeder, &value).unwrap(), bytes);
+}
+
+#[test]
+fn tupled_errors() {
+	let seeder = Tupled((SerdeLike, BigEndian));
+	assert_error(
+		read::<(u8, u16), _>(seeder, &[1, 0x12]),
+		"Tried to read 1 bytes at offset 2, but only 0 remain",
+	);
+	assert_error(
+		write(&Tupled((SerdeLike, NulTerminated)), &(1_u8, b"\0".to_vec())),
+		"Can't serialize interior NUL at index 0 as NulTerminated",
+	);
+}
+
+#[test]
+fn byte_length_prefixed_items() {
+	let seeder = ByteLengthPrefixed::items(TryAsU16(LittleEndian), NulTerminated);
+	let bytes = b"\x05\0a\0bc\0";
+	let value: Vec<Vec<u8>> = read(seeder, bytes).unwrap();
+	assert_eq!(value, [b"a".to_vec(), b"bc".to_vec()]);
+	assert_eq!(write(&seeder, &value).unwrap(), bytes);
+}
+
+#[test]
+fn byte_length_prefixed_items_errors() {
+	let seeder = ByteLengthPrefixed::items(TryAsU16(LittleEndian), NulTerminated);
+	assert_error(
+		read::<Vec<Vec<u8>>, _>(seeder, b"\x03\0a\0bc\0"),
+		"Missing NUL terminator (in ByteLengthPrefixed of 3 bytes)",
+	);
+}
+
+#[derive(Debug, PartialEq)]
+enum Keyword {
+	Begin,
+	End,
+}
+impl StringEnumerable for Keyword {
+	const TOKENS: &'static [&'static str] = &["begin", "end"];
+	fn from_index(index: usize) -> Self {
+		match index {
+			0 => Keyword::Begin,
+			_ => Keyword::End,
+		}
+	}
+	fn index(&self) -> usize {
+		match self {
+			Keyword::Begin => 0,
+			Keyword::End => 1,
+		}
+	}
+}
+
+#[test]
+fn string_enum() {
+	let value: Vec<Keyword> = read(Seq(StringEnum(NulTerminated)), b"begin\0end\0").unwrap();
+	assert_eq!(value, [Keyword::Begin, Keyword::End]);
+	assert_eq!(
+		write(&Seq(StringEnum(NulTerminated)), &value).unwrap(),
+		b"begin\0end\0"
+	);
+}
+
+#[test]
+fn string_enum_errors() {
+	assert_error(
+		read::<Keyword, _>(StringEnum(NulTerminated), b"middle\0"),
+		"unknown variant `middle`, expected `begin` or `end`",
+	);
+	assert_error(
+		read::<Keyword, _>(StringEnum(NulTerminated), b"begin"),
+		"Missing NUL terminator",
+	);
+}
+
+/// A chunk ID by token index, including one that's too long for a [`FourCC`].
+#[derive(Debug, PartialEq)]
+struct ChunkId(usize);
+impl StringEnumerable for ChunkId {
+	const TOKENS: &'static [&'static str] = &["RIFF", "LIST", "JUNK!"];
+	fn from_index(index: usize) -> Self {
+		ChunkId(index)
+	}
+	fn index(&self) -> usize {
+		self.0
+	}
+}
+
+#[test]
+fn four_cc() {
+	let value: (ChunkId, ChunkId) = read(Pair(FourCC, FourCC), b"LISTRIFF").unwrap();
+	assert_eq!(value, (ChunkId(1), ChunkId(0)));
+	assert_eq!(write(&Pair(FourCC, FourCC), &value).unwrap(), b"LISTRIFF");
+}
+
+#[test]
+fn four_cc_errors() {
+	assert_error(
+		read::<ChunkId, _>(FourCC, b"WAVE"),
+		"unknown variant `WAVE`, expected one of `RIFF`, `LIST`, `JUNK!`",
+	);
+	assert_error(
+		read::<ChunkId, _>(FourCC, b"RI"),
+		"Tried to read 1 bytes at offset 2, but only 0 remain",
+	);
+	assert_error(
+		write(&FourCC, &ChunkId(2)),
+		"Tried to serialise TupleN(4, _) from a .len = 5",
+	);
+	assert_error(
+		write(&FourCC, &ChunkId(3)),
+		"StringEnum index 3 is out of range for the tokens [\"RIFF\", \"LIST\", \"JUNK!\"]",
+	);
+}
+
+/// Integer fields of every width in a `#[seeded]` struct, as [`ByteOrdered`] is meant to be used.
+#[derive(Debug, PartialEq, serde_seeded::seed, serde_seeded::seeded)]
+struct Widths {
+	#[seeded(LittleEndian)]
+	small: u8,
+	#[seeded(LittleEndian)]
+	version: u16,
+	#[seeded(BigEndian)]
+	delta: i16,
+	#[seeded(LittleEndian)]
+	size: u64,
+	#[seeded(BigEndian)]
+	id: i128,
+}
+
+#[test]
+fn seeded_struct_widths() {
+	let bytes = [
+		0x01, 0x34, 0x12, 0xFF, 0xFE, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0x2A,
+	];
+	let value = Widths {
+		small: 1,
+		version: 0x1234,
+		delta: -2,
+		size: 0x0102_0304_0506_0708,
+		id: 42,
+	};
+	assert_eq!(raw::from_slice(Widths::seed(), &bytes).unwrap(), value);
+	assert_eq!(raw::to_vec(&value.seeded()).unwrap(), bytes);
+}
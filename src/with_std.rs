@@ -0,0 +1,592 @@
+//! Seeders that depend on [`std`], available with the `"std"` feature.
+
+use super::*;
+use std::{
+	any::{Any, TypeId},
+	collections::HashMap,
+	time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+impl<K: Debug + Eq + Hash, V> DeMapable for HashMap<K, V> {
+	type Key = K;
+	type Value = V;
+	fn contains_key(&self, key: &K) -> bool {
+		HashMap::contains_key(self, key)
+	}
+	fn insert(&mut self, key: K, value: V) {
+		HashMap::insert(self, key, value);
+	}
+}
+/// The entries are sorted by key, since the iteration order differs between runs.
+impl<K: Ord, V> SerMapable for HashMap<K, V> {
+	type Key = K;
+	type Value = V;
+	fn len(&self) -> usize {
+		HashMap::len(self)
+	}
+	fn to<SerializeTuple: ser::SerializeTuple, KeySeeder, ValueSeeder>(
+		&self,
+		serialize_tuple: &mut SerializeTuple,
+		key_seeder: &KeySeeder,
+		value_seeder: &ValueSeeder,
+	) -> Result<(), SerializeTuple::Error>
+	where
+		KeySeeder: SerSeeder<K>,
+		ValueSeeder: SerSeeder<V>,
+	{
+		let mut entries: Vec<_> = self.iter().collect();
+		entries.sort_unstable_by_key(|(key, _)| *key);
+		for (key, value) in entries {
+			serialize_tuple.serialize_element(&key_seeder.seeded(key))?;
+			serialize_tuple.serialize_element(&value_seeder.seeded(value))?;
+		}
+		Ok(())
+	}
+}
+
+/// [`Vec<_>`] alongside a key→index map built from its items.  
+/// The map is not stored, so only the [`Vec<_>`] is written.  
+/// (Parameters: `Fn(&Item) -> Key`, [`Vec<_>`] [`Seeder`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct IndexedSeq<KeyFn, ItemsSeeder>(pub KeyFn, pub ItemsSeeder);
+impl<'de, Item, Key: Eq + Hash, KeyFn: Fn(&Item) -> Key, ItemsSeeder: DeSeeder<'de, Vec<Item>>>
+	DeSeeder<'de, (Vec<Item>, HashMap<Key, usize>)> for IndexedSeq<KeyFn, ItemsSeeder>
+{
+	type Seed = IndexedSeqSeed<KeyFn, ItemsSeeder, Item, Key>;
+	fn seed(self) -> Self::Seed {
+		IndexedSeqSeed(self.0, self.1, PhantomData)
+	}
+}
+impl<Item, Key, KeyFn, ItemsSeeder: SerSeeder<Vec<Item>>>
+	SerSeeder<(Vec<Item>, HashMap<Key, usize>)> for IndexedSeq<KeyFn, ItemsSeeder>
+{
+	fn seeded<'s>(&'s self, value: &'s (Vec<Item>, HashMap<Key, usize>)) -> Seeded<'s> {
+		self.1.seeded(&value.0)
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct IndexedSeqSeed<KeyFn, ItemsSeeder, Item, Key>(
+	KeyFn,
+	ItemsSeeder,
+	PhantomData<(Item, Key)>,
+);
+impl<'de, Item, Key: Eq + Hash, KeyFn: Fn(&Item) -> Key, ItemsSeeder: DeSeeder<'de, Vec<Item>>>
+	de::DeserializeSeed<'de> for IndexedSeqSeed<KeyFn, ItemsSeeder, Item, Key>
+{
+	type Value = (Vec<Item>, HashMap<Key, usize>);
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let items = self.1.seed().deserialize(deserializer)?;
+		let mut index = HashMap::with_capacity(items.len());
+		for (i, item) in items.iter().enumerate() {
+			if index.insert(self.0(item), i).is_some() {
+				return Err(de::Error::custom(format_args!(
+					"Duplicate key in IndexedSeq at index {}",
+					i
+				)));
+			}
+		}
+		Ok((items, index))
+	}
+}
+
+/// Runtime table of value layouts by tag, for use with [`Registered`].
+///
+/// ```ignore
+/// let mut registry = Registry::new();
+/// registry
+///     .register::<u32, _>(1, LittleEndian)
+///     .register::<f32, _>(2, IEEE754(LittleEndian));
+///
+/// let value: Box<dyn Any> = Registered(LittleEndian, &registry).seed().deserialize(deserializer)?;
+/// if let Some(float) = value.downcast_ref::<f32>() {
+///     // …
+/// }
+/// ```
+pub struct Registry<Tag> {
+	entries: HashMap<Tag, Box<dyn RegistryEntry>>,
+}
+impl<Tag> Registry<Tag> {
+	pub fn new() -> Self {
+		Self {
+			entries: HashMap::new(),
+		}
+	}
+}
+impl<Tag: Eq + Hash + Debug> Registry<Tag> {
+	/// Registers `seeder` as layout of `T` under `tag`, replacing any previous registration of `tag`.  
+	/// Deserialized values can be recovered with [`Box::<dyn Any>::downcast`] to `T`.
+	///
+	/// # Panics
+	///
+	/// Iff `T` is already registered under a different tag, since serialization couldn't choose between them.
+	pub fn register<T: Any, S: 'static + Clone + for<'de> DeSeeder<'de, T> + SerSeeder<T>>(
+		&mut self,
+		tag: Tag,
+		seeder: S,
+	) -> &mut Self {
+		if let Some((registered, _)) = self.entries.iter().find(|(registered, entry)| {
+			entry.value_type_id() == TypeId::of::<T>() && **registered != tag
+		}) {
+			panic!(
+				"Tried to register {} under tag {:?}, but it's already registered under tag {:?}",
+				core::any::type_name::<T>(),
+				tag,
+				registered
+			)
+		}
+		self.entries
+			.insert(tag, Box::new(Entry::<T, S>(seeder, PhantomData)));
+		self
+	}
+}
+impl<Tag> Default for Registry<Tag> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+impl<Tag: Debug> Debug for Registry<Tag> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.debug_set().entries(self.entries.keys()).finish()
+	}
+}
+
+trait RegistryEntry {
+	fn value_type_id(&self) -> TypeId;
+	fn deserialize<'de>(
+		&self,
+		deserializer: &mut dyn erased_serde::Deserializer<'de>,
+	) -> Result<Box<dyn Any>, erased_serde::Error>;
+	fn seeded<'s>(&'s self, value: &'s dyn Any) -> Seeded<'s>;
+}
+
+struct Entry<T, S>(S, PhantomData<fn() -> T>);
+impl<T: Any, S: Clone + for<'de> DeSeeder<'de, T> + SerSeeder<T>> RegistryEntry for Entry<T, S> {
+	fn value_type_id(&self) -> TypeId {
+		TypeId::of::<T>()
+	}
+	fn deserialize<'de>(
+		&self,
+		deserializer: &mut dyn erased_serde::Deserializer<'de>,
+	) -> Result<Box<dyn Any>, erased_serde::Error> {
+		self.0
+			.clone()
+			.seed()
+			.deserialize(deserializer)
+			.map(|value| Box::new(value) as Box<dyn Any>)
+	}
+	fn seeded<'s>(&'s self, value: &'s dyn Any) -> Seeded<'s> {
+		self.0.seeded(
+			value
+				.downcast_ref()
+				.expect("Registry entries are only used with their own type"),
+		)
+	}
+}
+
+/// Tagged value whose layout is looked up in a [`Registry`], as [`Box<dyn Any>`].  
+/// Values of unregistered types can't be serialised.  
+/// (Parameters: tag [`Seeder`], `&Registry`)
+#[derive(Debug, Copy, Clone)]
+pub struct Registered<'a, TagSeeder, Tag>(pub TagSeeder, pub &'a Registry<Tag>);
+impl<'a, 'de, TagSeeder: DeSeeder<'de, Tag>, Tag: Eq + Hash + Debug> DeSeeder<'de, Box<dyn Any>>
+	for Registered<'a, TagSeeder, Tag>
+{
+	type Seed = Self;
+	fn seed(self) -> Self::Seed {
+		self
+	}
+}
+impl<'a, TagSeeder: SerSeeder<Tag>, Tag> SerSeeder<Box<dyn Any>>
+	for Registered<'a, TagSeeder, Tag>
+{
+	fn seeded<'s>(&'s self, value: &'s Box<dyn Any>) -> Seeded<'s> {
+		Box::new(RegisteredSeeded(&**value, &self.0, self.1))
+	}
+}
+impl<'a, 'de, TagSeeder: DeSeeder<'de, Tag>, Tag: Eq + Hash + Debug> de::DeserializeSeed<'de>
+	for Registered<'a, TagSeeder, Tag>
+{
+	type Value = Box<dyn Any>;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<'a, TagSeeder, Tag>(TagSeeder, &'a Registry<Tag>);
+		impl<'a, 'de, TagSeeder: DeSeeder<'de, Tag>, Tag: Eq + Hash + Debug> de::Visitor<'de>
+			for Visitor<'a, TagSeeder, Tag>
+		{
+			type Value = Box<dyn Any>;
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "tag and registered value")
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let tag = seq
+					.next_element_seed(self.0.seed())?
+					.ok_or_else(|| de::Error::invalid_length(0, &"tag and registered value"))?;
+				let entry =
+					self.1.entries.get(&tag).ok_or_else(|| {
+						de::Error::custom(format_args!("Unregistered tag {:?}", tag))
+					})?;
+				trace!("Deserializing registered {:?}...", tag);
+				seq.next_element_seed(RegistryEntrySeed(&**entry))?
+					.ok_or_else(|| de::Error::invalid_length(1, &"tag and registered value"))
+			}
+		}
+
+		deserializer.deserialize_tuple(2, Visitor(self.0, self.1))
+	}
+}
+
+struct RegistryEntrySeed<'a>(&'a dyn RegistryEntry);
+impl<'a, 'de> de::DeserializeSeed<'de> for RegistryEntrySeed<'a> {
+	type Value = Box<dyn Any>;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let mut deserializer = <dyn erased_serde::Deserializer>::erase(deserializer);
+		self.0
+			.deserialize(&mut deserializer)
+			.map_err(de::Error::custom)
+	}
+}
+
+#[doc(hidden)]
+pub struct RegisteredSeeded<'a, TagSeeder, Tag>(&'a dyn Any, &'a TagSeeder, &'a Registry<Tag>);
+impl<'a, TagSeeder: SerSeeder<Tag>, Tag> ser::Serialize for RegisteredSeeded<'a, TagSeeder, Tag> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let (tag, entry) = self
+			.2
+			.entries
+			.iter()
+			.find(|(_, entry)| entry.value_type_id() == self.0.type_id())
+			.ok_or_else(|| ser::Error::custom("Tried to serialise unregistered type"))?;
+		let mut serialize_tuple = serializer.serialize_tuple(2)?;
+		serialize_tuple.serialize_element(&self.1.seeded(tag))?;
+		serialize_tuple.serialize_element(&entry.seeded(self.0))?;
+		serialize_tuple.end()
+	}
+}
+
+/// [`SystemTime`] as nanoseconds since the Unix epoch.  
+/// (Parameters: u64 [`Seeder`])
+#[derive(Debug, Copy, Clone, Default)]
+pub struct UnixNanos<U64Seeder>(pub U64Seeder);
+impl<'de, U64Seeder: DeSeeder<'de, u64>> DeSeeder<'de, SystemTime> for UnixNanos<U64Seeder> {
+	type Seed = UnixNanosSeed<U64Seeder>;
+	fn seed(self) -> Self::Seed {
+		UnixNanosSeed(self.0)
+	}
+}
+impl<U64Seeder: SerSeeder<u64>> SerSeeder<SystemTime> for UnixNanos<U64Seeder> {
+	fn seeded<'s>(&'s self, value: &'s SystemTime) -> Seeded<'s> {
+		Box::new(UnixNanosSeeded(value, &self.0))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct UnixNanosSeed<U64Seeder>(U64Seeder);
+impl<'de, U64Seeder: DeSeeder<'de, u64>> de::DeserializeSeed<'de> for UnixNanosSeed<U64Seeder> {
+	type Value = SystemTime;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let nanos = self.0.seed().deserialize(deserializer)?;
+		UNIX_EPOCH
+			.checked_add(Duration::from_nanos(nanos))
+			.ok_or_else(|| {
+				de::Error::invalid_value(
+					de::Unexpected::Unsigned(nanos),
+					&"nanoseconds since the Unix epoch within SystemTime's range",
+				)
+			})
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct UnixNanosSeeded<'a, U64Seeder>(&'a SystemTime, &'a U64Seeder);
+impl<'a, U64Seeder: SerSeeder<u64>> ser::Serialize for UnixNanosSeeded<'a, U64Seeder> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		self.0
+			.duration_since(UNIX_EPOCH)
+			.map_err(ser::Error::custom)?
+			.as_nanos()
+			.pipe(u64::try_from)
+			.map_err(ser::Error::custom)?
+			.pipe(|nanos| self.1.seeded(&nanos).serialize(serializer))
+	}
+}
+
+/// Resolution of a [`Timestamp`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum TimeUnit {
+	#[default]
+	Seconds,
+	Milliseconds,
+	Microseconds,
+	Nanoseconds,
+}
+impl TimeUnit {
+	fn nanos(self) -> i128 {
+		match self {
+			TimeUnit::Seconds => 1_000_000_000,
+			TimeUnit::Milliseconds => 1_000_000,
+			TimeUnit::Microseconds => 1_000,
+			TimeUnit::Nanoseconds => 1,
+		}
+	}
+}
+
+/// [`SystemTime`] (or [`Duration`]) as integer count of [`TimeUnit`]s since an epoch.  
+/// Signed integers can store times before the epoch. Values that don't fit either side are an error,
+/// and times between two units are rounded down when writing.  
+/// (Usage: [`Timestamp::unix(int_seeder, unit)`], [`Timestamp::since(epoch, int_seeder, unit)`])
+#[derive(Debug, Copy, Clone)]
+pub struct Timestamp<IntSeeder, Int>(IntSeeder, SystemTime, TimeUnit, PhantomData<Int>);
+impl<IntSeeder, Int> Timestamp<IntSeeder, Int> {
+	/// Counts from [`UNIX_EPOCH`].
+	pub fn unix(int_seeder: IntSeeder, unit: TimeUnit) -> Self {
+		Self::since(UNIX_EPOCH, int_seeder, unit)
+	}
+
+	/// Counts from a custom `epoch`. [`Duration`]s ignore it.
+	pub fn since(epoch: SystemTime, int_seeder: IntSeeder, unit: TimeUnit) -> Self {
+		Self(int_seeder, epoch, unit, PhantomData)
+	}
+}
+impl<'de, T: Timestampable, IntSeeder: DeSeeder<'de, Int>, Int: Into<i128> + Copy + Display>
+	DeSeeder<'de, T> for Timestamp<IntSeeder, Int>
+{
+	type Seed = TimestampSeed<T, IntSeeder, Int>;
+	fn seed(self) -> Self::Seed {
+		TimestampSeed(self.0, self.1, self.2, PhantomData)
+	}
+}
+impl<T: Timestampable, IntSeeder: SerSeeder<Int>, Int: TryFrom<i128>> SerSeeder<T>
+	for Timestamp<IntSeeder, Int>
+{
+	fn seeded<'s>(&'s self, value: &'s T) -> Seeded<'s> {
+		Box::new(TimestampSeeded(value, &self.0, self.1, self.2, PhantomData))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone)]
+pub struct TimestampSeed<T, IntSeeder, Int>(IntSeeder, SystemTime, TimeUnit, PhantomData<(T, Int)>);
+impl<'de, T: Timestampable, IntSeeder: DeSeeder<'de, Int>, Int: Into<i128> + Copy + Display>
+	de::DeserializeSeed<'de> for TimestampSeed<T, IntSeeder, Int>
+{
+	type Value = T;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let TimestampSeed(int_seeder, epoch, unit, _) = self;
+		let repr = int_seeder.seed().deserialize(deserializer)?;
+		repr.into()
+			.checked_mul(unit.nanos())
+			.and_then(|nanos| T::from_nanos(nanos, epoch))
+			.ok_or_else(|| {
+				de::Error::custom(format_args!(
+					"Timestamp of {} {:?} since {:?} is out of range",
+					repr, unit, epoch
+				))
+			})
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone)]
+pub struct TimestampSeeded<'a, T, IntSeeder, Int>(
+	&'a T,
+	&'a IntSeeder,
+	SystemTime,
+	TimeUnit,
+	PhantomData<Int>,
+);
+impl<'a, T: Timestampable, IntSeeder: SerSeeder<Int>, Int: TryFrom<i128>> ser::Serialize
+	for TimestampSeeded<'a, T, IntSeeder, Int>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let TimestampSeeded(value, int_seeder, epoch, unit, _) = *self;
+		value
+			.to_nanos(epoch)
+			.and_then(|nanos| Int::try_from(nanos.div_euclid(unit.nanos())).ok())
+			.ok_or_else(|| {
+				ser::Error::custom(format_args!(
+					"Timestamp since {:?} is out of range for the {:?} field",
+					epoch, unit
+				))
+			})?
+			.pipe(|repr| int_seeder.seeded(&repr).serialize(serializer))
+	}
+}
+
+/// See [`Timestamp`].
+pub trait Timestampable: Sized {
+	fn from_nanos(nanos: i128, epoch: SystemTime) -> Option<Self>;
+	fn to_nanos(&self, epoch: SystemTime) -> Option<i128>;
+}
+
+impl Timestampable for SystemTime {
+	fn from_nanos(nanos: i128, epoch: SystemTime) -> Option<Self> {
+		let magnitude = duration_from_nanos(nanos.unsigned_abs())?;
+		if nanos < 0 {
+			epoch.checked_sub(magnitude)
+		} else {
+			epoch.checked_add(magnitude)
+		}
+	}
+	fn to_nanos(&self, epoch: SystemTime) -> Option<i128> {
+		match self.duration_since(epoch) {
+			Ok(after) => i128::try_from(after.as_nanos()).ok(),
+			Err(before) => i128::try_from(before.duration().as_nanos())
+				.ok()
+				.map(|n| -n),
+		}
+	}
+}
+
+impl Timestampable for Duration {
+	fn from_nanos(nanos: i128, _epoch: SystemTime) -> Option<Self> {
+		u128::try_from(nanos).ok().and_then(duration_from_nanos)
+	}
+	fn to_nanos(&self, _epoch: SystemTime) -> Option<i128> {
+		i128::try_from(self.as_nanos()).ok()
+	}
+}
+
+fn duration_from_nanos(nanos: u128) -> Option<Duration> {
+	let secs = u64::try_from(nanos / 1_000_000_000).ok()?;
+	Duration::new(secs, (nanos % 1_000_000_000) as u32).pipe(Some)
+}
+
+/// GUID-keyed properties, stored as count followed by [`Guid`]/value pairs.  
+/// Properties are written in key order.  
+/// (Parameters: count [`Seeder`], value [`Seeder`])
+#[cfg(feature = "uuid")]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct PropertyBag<CountSeeder, ValueSeeder>(pub CountSeeder, pub ValueSeeder);
+#[cfg(feature = "uuid")]
+impl<'de, V, CountSeeder: DeSeeder<'de, usize>, ValueSeeder: Clone + DeSeeder<'de, V>>
+	DeSeeder<'de, HashMap<uuid::Uuid, V>> for PropertyBag<CountSeeder, ValueSeeder>
+{
+	type Seed = PropertyBagSeed<V, CountSeeder, ValueSeeder>;
+	fn seed(self) -> Self::Seed {
+		PropertyBagSeed(self.0, self.1, PhantomData)
+	}
+}
+#[cfg(feature = "uuid")]
+impl<V, CountSeeder: SerSeeder<usize>, ValueSeeder: SerSeeder<V>> SerSeeder<HashMap<uuid::Uuid, V>>
+	for PropertyBag<CountSeeder, ValueSeeder>
+{
+	fn seeded<'s>(&'s self, value: &'s HashMap<uuid::Uuid, V>) -> Seeded<'s> {
+		Box::new(PropertyBagSeeded(value, &self.0, &self.1))
+	}
+}
+
+#[cfg(feature = "uuid")]
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct PropertyBagSeed<V, CountSeeder, ValueSeeder>(CountSeeder, ValueSeeder, PhantomData<V>);
+#[cfg(feature = "uuid")]
+impl<'de, V, CountSeeder: DeSeeder<'de, usize>, ValueSeeder: Clone + DeSeeder<'de, V>>
+	de::DeserializeSeed<'de> for PropertyBagSeed<V, CountSeeder, ValueSeeder>
+{
+	type Value = HashMap<uuid::Uuid, V>;
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor<V, CountSeeder, ValueSeeder>(CountSeeder, ValueSeeder, PhantomData<V>);
+		impl<'de, V, CountSeeder: DeSeeder<'de, usize>, ValueSeeder: Clone + DeSeeder<'de, V>>
+			de::Visitor<'de> for Visitor<V, CountSeeder, ValueSeeder>
+		{
+			type Value = HashMap<uuid::Uuid, V>;
+			fn expecting(
+				&self,
+				f: &mut core::fmt::Formatter<'_>,
+			) -> core::result::Result<(), core::fmt::Error> {
+				write!(f, "PropertyBag")
+			}
+
+			fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let Visitor(count_seeder, value_seeder, _) = self;
+				let count = seq
+					.next_element_seed(count_seeder.seed())?
+					.ok_or_else(|| de::Error::invalid_length(0, &"count and properties"))?;
+				let mut properties =
+					HashMap::with_capacity(cautious_capacity::<(uuid::Uuid, V)>(Some(count)));
+				for i in 0..count {
+					let key = seq.next_element_seed(Guid)?.ok_or_else(|| {
+						de::Error::invalid_length(1 + i * 2, &"count and properties")
+					})?;
+					let value = seq
+						.next_element_seed(value_seeder.clone().seed())?
+						.ok_or_else(|| {
+							de::Error::invalid_length(2 + i * 2, &"count and properties")
+						})?;
+					if properties.insert(key, value).is_some() {
+						return Err(de::Error::custom(format_args!(
+							"Duplicate key {} in PropertyBag",
+							key
+						)));
+					}
+				}
+				Ok(properties)
+			}
+		}
+
+		deserializer.deserialize_seq(Visitor(self.0, self.1, PhantomData))
+	}
+}
+
+#[cfg(feature = "uuid")]
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone)]
+pub struct PropertyBagSeeded<'a, V, CountSeeder, ValueSeeder>(
+	&'a HashMap<uuid::Uuid, V>,
+	&'a CountSeeder,
+	&'a ValueSeeder,
+);
+#[cfg(feature = "uuid")]
+impl<'a, V, CountSeeder: SerSeeder<usize>, ValueSeeder: SerSeeder<V>> ser::Serialize
+	for PropertyBagSeeded<'a, V, CountSeeder, ValueSeeder>
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let mut properties: Vec<_> = self.0.iter().collect();
+		properties.sort_unstable_by_key(|(key, _)| **key);
+
+		let mut serialize_seq = serializer.serialize_seq(Some(1 + properties.len() * 2))?;
+		serialize_seq.serialize_element(&self.1.seeded(&properties.len()))?;
+		for (key, value) in properties {
+			serialize_seq.serialize_element(&Guid.seeded(key))?;
+			serialize_seq.serialize_element(&self.2.seeded(value))?;
+		}
+		serialize_seq.end()
+	}
+}